@@ -1,22 +1,43 @@
 #![allow(dead_code)]
 
+use crate::config::{CommunityConfig, DedupConfig, Preset, QuotesConfig, ReaderStyleConfig};
+use crate::earnings::Period;
+use crate::ipo::Listing;
+use crate::keymap::Keymap;
+use crate::ratings::RatingEntry;
+use crate::rups::Meeting;
+use crate::script::Script;
 use crate::model::*;
+use crate::quotes::{PriceMove, Quote};
+use std::cell::Cell;
 use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
 
 pub struct DisplayRow {
     pub article_idx: usize,
     pub dup_count: usize,
-    pub other_sources: Vec<String>,
+    pub other_sources: Vec<Arc<str>>,
+    /// Oldest article id in the row's duplicate cluster, if it has copies
+    pub cluster_id: Option<i64>,
+    /// Every copy in the cluster, including this row's, as article indices
+    pub cluster: Vec<usize>,
 }
 
 #[derive(Debug, PartialEq)]
 pub enum InputMode {
     Normal,
     Search,
+    AddArticle,
+    ImportWatchlist,
+    AddTicker,
+    ReaderSearch,
+    SearchHistory,
+    HighlightNote,
     SourceAdd(SourceInputField),
     SourceEdit(SourceInputField),
     SourceDelete,
+    ConfirmQuit,
 }
 
 #[derive(Debug, PartialEq)]
@@ -67,20 +88,96 @@ impl SourceFetchState {
     }
 }
 
+/// Article bodies fetched this session, keyed by URL. The least recently
+/// used entry goes once `max_entries` is reached, and `evict_expired`
+/// drops entries older than `max_age` (zero keeps them).
+pub struct ContentCache {
+    entries: HashMap<String, (String, Instant)>,
+    max_entries: usize,
+    max_age: Duration,
+}
+
+impl ContentCache {
+    pub fn new(max_entries: usize, max_age: Duration) -> Self {
+        Self {
+            entries: HashMap::new(),
+            max_entries: max_entries.max(1),
+            max_age,
+        }
+    }
+
+    pub fn get(&self, url: &str) -> Option<&String> {
+        self.entries.get(url).map(|(content, _)| content)
+    }
+
+    /// Look up `url` and mark it as just used
+    pub fn touch(&mut self, url: &str) -> Option<&String> {
+        let entry = self.entries.get_mut(url)?;
+        entry.1 = Instant::now();
+        Some(&entry.0)
+    }
+
+    pub fn insert(&mut self, url: String, content: String) {
+        self.entries.insert(url, (content, Instant::now()));
+        while self.entries.len() > self.max_entries {
+            let Some(oldest) = self
+                .entries
+                .iter()
+                .min_by_key(|(_, (_, used))| *used)
+                .map(|(url, _)| url.clone())
+            else {
+                break;
+            };
+            self.entries.remove(&oldest);
+        }
+    }
+
+    pub fn remove(&mut self, url: &str) {
+        self.entries.remove(url);
+    }
+
+    pub fn evict_expired(&mut self) {
+        if !self.max_age.is_zero() {
+            let max_age = self.max_age;
+            self.entries.retain(|_, (_, used)| used.elapsed() < max_age);
+        }
+    }
+
+    pub fn max_age(&self) -> Duration {
+        self.max_age
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
 pub struct App {
     // Articles
     pub articles: Vec<Article>,
     pub selected_index: usize,
-    pub scroll_offset: usize,
+    // First visible feed row, updated by the renderer
+    pub scroll_offset: Cell<usize>,
 
     // Input
     pub input_mode: InputMode,
     pub input_buffer: String,
     pub should_quit: bool,
+    // Once quitting, when to stop waiting on background work, and whether
+    // the user asked to skip the wait
+    pub quit_deadline: Option<Instant>,
+    pub force_quit: bool,
+    pub confirm_quit: bool,
+    pub suspend_requested: bool,
 
     // View
     pub view_mode: ViewMode,
     pub filter_mode: FilterMode,
+    pub reading_time_column: bool,
     pub theme_name: ThemeName,
     pub theme: Theme,
     pub show_help: bool,
@@ -91,20 +188,152 @@ pub struct App {
 
     // Watchlist filter
     pub watchlist: Vec<String>,
+    pub watchlist_overview: Vec<TickerSummary>,
+
+    // Earnings calendar rows: (reporting period, results article)
+    pub earnings: Vec<(Option<Period>, Article)>,
+
+    // IPO pipeline, one entry per company
+    pub ipo_listings: Vec<Listing>,
+
+    // Broker rating actions, grouped by ticker
+    pub ratings: Vec<RatingEntry>,
+
+    // New suspension/UMA notices, shown in a banner until dismissed
+    pub trading_alerts: Vec<Article>,
+    // Every alert that fired, for the Alerts view
+    pub alert_history: Vec<AlertRecord>,
+
+    // Community mention counts and crowd sentiment per watchlist ticker
+    pub community: HashMap<String, SentimentMeter>,
+    pub community_config: CommunityConfig,
+    pub community_fetched: Option<Instant>,
+
+    // Upcoming shareholder meetings for watched tickers, soonest first
+    pub meetings: Vec<Meeting>,
+
+    // Saved reader highlights, newest first
+    pub highlights: Vec<Highlight>,
+
+    // Event timeline for one ticker, newest first
+    pub timeline_ticker: String,
+    pub timeline: Vec<TimelineEntry>,
+
+    // Command run for each new article matching the watchlist
+    pub on_new_article: Option<String>,
+
+    // Portfolio holdings and their alert command
+    pub holdings: Vec<Holding>,
+    pub on_holding_article: Option<String>,
+
+    // User script hooks, and articles its on_key hook muted this session
+    pub script: Option<Arc<Script>>,
+    pub script_muted: HashSet<i64>,
+    pub sort_mode: SortMode,
 
     // Refresh
     pub refresh_interval: Duration,
+    // Articles held in memory, grown a page at a time past the end
+    pub article_limit: usize,
+    pub page_size: usize,
     pub last_refresh: Option<Instant>,
     pub is_fetching: bool,
+    // Offline pack download as (done, total) and how many failed so far
+    pub offline_requested: bool,
+    pub offline_progress: Option<(usize, usize)>,
+    pub offline_failed: usize,
+    // Re-analysis of stored articles as (done, total), resuming after the
+    // last id handled; the fingerprint is recorded once it finishes
+    pub reanalysis_requested: bool,
+    pub reanalysis_progress: Option<(usize, usize)>,
+    pub reanalysis_after: i64,
+    pub reanalysis_changed: usize,
+    pub analysis_fingerprint: String,
+    pub refresh_paused: bool,
+    pub idle_pause: Option<Duration>,
+    pub last_input: Instant,
+
+    // Key sequences from [keys] and the keys typed toward one so far
+    pub keymap: Keymap,
+    pub pending_keys: Vec<crossterm::event::KeyEvent>,
+
+    // External sentiment scorer and new articles waiting for it
+    pub sentiment_backend: Option<crate::sentiment::Backend>,
+    pub pending_rescore: Vec<(i64, String)>,
+    pub rescores_in_flight: usize,
+
+    // Webhooks and new-article payloads waiting to be sent to them
+    pub webhooks: Vec<crate::webhook::Webhook>,
+    pub pending_webhooks: Vec<Vec<u8>>,
+    pub webhooks_in_flight: usize,
+
+    // Control socket path, when [ipc] is enabled
+    pub ipc_socket: Option<std::path::PathBuf>,
+
+    // Status bar file, kept up to date while running
+    pub status_file: crate::config::StatusFileConfig,
+
+    // Language model for reader summaries and the article waiting on it
+    pub llm: Option<crate::llm::Llm>,
+    pub pending_summary: Option<(i64, String, String)>,
+    // Translator and the (article id, text, target language) waiting on it
+    pub translator: Option<crate::translate::Translator>,
+    pub pending_translation: Option<(i64, String, String)>,
+    // Read-later service, the (article id, url, title) being sent, and
+    // articles already sent
+    pub read_later: Option<crate::readlater::Service>,
+    pub pending_send: Option<(i64, String, String)>,
+    pub sends_in_flight: usize,
+    pub sent_ids: std::collections::HashSet<i64>,
+    // URL entered at the add-article prompt and fetches of added URLs
+    pub pending_add: Option<String>,
+    pub adds_in_flight: usize,
+
+    // Sentiment meters: ticker -> one meter per configured window
+    pub sentiment_windows: Vec<(String, i64)>,
+    pub sentiment_meters: HashMap<String, Vec<SentimentMeter>>,
+
+    // Price quotes: ticker -> latest delayed quote
+    pub quotes: HashMap<String, Quote>,
+    pub quotes_config: QuotesConfig,
+    pub reader_style: ReaderStyleConfig,
+    pub dedup: DedupConfig,
+    // Watchlist came from --tickers or the environment, so edits aren't saved
+    pub watchlist_overridden: bool,
+    // Sources are the --demo ones, so edits aren't saved
+    pub demo: bool,
+    // Presets from config; index 0 is the top-level settings
+    pub presets: Vec<Preset>,
+    pub preset_index: usize,
+    // Sources view row, kept while other views are open
+    pub sources_selected: usize,
+    pub time_display: TimeDisplay,
+    pub show_quotes: bool,
+    // Market strip indicators: label -> latest quote
+    pub market_quotes: HashMap<String, Quote>,
+    pub show_market_strip: bool,
 
     // Rate limiting
     pub source_fetch_state: HashMap<String, SourceFetchState>,
     pub min_fetch_interval: Duration,
 
+    // Set by input and background results; the loop skips drawing otherwise
+    pub needs_redraw: bool,
+    // Columns and rows, kept current from resize events
+    pub terminal_size: (u16, u16),
+    // Body fetch for the article open in the reader, as its URL and a
+    // handle to cancel it once the reader moves on
+    pub content_task: Option<(String, tokio::task::AbortHandle)>,
+
+    // Whether `articles` holds the bookmarks list rather than the feed
+    pub showing_bookmarks: bool,
+
     // Stats
     pub total_articles: i64,
     pub unread_count: i64,
     pub last_fetch_results: Vec<(String, Result<usize, String>)>,
+    // How long the last full refresh took, shown in the Sources view
+    pub last_refresh_took: Option<Duration>,
 
     // Status
     pub status_message: Option<(String, Instant)>,
@@ -114,20 +343,90 @@ pub struct App {
 
     // Search results (filtered article indices)
     pub search_query: String,
+    // Past queries, oldest first; Up/Down recall them while typing
+    pub search_history: Vec<String>,
+    pub history_cursor: Option<usize>,
+    pub history_draft: String,
+    // Ctrl-R picker: typed pattern and which match is shown (0 = newest)
+    pub history_pattern: String,
+    pub history_match: usize,
+    // When the previous session ended, for "new" markers
+    pub previous_session_end: Option<i64>,
+    // Duplicate-cluster choices by cluster id, loaded from the DB
+    pub cluster_prefs: HashMap<i64, ClusterPref>,
+    pub last_dismissed: Option<i64>,
+    // Ticker filters used lately, newest first, and the picker over them
+    pub recent_tickers: Vec<String>,
+    pub recent_cursor: Option<usize>,
 
     // Reader state
     pub reader_content: Option<String>,
     pub reader_scroll: u16,
     pub content_loading: bool,
+    // In-article search query (lowercased) and the current match
+    pub reader_search: Option<String>,
+    pub reader_match: usize,
+    // Paragraphs being marked for a highlight as (anchor, cursor)
+    pub highlight_selection: Option<(usize, usize)>,
+    // Links in the article body, the link picker cursor while it's open,
+    // and linked pages followed from the reader (last one is on screen)
+    pub reader_links: Vec<Link>,
+    pub link_cursor: Option<usize>,
+    pub linked_pages: Vec<LinkedPage>,
+    pub pending_link: Option<String>,
+    // Local copy of the article's lead image from an offline pack
+    pub reader_image: Option<String>,
+    // Bullet summary of the open article, once generated
+    pub reader_summary: Option<String>,
+    pub summary_loading: bool,
+    // Translated body as (language code, text), shown in place of the original
+    pub reader_translation: Option<(String, String)>,
+    pub show_translation: bool,
+    pub translation_loading: bool,
+
+    // Text-to-speech command and the playback in progress, if any
+    pub tts_command: Option<String>,
+    pub speech: Option<std::process::Child>,
+
+    // Commands for copying and piping article text; the pipe runs in the
+    // foreground, so the loop hands it the terminal
+    pub clipboard_command: Option<String>,
+    pub pipe_command: Option<String>,
+    pub pending_pipe: Option<String>,
+
+    // Older coverage of the same tickers or story, shown under the article
+    pub reader_context: Vec<Article>,
+    // Article opened from the context list, read in place of the selection
+    pub reader_override: Option<Article>,
 
     // Content cache: url -> content
-    pub content_cache: HashMap<String, String>,
+    pub content_cache: ContentCache,
 
     // Ticker filter (quick filter for a specific ticker)
     pub ticker_filter: Option<String>,
 
-    // Failed content URLs (don't re-fetch)
-    pub failed_content_urls: std::collections::HashSet<String>,
+    // Corporate action filter
+    pub event_filter: Option<EventKind>,
+
+    // Content language filter
+    pub language_filter: Option<Language>,
+
+    // Commodity/FX topic filter and the configured topic names
+    pub topic_filter: Option<String>,
+    pub topic_names: Vec<String>,
+
+    // Only show articles that mention a regulator
+    pub regulatory_only: bool,
+
+    // Only show articles published around a large price move
+    pub moves_only: bool,
+
+    // Market-wide (IHSG/LQ45/macro) bucket filter
+    pub market_filter: MarketFilter,
+
+    // Failed content URLs and when they failed (don't re-fetch until they
+    // age out of the cache window or are retried)
+    pub failed_content_urls: HashMap<String, Instant>,
 
     // Source editing state
     pub source_edit_name: String,
@@ -144,35 +443,153 @@ impl App {
         Self {
             articles: Vec::new(),
             selected_index: 0,
-            scroll_offset: 0,
+            scroll_offset: Cell::new(0),
             input_mode: InputMode::Normal,
             input_buffer: String::new(),
             should_quit: false,
+            quit_deadline: None,
+            force_quit: false,
+            confirm_quit: false,
+            suspend_requested: false,
             view_mode: ViewMode::Feed,
             filter_mode: FilterMode::All,
+            reading_time_column: false,
             theme_name: ThemeName::Dark,
             theme: Theme::from_name(ThemeName::Dark),
             show_help: false,
             show_sources: false,
             sources,
             watchlist,
+            watchlist_overview: Vec::new(),
+            earnings: Vec::new(),
+            ipo_listings: Vec::new(),
+            ratings: Vec::new(),
+            trading_alerts: Vec::new(),
+            alert_history: Vec::new(),
+            community: HashMap::new(),
+            community_config: CommunityConfig::default(),
+            community_fetched: None,
+            meetings: Vec::new(),
+            highlights: Vec::new(),
+            timeline_ticker: String::new(),
+            timeline: Vec::new(),
+            on_new_article: None,
+            holdings: Vec::new(),
+            on_holding_article: None,
+            script: None,
+            script_muted: HashSet::new(),
+            sort_mode: SortMode::Time,
             refresh_interval: Duration::from_secs(300),
+            article_limit: 100,
+            page_size: 100,
             last_refresh: None,
             is_fetching: false,
+            offline_requested: false,
+            offline_progress: None,
+            offline_failed: 0,
+            reanalysis_requested: false,
+            reanalysis_progress: None,
+            reanalysis_after: 0,
+            reanalysis_changed: 0,
+            analysis_fingerprint: String::new(),
+            refresh_paused: false,
+            idle_pause: None,
+            last_input: Instant::now(),
+            keymap: Keymap::default(),
+            pending_keys: Vec::new(),
+            sentiment_backend: None,
+            pending_rescore: Vec::new(),
+            rescores_in_flight: 0,
+            webhooks: Vec::new(),
+            pending_webhooks: Vec::new(),
+            webhooks_in_flight: 0,
+            ipc_socket: None,
+            status_file: Default::default(),
+            llm: None,
+            pending_summary: None,
+            translator: None,
+            pending_translation: None,
+            read_later: None,
+            pending_send: None,
+            sends_in_flight: 0,
+            sent_ids: std::collections::HashSet::new(),
+            pending_add: None,
+            adds_in_flight: 0,
+            sentiment_windows: vec![
+                ("24h".to_string(), 86400),
+                ("7d".to_string(), 7 * 86400),
+                ("30d".to_string(), 30 * 86400),
+            ],
+            sentiment_meters: HashMap::new(),
+            quotes: HashMap::new(),
+            quotes_config: QuotesConfig::default(),
+            reader_style: ReaderStyleConfig::default(),
+            dedup: DedupConfig::default(),
+            watchlist_overridden: false,
+            demo: false,
+            presets: Vec::new(),
+            preset_index: 0,
+            sources_selected: 0,
+            time_display: TimeDisplay::default(),
+            show_quotes: false,
+            market_quotes: HashMap::new(),
+            show_market_strip: false,
             source_fetch_state: HashMap::new(),
             min_fetch_interval: Duration::from_secs(60),
+            needs_redraw: true,
+            terminal_size: (80, 24),
+            content_task: None,
+            showing_bookmarks: false,
             total_articles: 0,
             unread_count: 0,
             last_fetch_results: Vec::new(),
+            last_refresh_took: None,
             status_message: None,
             tick_count: 0,
             search_query: String::new(),
+            search_history: Vec::new(),
+            history_cursor: None,
+            history_draft: String::new(),
+            history_pattern: String::new(),
+            history_match: 0,
+            previous_session_end: None,
+            cluster_prefs: HashMap::new(),
+            last_dismissed: None,
+            recent_tickers: Vec::new(),
+            recent_cursor: None,
             reader_content: None,
             reader_scroll: 0,
             content_loading: false,
-            content_cache: HashMap::new(),
+            reader_search: None,
+            reader_match: 0,
+            highlight_selection: None,
+            reader_links: Vec::new(),
+            link_cursor: None,
+            linked_pages: Vec::new(),
+            pending_link: None,
+            reader_image: None,
+            reader_summary: None,
+            summary_loading: false,
+            reader_translation: None,
+            show_translation: false,
+            translation_loading: false,
+            tts_command: None,
+            speech: None,
+            clipboard_command: None,
+            pipe_command: None,
+            pending_pipe: None,
+            reader_context: Vec::new(),
+            reader_override: None,
+            content_cache: ContentCache::new(200, Duration::from_secs(2 * 60 * 60)),
             ticker_filter: None,
-            failed_content_urls: std::collections::HashSet::new(),
+            event_filter: None,
+            language_filter: None,
+            topic_filter: None,
+            topic_names: Vec::new(),
+            regulatory_only: false,
+            moves_only: false,
+            market_filter: MarketFilter::Mixed,
+            failed_content_urls: HashMap::new(),
             source_edit_name: String::new(),
             source_edit_url: String::new(),
             source_edit_index: None,
@@ -184,11 +601,21 @@ impl App {
     pub fn enter_reader(&mut self) {
         self.view_mode = ViewMode::Reader;
         self.reader_scroll = 0;
+        self.reader_summary = None;
+        self.summary_loading = false;
+        self.reader_search = None;
+        self.highlight_selection = None;
+        self.reader_links.clear();
+        self.link_cursor = None;
+        self.linked_pages.clear();
+        self.reader_translation = None;
+        self.show_translation = false;
+        self.translation_loading = false;
 
         // Check cache first (use display cache for correct article lookup)
         let url = self.selected_article().map(|a| a.url.clone());
         if let Some(url) = url {
-            if let Some(content) = self.content_cache.get(&url) {
+            if let Some(content) = self.content_cache.touch(&url) {
                 self.reader_content = Some(content.clone());
                 self.content_loading = false;
             } else {
@@ -198,6 +625,39 @@ impl App {
         }
     }
 
+    /// Selected paragraph range, lowest index first
+    pub fn highlight_range(&self) -> Option<(usize, usize)> {
+        self.highlight_selection
+            .map(|(anchor, cursor)| (anchor.min(cursor), anchor.max(cursor)))
+    }
+
+    /// Body shown in the reader: the translation when toggled on, else
+    /// the extracted article
+    pub fn reader_body(&self) -> Option<&String> {
+        match (&self.reader_translation, self.show_translation) {
+            (Some((_, text)), true) => Some(text),
+            _ => self.reader_content.as_ref(),
+        }
+    }
+
+    /// Links of the page on screen: the top linked page, or the article
+    pub fn current_links(&self) -> &[Link] {
+        match self.linked_pages.last() {
+            Some(page) => &page.links,
+            None => &self.reader_links,
+        }
+    }
+
+    /// Age out cached bodies and failed fetches
+    pub fn evict_expired_content(&mut self) {
+        self.content_cache.evict_expired();
+        let max_age = self.content_cache.max_age();
+        if !max_age.is_zero() {
+            self.failed_content_urls
+                .retain(|_, failed| failed.elapsed() < max_age);
+        }
+    }
+
     pub fn cache_content(&mut self, url: String, content: String) {
         self.content_cache.insert(url, content.clone());
         self.reader_content = Some(content);
@@ -205,9 +665,102 @@ impl App {
     }
 
     pub fn set_ticker_filter(&mut self, ticker: Option<String>) {
+        if let Some(ref t) = ticker {
+            self.recent_tickers.retain(|r| r != t);
+            self.recent_tickers.insert(0, t.clone());
+            self.recent_tickers.truncate(RECENT_TICKERS_LIMIT);
+        }
         self.ticker_filter = ticker;
         self.selected_index = 0;
-        self.scroll_offset = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    pub fn cycle_sort(&mut self) {
+        self.sort_mode = self.sort_mode.next();
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    pub fn is_holding(&self, article: &Article) -> bool {
+        holding_weight(article, &self.holdings).is_some()
+    }
+
+    /// Cycle None -> each configured topic -> None
+    pub fn cycle_topic_filter(&mut self) {
+        let next = match self.topic_filter {
+            None => 0,
+            Some(ref t) => self
+                .topic_names
+                .iter()
+                .position(|n| n == t)
+                .map(|i| i + 1)
+                .unwrap_or(0),
+        };
+        self.topic_filter = self.topic_names.get(next).cloned();
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    /// Whether the community stats should be refreshed: only while the
+    /// watchlist overview is open, and at most once per configured interval
+    pub fn community_due(&self) -> bool {
+        self.view_mode == ViewMode::Watchlist
+            && self.community_config.url.is_some()
+            && !self.watchlist.is_empty()
+            && self.community_fetched.is_none_or(|at| {
+                at.elapsed() >= Duration::from_secs(self.community_config.interval.max(60))
+            })
+    }
+
+    pub fn toggle_moves_filter(&mut self) {
+        self.moves_only = !self.moves_only;
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    /// The largest price move of any of the article's tickers around the
+    /// time it was published
+    pub fn price_move(&self, article: &Article) -> Option<PriceMove> {
+        let window = self.quotes_config.move_window_hours as i64 * 3600;
+        article
+            .tickers
+            .iter()
+            .filter_map(|t| self.quotes.get(t))
+            .flat_map(|q| q.moves.iter())
+            .filter(|m| m.covers(article.published_at, window))
+            .max_by(|a, b| a.change_pct.abs().total_cmp(&b.change_pct.abs()))
+            .copied()
+    }
+
+    pub fn toggle_regulatory_filter(&mut self) {
+        self.regulatory_only = !self.regulatory_only;
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    pub fn cycle_market_filter(&mut self) {
+        self.market_filter = self.market_filter.next();
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    pub fn cycle_event_filter(&mut self) {
+        self.event_filter = EventKind::cycle(self.event_filter);
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        self.display_dirty = true;
+    }
+
+    pub fn cycle_language_filter(&mut self) {
+        self.language_filter = Language::cycle(self.language_filter);
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
         self.display_dirty = true;
     }
 
@@ -226,7 +779,7 @@ impl App {
 
     pub fn select_first(&mut self) {
         self.selected_index = 0;
-        self.scroll_offset = 0;
+        self.scroll_offset.set(0);
     }
 
     pub fn select_last(&mut self) {
@@ -236,7 +789,43 @@ impl App {
         }
     }
 
+    /// Apply `change` to the article with `id` wherever it is held, so a
+    /// one-field edit doesn't need the feed queried again
+    pub fn update_article(&mut self, id: i64, change: impl Fn(&mut Article)) {
+        self.articles
+            .iter_mut()
+            .chain(self.reader_override.as_mut())
+            .filter(|a| a.id == id)
+            .for_each(change);
+    }
+
+    /// Run the script's on_display hook over the loaded articles, leaving
+    /// out the ones it mutes. An article whose hook fails is kept, and the
+    /// first error is shown.
+    pub fn run_display_hook(&mut self) {
+        let Some(script) = self.script.clone() else {
+            return;
+        };
+        let muted = &self.script_muted;
+        let mut error = None;
+        self.articles.retain_mut(|a| {
+            !muted.contains(&a.id)
+                && script.on_display(a).unwrap_or_else(|e| {
+                    error.get_or_insert(e);
+                    true
+                })
+        });
+        if let Some(e) = error {
+            self.set_status(format!("Script error: {}", e));
+        }
+    }
+
     pub fn selected_article(&self) -> Option<&Article> {
+        if self.view_mode == ViewMode::Reader {
+            if let Some(ref article) = self.reader_override {
+                return Some(article);
+            }
+        }
         self.cached_display
             .get(self.selected_index)
             .and_then(|row| self.articles.get(row.article_idx))
@@ -255,6 +844,16 @@ impl App {
         None
     }
 
+    /// Something on screen is spinning or loading, so every tick redraws
+    pub fn animating(&self) -> bool {
+        self.is_fetching
+            || self.offline_progress.is_some()
+            || self.reanalysis_progress.is_some()
+            || self.content_loading
+            || self.summary_loading
+            || self.translation_loading
+    }
+
     pub fn spinner_char(&self) -> char {
         const CHARS: &[char] = &['\u{25dc}', '\u{25dd}', '\u{25de}', '\u{25df}'];
         CHARS[(self.tick_count as usize / 2) % CHARS.len()]
@@ -268,7 +867,7 @@ impl App {
     pub fn cycle_filter(&mut self) {
         self.filter_mode = self.filter_mode.next();
         self.selected_index = 0;
-        self.scroll_offset = 0;
+        self.scroll_offset.set(0);
         self.display_dirty = true;
     }
 
@@ -282,7 +881,240 @@ impl App {
         0
     }
 
+    /// True when no key has been pressed for longer than the idle timeout
+    pub fn is_idle(&self) -> bool {
+        self.idle_pause
+            .map(|limit| self.last_input.elapsed() >= limit)
+            .unwrap_or(false)
+    }
+
+    /// Background work whose results would be lost by exiting now
+    pub fn unfinished_work(&self) -> Vec<&'static str> {
+        let mut work = Vec::new();
+        if self.is_fetching {
+            work.push("refresh");
+        }
+        if self.content_loading {
+            work.push("article");
+        }
+        if self.rescores_in_flight > 0 || !self.pending_rescore.is_empty() {
+            work.push("sentiment scores");
+        }
+        if self.webhooks_in_flight > 0 || !self.pending_webhooks.is_empty() {
+            work.push("webhooks");
+        }
+        if self.sends_in_flight > 0 || self.pending_send.is_some() {
+            work.push("read-later send");
+        }
+        if self.adds_in_flight > 0 || self.pending_add.is_some() {
+            work.push("added article");
+        }
+        if self.offline_progress.is_some() {
+            work.push("offline pack");
+        }
+        if self.summary_loading {
+            work.push("summary");
+        }
+        if self.translation_loading {
+            work.push("translation");
+        }
+        work
+    }
+
+    /// Whether the auto-refresh timer should fire (not paused, not idle)
+    pub fn auto_refresh_active(&self) -> bool {
+        !self.refresh_paused && !self.is_idle()
+    }
+
+    pub fn toggle_refresh_pause(&mut self) {
+        self.refresh_paused = !self.refresh_paused;
+        if !self.refresh_paused {
+            // Restart the countdown instead of firing immediately
+            self.last_refresh = Some(Instant::now());
+        }
+    }
+
+    /// Meter for the shortest configured window, shown in feed rows
+    pub fn primary_meter(&self, ticker: &str) -> Option<&SentimentMeter> {
+        self.sentiment_meters.get(ticker).and_then(|m| m.first())
+    }
+
+    /// Tickers the quote task should keep fresh: the watchlist plus the
+    /// active ticker filter
+    pub fn quote_tickers(&self) -> Vec<String> {
+        let mut tickers = self.watchlist.clone();
+        for h in &self.holdings {
+            if !tickers.contains(&h.ticker) {
+                tickers.push(h.ticker.clone());
+            }
+        }
+        if let Some(ref t) = self.ticker_filter {
+            if !tickers.contains(t) {
+                tickers.push(t.clone());
+            }
+        }
+        tickers
+    }
+
+    /// The configured source an article came from, if it still exists
+    pub fn source_of(&self, article: &Article) -> Option<&FeedSource> {
+        self.sources.iter().find(|s| *s.name == *article.source)
+    }
+
+    /// Sources with their own refresh_interval whose next fetch is due
+    pub fn scheduled_sources(&self) -> Vec<FeedSource> {
+        self.sources
+            .iter()
+            .filter(|s| s.enabled)
+            .filter(|s| {
+                let Some(secs) = s.refresh_interval else {
+                    return false;
+                };
+                self.source_fetch_state
+                    .get(&s.name)
+                    .map(|state| state.can_fetch(Duration::from_secs(secs)))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect()
+    }
+
     /// Get sources eligible for fetching (respects rate limits)
+    /// Switch to the next preset, wrapping back to the top-level settings.
+    /// Returns the new preset's name, or None when no presets are defined.
+    pub fn cycle_preset(&mut self) -> Option<String> {
+        if self.presets.len() < 2 {
+            return None;
+        }
+        self.preset_index = (self.preset_index + 1) % self.presets.len();
+        let preset = self.presets[self.preset_index].clone();
+        self.watchlist = preset.watchlist;
+        self.sources = preset.sources;
+        if let Some(sort_mode) = preset.sort_mode {
+            self.sort_mode = sort_mode;
+        }
+        if let Some(filter_mode) = preset.filter_mode {
+            self.filter_mode = filter_mode;
+        }
+        self.selected_index = 0;
+        self.scroll_offset.set(0);
+        Some(preset.name)
+    }
+
+    /// Edits made under a preset stay in the session rather than
+    /// overwriting the top-level watchlist and sources
+    pub fn preset_active(&self) -> bool {
+        self.preset_index > 0
+    }
+
+    /// Change the selected row's duplicate cluster and return its id and
+    /// new choices to store, or None when the row has no copies
+    fn update_cluster(
+        &mut self,
+        change: impl FnOnce(&mut ClusterPref, &[String]),
+    ) -> Option<(i64, ClusterPref)> {
+        let row = self.cached_display.get(self.selected_index)?;
+        let id = row.cluster_id?;
+        let mut sources: Vec<String> = vec![self.articles[row.article_idx].source.to_string()];
+        for &m in &row.cluster {
+            let source = &*self.articles[m].source;
+            if !sources.iter().any(|s| s == source) {
+                sources.push(source.to_string());
+            }
+        }
+        let pref = self.cluster_prefs.entry(id).or_default();
+        change(pref, &sources);
+        let pref = pref.clone();
+        self.display_dirty = true;
+        Some((id, pref))
+    }
+
+    /// Show a cluster's copies as separate rows, or fold them back
+    pub fn toggle_cluster_expanded(&mut self) -> Option<(i64, ClusterPref)> {
+        self.update_cluster(|pref, _| pref.expanded = !pref.expanded)
+    }
+
+    /// Hide the selected cluster, every copy of it
+    pub fn dismiss_cluster(&mut self) -> Option<(i64, ClusterPref)> {
+        let updated = self.update_cluster(|pref, _| pref.dismissed = true);
+        if let Some((id, _)) = updated {
+            self.last_dismissed = Some(id);
+        }
+        updated
+    }
+
+    /// Bring back the most recently dismissed cluster
+    pub fn restore_dismissed_cluster(&mut self) -> Option<(i64, ClusterPref)> {
+        let id = self.last_dismissed.take()?;
+        let pref = self.cluster_prefs.entry(id).or_default();
+        pref.dismissed = false;
+        let pref = pref.clone();
+        self.display_dirty = true;
+        Some((id, pref))
+    }
+
+    /// Let the next source in the cluster represent it
+    pub fn cycle_cluster_source(&mut self) -> Option<(i64, ClusterPref)> {
+        self.update_cluster(|pref, sources| {
+            // sources[0] is the current lead
+            pref.preferred_source = sources.get(1).or(sources.first()).cloned();
+        })
+    }
+
+    /// Record a submitted query, moving a repeat to the newest slot
+    pub fn push_search_history(&mut self, query: &str) {
+        if query.is_empty() {
+            return;
+        }
+        self.search_history.retain(|q| q != query);
+        self.search_history.push(query.to_string());
+        let excess = self
+            .search_history
+            .len()
+            .saturating_sub(SEARCH_HISTORY_LIMIT);
+        self.search_history.drain(..excess);
+        self.history_cursor = None;
+    }
+
+    /// Replace the search input with the previous (older) query
+    pub fn history_prev(&mut self) {
+        let index = match self.history_cursor {
+            None if self.search_history.is_empty() => return,
+            None => {
+                self.history_draft = self.input_buffer.clone();
+                self.search_history.len() - 1
+            }
+            Some(i) => i.saturating_sub(1),
+        };
+        self.history_cursor = Some(index);
+        self.input_buffer = self.search_history[index].clone();
+    }
+
+    /// Replace the search input with the next (newer) query, ending back at
+    /// whatever was typed before browsing
+    pub fn history_next(&mut self) {
+        let Some(i) = self.history_cursor else {
+            return;
+        };
+        if i + 1 < self.search_history.len() {
+            self.history_cursor = Some(i + 1);
+            self.input_buffer = self.search_history[i + 1].clone();
+        } else {
+            self.history_cursor = None;
+            self.input_buffer = std::mem::take(&mut self.history_draft);
+        }
+    }
+
+    /// History entries matching the Ctrl-R pattern, newest first
+    pub fn history_matches(&self) -> Vec<&str> {
+        self.search_history
+            .iter()
+            .rev()
+            .filter(|q| fuzzy_match(&self.history_pattern, q))
+            .map(String::as_str)
+            .collect()
+    }
+
     pub fn eligible_sources(&self) -> Vec<FeedSource> {
         self.sources
             .iter()
@@ -297,6 +1129,17 @@ impl App {
             .collect()
     }
 
+    /// Fetched after the previous session ended; nothing is new on the
+    /// very first run
+    pub fn is_new(&self, a: &Article) -> bool {
+        self.previous_session_end
+            .is_some_and(|end| a.fetched_at > end)
+    }
+
+    pub fn matches_watchlist(&self, a: &Article) -> bool {
+        matches_watchlist(a, &self.watchlist)
+    }
+
     /// Recompute the cached display list (filtering + deduplication).
     /// Called once when data changes, not on every render frame.
     pub fn recompute_display(&mut self) {
@@ -310,18 +1153,9 @@ impl App {
                 let a = &self.articles[i];
                 match self.filter_mode {
                     FilterMode::All | FilterMode::Source => true,
-                    FilterMode::Watchlist => {
-                        if self.watchlist.is_empty() {
-                            true
-                        } else {
-                            a.tickers.iter().any(|t| self.watchlist.contains(t))
-                                || self
-                                    .watchlist
-                                    .iter()
-                                    .any(|w| a.title.to_uppercase().contains(w))
-                        }
-                    }
+                    FilterMode::Watchlist => self.matches_watchlist(a),
                     FilterMode::Unread => !a.read,
+                    FilterMode::New => self.is_new(a),
                 }
             })
             .filter(|&i| {
@@ -333,6 +1167,28 @@ impl App {
                     true
                 }
             })
+            .filter(|&i| {
+                let market = is_market_news(&self.articles[i].title);
+                match self.market_filter {
+                    MarketFilter::Mixed => !(market && self.ticker_filter.is_some()),
+                    MarketFilter::Only => market,
+                    MarketFilter::Hidden => !market,
+                }
+            })
+            .filter(|&i| !self.regulatory_only || is_regulatory(&self.articles[i].title))
+            .filter(|&i| !self.moves_only || self.price_move(&self.articles[i]).is_some())
+            .filter(|&i| match self.topic_filter {
+                Some(ref topic) => self.articles[i].topics.contains(topic),
+                None => true,
+            })
+            .filter(|&i| match self.event_filter {
+                Some(kind) => self.articles[i].event == Some(kind),
+                None => true,
+            })
+            .filter(|&i| match self.language_filter {
+                Some(lang) => self.articles[i].language == Some(lang),
+                None => true,
+            })
             .filter(|&i| {
                 if has_search {
                     let a = &self.articles[i];
@@ -340,6 +1196,7 @@ impl App {
                         || a.tickers
                             .iter()
                             .any(|t| t.to_lowercase().contains(&search_lower))
+                        || a.keywords.iter().any(|k| k.contains(&search_lower))
                         || self
                             .content_cache
                             .get(&a.url)
@@ -352,27 +1209,29 @@ impl App {
             .collect();
 
         // Step 2: Deduplicate with pre-computed normalized titles
-        if filtered_indices.len() <= 1 {
+        if filtered_indices.len() <= 1 || !self.dedup.enabled {
             self.cached_display = filtered_indices
                 .into_iter()
                 .map(|idx| DisplayRow {
                     article_idx: idx,
                     dup_count: 0,
                     other_sources: vec![],
+                    cluster_id: None,
+                    cluster: vec![],
                 })
                 .collect();
         } else {
             // Pre-compute normalized titles and word sets once
             let normalized: Vec<String> = filtered_indices
                 .iter()
-                .map(|&idx| normalize_title(&self.articles[idx].title))
+                .map(|&idx| normalize_title(&self.articles[idx].title, &self.dedup.stop_words))
                 .collect();
             let word_sets: Vec<HashSet<&str>> = normalized
                 .iter()
                 .map(|n| n.split_whitespace().collect())
                 .collect();
 
-            let threshold = 0.7;
+            let threshold = self.dedup.threshold;
             let mut consumed = vec![false; filtered_indices.len()];
             let mut result = Vec::new();
 
@@ -380,7 +1239,7 @@ impl App {
                 if consumed[i] {
                     continue;
                 }
-                let mut other_sources = Vec::new();
+                let mut members = vec![filtered_indices[i]];
                 for j in (i + 1)..filtered_indices.len() {
                     if consumed[j] {
                         continue;
@@ -390,23 +1249,79 @@ impl App {
                             word_sets[i].intersection(&word_sets[j]).count() as f64;
                         let union = word_sets[i].union(&word_sets[j]).count() as f64;
                         if union > 0.0 && (intersection / union) >= threshold {
-                            other_sources
-                                .push(self.articles[filtered_indices[j]].source.clone());
+                            members.push(filtered_indices[j]);
                             consumed[j] = true;
                         }
                     }
                 }
-                let dup_count = other_sources.len();
+                if members.len() == 1 {
+                    result.push(DisplayRow {
+                        article_idx: members[0],
+                        dup_count: 0,
+                        other_sources: vec![],
+                        cluster_id: None,
+                        cluster: vec![],
+                    });
+                    continue;
+                }
+
+                // The oldest copy's id names the cluster, so it stays the
+                // same as later copies join
+                let cluster_id = members.iter().map(|&m| self.articles[m].id).min();
+                let pref = cluster_id
+                    .and_then(|id| self.cluster_prefs.get(&id))
+                    .cloned()
+                    .unwrap_or_default();
+                if pref.dismissed {
+                    continue;
+                }
+                if pref.expanded {
+                    for &m in &members {
+                        result.push(DisplayRow {
+                            article_idx: m,
+                            dup_count: 0,
+                            other_sources: vec![],
+                            cluster_id,
+                            cluster: members.clone(),
+                        });
+                    }
+                    continue;
+                }
+                let lead = pref
+                    .preferred_source
+                    .as_ref()
+                    .and_then(|src| members.iter().find(|&&m| *self.articles[m].source == **src))
+                    .copied()
+                    .unwrap_or(members[0]);
+                let other_sources: Vec<Arc<str>> = members
+                    .iter()
+                    .filter(|&&m| m != lead)
+                    .map(|&m| self.articles[m].source.clone())
+                    .collect();
                 result.push(DisplayRow {
-                    article_idx: filtered_indices[i],
-                    dup_count,
+                    article_idx: lead,
+                    dup_count: other_sources.len(),
                     other_sources,
+                    cluster_id,
+                    cluster: members,
                 });
             }
 
             self.cached_display = result;
         }
 
+        // Portfolio sort: articles about holdings first, biggest position
+        // first; the stable sort keeps newest-first within each group
+        if self.sort_mode == SortMode::Portfolio {
+            let holdings = &self.holdings;
+            let articles = &self.articles;
+            self.cached_display.sort_by(|a, b| {
+                let wa = holding_weight(&articles[a.article_idx], holdings);
+                let wb = holding_weight(&articles[b.article_idx], holdings);
+                wb.partial_cmp(&wa).unwrap_or(std::cmp::Ordering::Equal)
+            });
+        }
+
         // Keep selected_index in bounds
         if self.cached_display.is_empty() {
             self.selected_index = 0;
@@ -436,11 +1351,8 @@ impl App {
 
     pub fn confirm_add_source(&mut self) {
         if !self.source_edit_name.is_empty() && !self.source_edit_url.is_empty() {
-            self.sources.push(FeedSource {
-                name: self.source_edit_name.clone(),
-                url: self.source_edit_url.clone(),
-                enabled: true,
-            });
+            self.sources
+                .push(FeedSource::new(&self.source_edit_name, &self.source_edit_url));
             self.set_status(format!("Added source: {}", self.source_edit_name));
         }
         self.input_mode = InputMode::Normal;
@@ -472,6 +1384,7 @@ impl App {
     // View state persistence
     pub fn to_view_state(&self) -> crate::state::ViewState {
         crate::state::ViewState {
+            version: crate::state::STATE_VERSION,
             filter_mode: Some(self.filter_mode.as_str().to_string()),
             search_query: if self.search_query.is_empty() {
                 None
@@ -479,8 +1392,23 @@ impl App {
                 Some(self.search_query.clone())
             },
             ticker_filter: self.ticker_filter.clone(),
+            event_filter: self.event_filter.map(|e| e.as_str().to_string()),
+            language_filter: self.language_filter.map(|l| l.as_str().to_string()),
+            sort_mode: Some(self.sort_mode.as_str().to_string()),
+            market_filter: Some(self.market_filter.as_str().to_string()),
+            topic_filter: self.topic_filter.clone(),
+            regulatory_only: self.regulatory_only,
             theme_name: Some(self.theme_name.label().to_lowercase()),
             selected_index: Some(self.selected_index),
+            scroll_offset: self.scroll_offset.get(),
+            sources_selected: if self.view_mode == ViewMode::Sources {
+                self.selected_index
+            } else {
+                self.sources_selected
+            },
+            search_history: self.search_history.clone(),
+            recent_tickers: self.recent_tickers.clone(),
+            session_end: None,
         }
     }
 
@@ -492,6 +1420,22 @@ impl App {
             self.search_query = q.clone();
         }
         self.ticker_filter = state.ticker_filter.clone();
+        self.event_filter = state.event_filter.as_deref().and_then(EventKind::from_str);
+        self.language_filter = state
+            .language_filter
+            .as_deref()
+            .and_then(Language::from_str);
+        self.regulatory_only = state.regulatory_only;
+        self.topic_filter = state
+            .topic_filter
+            .clone()
+            .filter(|t| self.topic_names.contains(t));
+        if let Some(ref mf) = state.market_filter {
+            self.market_filter = MarketFilter::from_str(mf);
+        }
+        if let Some(ref sm) = state.sort_mode {
+            self.sort_mode = SortMode::from_str(sm);
+        }
         if let Some(ref tn) = state.theme_name {
             self.theme_name = ThemeName::from_str(tn);
             self.theme = Theme::from_name(self.theme_name);
@@ -499,5 +1443,25 @@ impl App {
         if let Some(idx) = state.selected_index {
             self.selected_index = idx;
         }
+        self.scroll_offset.set(state.scroll_offset);
+        self.sources_selected = state.sources_selected;
+        self.search_history = state.search_history.clone();
+        self.recent_tickers = state.recent_tickers.clone();
+        self.previous_session_end = state.session_end;
     }
 }
+
+/// How many tickers the recent-tickers picker offers (keys 1-9)
+const RECENT_TICKERS_LIMIT: usize = 9;
+
+/// How many past search queries are kept
+const SEARCH_HISTORY_LIMIT: usize = 100;
+
+/// Pattern characters appear in order in the text, ignoring case
+fn fuzzy_match(pattern: &str, text: &str) -> bool {
+    let mut chars = text.chars().flat_map(char::to_lowercase);
+    pattern
+        .chars()
+        .flat_map(char::to_lowercase)
+        .all(|p| chars.any(|c| c == p))
+}
@@ -0,0 +1,1162 @@
+use crate::app::SourceFetchState;
+use crate::companies;
+use crate::config::{
+    self, Command, CompanyAction, ConfigAction, DigestGroup, ResolvedConfig, SourceAction,
+    StatusFormat, WatchlistAction,
+};
+use crate::db::{Db, ANALYSIS_KEY, LAST_EMAIL_KEY, LAST_REFRESH_KEY};
+use crate::digest;
+use crate::export;
+use crate::feed;
+use crate::ingest;
+use crate::keymap;
+use crate::model::*;
+use crate::script::Script;
+use crate::sentiment;
+use crate::serve;
+use crate::statusline::{self, Status};
+use crate::watchlist;
+use crate::webhook;
+use chrono::Timelike;
+use std::collections::HashMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// Everything a headless subcommand needs
+pub struct Context<'a> {
+    pub resolved: &'a ResolvedConfig,
+    pub sources: &'a [FeedSource],
+    pub db: &'a Db,
+    pub db_path: &'a Path,
+    pub config_path: PathBuf,
+    /// Emit structured JSON instead of human-readable text
+    pub json: bool,
+    /// Suppress normal output; only errors are reported
+    pub quiet: bool,
+}
+
+/// Failure categories for headless commands, each with its own exit code
+/// so cron jobs and scripts can tell them apart.
+#[derive(Debug)]
+pub enum CliError {
+    Other(String),
+    Config(String),
+    Network(String),
+    Db(String),
+    NoResults(String),
+}
+
+impl CliError {
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Other(_) => 1,
+            CliError::Config(_) => 2,
+            CliError::Network(_) => 3,
+            CliError::Db(_) => 4,
+            CliError::NoResults(_) => 5,
+        }
+    }
+
+    pub fn kind(&self) -> &str {
+        match self {
+            CliError::Other(_) => "error",
+            CliError::Config(_) => "config",
+            CliError::Network(_) => "network",
+            CliError::Db(_) => "database",
+            CliError::NoResults(_) => "no_results",
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            CliError::Other(m)
+            | CliError::Config(m)
+            | CliError::Network(m)
+            | CliError::Db(m)
+            | CliError::NoResults(m) => m,
+        }
+    }
+}
+
+impl From<io::Error> for CliError {
+    fn from(e: io::Error) -> Self {
+        CliError::Other(e.to_string())
+    }
+}
+
+impl From<rusqlite::Error> for CliError {
+    fn from(e: rusqlite::Error) -> Self {
+        CliError::Db(e.to_string())
+    }
+}
+
+type CliResult = Result<(), CliError>;
+
+/// Print an error to stderr, as JSON when requested
+pub fn report_error(err: &CliError, errors_json: bool) {
+    if errors_json {
+        eprintln!(
+            "{}",
+            serde_json::json!({
+                "error": err.kind(),
+                "message": err.message(),
+                "exit_code": err.exit_code(),
+            })
+        );
+    } else {
+        eprintln!("Error: {}", err.message());
+    }
+}
+
+/// println! unless --quiet
+macro_rules! out {
+    ($ctx:expr) => {
+        if !$ctx.quiet {
+            println!();
+        }
+    };
+    ($ctx:expr, $($arg:tt)*) => {
+        if !$ctx.quiet {
+            println!($($arg)*);
+        }
+    };
+}
+
+/// Run a headless subcommand
+pub fn run(command: &Command, ctx: &Context) -> CliResult {
+    match command {
+        Command::Fetch => run_fetch(ctx),
+        Command::Daemon => run_daemon(ctx),
+        Command::Sources => run_sources(ctx),
+        Command::Tickers => run_tickers(ctx),
+        Command::Stats => run_stats(ctx),
+        Command::Status { format } => run_status(ctx, *format),
+        Command::Serve { bind } => run_serve(ctx, bind.as_deref()),
+        Command::Doctor => run_doctor(ctx),
+        Command::Digest { hours, by } => run_digest(ctx, *hours, *by),
+        Command::EmailDigest { hours } => run_email_digest(ctx, *hours),
+        Command::ExportRss { search, output } => {
+            run_export_rss(ctx, search.as_deref(), output.as_ref())
+        }
+        Command::AddArticle { url } => run_add_article(ctx, url),
+        Command::Source { action } => run_source_action(ctx, action),
+        Command::Companies { action } => run_companies(ctx, action),
+        Command::Reanalyze => run_reanalyze(ctx),
+        Command::Watchlist { action } => run_watchlist(ctx, action),
+        Command::Config { action } => match action {
+            ConfigAction::Check => run_config_check(ctx),
+        },
+    }
+}
+
+fn print_json(ctx: &Context, value: &serde_json::Value) {
+    out!(
+        ctx,
+        "{}",
+        serde_json::to_string_pretty(value).unwrap_or_default()
+    );
+}
+
+fn build_client() -> Result<reqwest::Client, CliError> {
+    feed::build_client().map_err(|e| CliError::Network(e.to_string()))
+}
+
+// ============================================================
+// fetch
+// ============================================================
+
+fn run_fetch(ctx: &Context) -> CliResult {
+    let rt = tokio::runtime::Runtime::new()?;
+    let client = build_client()?;
+    let (results, hooks) = fetch_and_store(ctx, &rt, &client, ctx.sources);
+
+    let total_new: usize = results.iter().filter_map(|(_, r)| r.as_ref().ok()).sum();
+    if ctx.json {
+        let report: Vec<_> = results
+            .iter()
+            .map(|(source, result)| match result {
+                Ok(new) => serde_json::json!({ "source": source, "new": new }),
+                Err(e) => serde_json::json!({ "source": source, "error": e }),
+            })
+            .collect();
+        print_json(
+            ctx,
+            &serde_json::json!({ "sources": report, "total_new": total_new }),
+        );
+    } else {
+        for (source, result) in &results {
+            match result {
+                Ok(new) => out!(ctx, "{:<16} {} new", source, new),
+                Err(e) => out!(ctx, "{:<16} error: {}", source, e),
+            }
+        }
+        out!(ctx, "{} new articles", total_new);
+    }
+
+    for handle in hooks {
+        let _ = handle.join();
+    }
+    if !results.is_empty() && results.iter().all(|(_, r)| r.is_err()) {
+        return Err(CliError::Network("All sources failed to fetch".to_string()));
+    }
+    Ok(())
+}
+
+/// Per-source outcome of a fetch: new articles inserted, or the error
+type FetchReport = (String, Result<usize, String>);
+
+/// Fetch the given sources, insert new articles, and fire the new-article
+/// hook and webhooks for watchlist matches. Returns the per-source insert counts and the
+/// hook threads still running.
+fn fetch_and_store(
+    ctx: &Context,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    sources: &[FeedSource],
+) -> (Vec<FetchReport>, Vec<JoinHandle<()>>) {
+    let results = rt.block_on(feed::fetch_all_feeds(client, sources));
+    let backend = sentiment::Backend::from_config(&ctx.resolved.sentiment_backend);
+    let mut hooks = Vec::new();
+    let mut report = Vec::new();
+    let mut rescore = Vec::new();
+    let webhooks = webhook::Webhook::from_config(&ctx.resolved.webhooks);
+    let mut payloads = Vec::new();
+    let notify = ingest::Notify {
+        watchlist: &ctx.resolved.watchlist,
+        holdings: &ctx.resolved.holdings,
+        on_new_article: ctx.resolved.on_new_article.as_deref(),
+        on_holding_article: ctx.resolved.on_holding_article.as_deref(),
+        webhooks: !webhooks.is_empty(),
+    };
+    let script = match ctx.resolved.script.as_deref().map(Script::load) {
+        Some(Ok(script)) => Some(script),
+        Some(Err(e)) => {
+            eprintln!("Warning: script not loaded: {}", e);
+            None
+        }
+        None => None,
+    };
+
+    for (source_name, result) in results {
+        match result {
+            Ok(articles) => {
+                let mut inserted = 0;
+                for mut article in articles {
+                    if let Some(ref script) = script {
+                        match script.on_insert(&mut article) {
+                            Ok(true) => {}
+                            Ok(false) => continue,
+                            Err(e) => eprintln!("Warning: script error: {}", e),
+                        }
+                    }
+                    let Some(stored) = ingest::store(ctx.db, &article, &notify) else {
+                        continue;
+                    };
+                    inserted += 1;
+                    if let (true, Some(id)) = (backend.is_some(), stored.id) {
+                        rescore.push((id, article.title.to_string()));
+                    }
+                    payloads.extend(stored.payload);
+                    hooks.extend(stored.hooks);
+                }
+                report.push((source_name, Ok(inserted)));
+            }
+            Err(e) => report.push((source_name, Err(e))),
+        }
+    }
+
+    if let Some(backend) = backend {
+        for (id, score) in rt.block_on(sentiment::score_batch(&backend, client, rescore)) {
+            let _ = ctx.db.update_sentiment(id, score);
+        }
+    }
+    let now = chrono::Utc::now().timestamp();
+    let _ = ctx.db.set_meta(LAST_REFRESH_KEY, &now.to_string());
+    if !payloads.is_empty() {
+        for failure in rt.block_on(webhook::deliver_all(client, &webhooks, payloads)) {
+            eprintln!("Warning: webhook failed: {}", failure);
+        }
+    }
+
+    (report, hooks)
+}
+
+// ============================================================
+// daemon
+// ============================================================
+
+fn run_daemon(ctx: &Context) -> CliResult {
+    let rt = tokio::runtime::Runtime::new()?;
+    let client = build_client()?;
+    let min_interval = Duration::from_secs(ctx.resolved.min_fetch_interval);
+    let mut fetch_state: HashMap<String, SourceFetchState> = HashMap::new();
+    // Kept in the database so a restart doesn't send the day's digest again
+    let mut last_email_day = ctx.db.get_meta(LAST_EMAIL_KEY).ok().flatten();
+
+    loop {
+        let eligible: Vec<FeedSource> = ctx
+            .sources
+            .iter()
+            .filter(|s| s.enabled)
+            .filter(|s| {
+                let interval = s.refresh_interval.map(Duration::from_secs);
+                fetch_state
+                    .get(&s.name)
+                    .map(|state| state.can_fetch(interval.unwrap_or(min_interval)))
+                    .unwrap_or(true)
+            })
+            .cloned()
+            .collect();
+
+        let (results, hooks) = fetch_and_store(ctx, &rt, &client, &eligible);
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
+        for (source, result) in &results {
+            let state = fetch_state
+                .entry(source.clone())
+                .or_insert_with(SourceFetchState::new);
+            match result {
+                Ok(_) => state.record_success(),
+                Err(_) => state.record_failure(),
+            }
+            if ctx.json {
+                let line = match result {
+                    Ok(new) => {
+                        serde_json::json!({ "time": now.to_string(), "source": source, "new": new })
+                    }
+                    Err(e) => {
+                        serde_json::json!({ "time": now.to_string(), "source": source, "error": e })
+                    }
+                };
+                out!(ctx, "{}", line);
+            } else {
+                match result {
+                    Ok(new) => out!(ctx, "[{}] {:<16} {} new", now, source, new),
+                    Err(e) => out!(ctx, "[{}] {:<16} error: {}", now, source, e),
+                }
+            }
+        }
+        // Hooks are reaped in the background; the daemon never exits on its own
+        drop(hooks);
+
+        if let Some(path) = ctx.resolved.status_file.path() {
+            let time =
+                TimeDisplay::new(ctx.resolved.timezone.as_deref(), &ctx.resolved.date_format);
+            let status = Status::gather(ctx.db, &ctx.resolved.watchlist);
+            let text = status.render(&ctx.resolved.status_file.format, &time);
+            if let Err(e) = statusline::write(&path, &text) {
+                eprintln!("[{}] status file {}: {}", now, path.display(), e);
+            }
+        }
+
+        // Scheduled email digest, at most once per day at the configured
+        // hour in the configured zone, so the day matches the subject's. A
+        // failed send is tried again on the next wake; a day with no
+        // watchlist articles sends nothing.
+        let time = TimeDisplay::new(ctx.resolved.timezone.as_deref(), &ctx.resolved.date_format);
+        let local = time.at(chrono::Utc::now().timestamp());
+        if let (Some(hour), Some(local)) = (ctx.resolved.email.daily_hour, local) {
+            let today = local.date_naive().to_string();
+            if local.hour() >= hour && last_email_day.as_ref() != Some(&today) {
+                let result = load_digest_articles(ctx, 24)
+                    .map_err(|e| e.message().to_string())
+                    .and_then(|articles| {
+                        if articles.is_empty() {
+                            Ok(())
+                        } else {
+                            send_digest_email(ctx, 24, &articles)
+                        }
+                    });
+                match result {
+                    Ok(()) => {
+                        let _ = ctx.db.set_meta(LAST_EMAIL_KEY, &today);
+                        last_email_day = Some(today);
+                    }
+                    Err(e) => eprintln!("[{}] email digest failed: {}", now, e),
+                }
+            }
+        }
+
+        // Wake for the most frequent per-source schedule
+        let sleep = ctx
+            .sources
+            .iter()
+            .filter(|s| s.enabled)
+            .filter_map(|s| s.refresh_interval)
+            .fold(ctx.resolved.refresh_interval, u64::min);
+        std::thread::sleep(Duration::from_secs(sleep.max(1)));
+    }
+}
+
+// ============================================================
+// sources / tickers
+// ============================================================
+
+fn run_sources(ctx: &Context) -> CliResult {
+    let stats = ctx.db.source_stats()?;
+    let now = chrono::Utc::now().timestamp();
+
+    let mut report = Vec::new();
+    for source in ctx.sources {
+        let stat = stats.iter().find(|(name, _, _)| *name == source.name);
+        if ctx.json {
+            report.push(serde_json::json!({
+                "name": source.name,
+                "url": source.url,
+                "enabled": source.enabled,
+                "category": source.category,
+                "tags": source.tags,
+                "refresh_interval": source.refresh_interval,
+                "mute_content": source.mute_content,
+                "articles": stat.map(|(_, count, _)| *count).unwrap_or(0),
+                "last_fetched_at": stat.map(|(_, _, last)| *last),
+            }));
+            continue;
+        }
+        let check = if source.enabled { "[x]" } else { "[ ]" };
+        let health = match stat {
+            Some((_, count, last)) => {
+                format!(
+                    "{} articles, last fetched {}h ago",
+                    count,
+                    (now - last) / 3600
+                )
+            }
+            None => "no articles stored".to_string(),
+        };
+        out!(
+            ctx,
+            "{} {:<16} {:<12} {:<36} {}",
+            check,
+            source.name,
+            source.category.as_deref().unwrap_or("-"),
+            health,
+            source.url
+        );
+    }
+
+    if ctx.json {
+        print_json(ctx, &serde_json::Value::from(report));
+    }
+    Ok(())
+}
+
+fn run_tickers(ctx: &Context) -> CliResult {
+    let counts = ctx.db.ticker_counts()?;
+    if counts.is_empty() {
+        return Err(CliError::NoResults("No tickers stored yet".to_string()));
+    }
+    if ctx.json {
+        let report: Vec<_> = counts
+            .iter()
+            .map(|(ticker, count)| serde_json::json!({ "ticker": ticker, "articles": count }))
+            .collect();
+        print_json(ctx, &serde_json::Value::from(report));
+        return Ok(());
+    }
+    for (ticker, count) in counts {
+        out!(ctx, "{}\t{}", ticker, count);
+    }
+    Ok(())
+}
+
+// ============================================================
+// stats
+// ============================================================
+
+fn run_stats(ctx: &Context) -> CliResult {
+    let db = ctx.db;
+    let total = db.article_count()?;
+    let unread = db.unread_count()?;
+    let bookmarked = db.bookmarked_count()?;
+    let size = db.size_bytes()?;
+    let sources = db.source_stats()?;
+    let sentiments = db.sentiment_counts()?;
+    let mut tickers = db.ticker_counts()?;
+    tickers.truncate(20);
+
+    if ctx.json {
+        let sources: serde_json::Map<_, _> = sources
+            .iter()
+            .map(|(name, count, _)| (name.clone(), serde_json::json!(count)))
+            .collect();
+        let sentiments: serde_json::Map<_, _> = sentiments
+            .iter()
+            .map(|(label, count)| (label.clone(), serde_json::json!(count)))
+            .collect();
+        let tickers: serde_json::Map<_, _> = tickers
+            .iter()
+            .map(|(ticker, count)| (ticker.clone(), serde_json::json!(count)))
+            .collect();
+        print_json(
+            ctx,
+            &serde_json::json!({
+                "total": total,
+                "unread": unread,
+                "bookmarked": bookmarked,
+                "db_size_bytes": size,
+                "sources": sources,
+                "sentiment": sentiments,
+                "top_tickers": tickers,
+            }),
+        );
+        return Ok(());
+    }
+
+    out!(
+        ctx,
+        "Articles:   {} total, {} unread, {} bookmarked",
+        total,
+        unread,
+        bookmarked
+    );
+    out!(ctx, "Database:   {:.1} MB", size as f64 / (1024.0 * 1024.0));
+    out!(ctx);
+    out!(ctx, "Sentiment:");
+    for (label, count) in &sentiments {
+        out!(ctx, "  {:<16} {}", label, count);
+    }
+    out!(ctx);
+    out!(ctx, "Sources:");
+    for (name, count, _) in &sources {
+        out!(ctx, "  {:<16} {}", name, count);
+    }
+    out!(ctx);
+    out!(ctx, "Top tickers:");
+    for (ticker, count) in &tickers {
+        out!(ctx, "  {:<16} {}", ticker, count);
+    }
+    Ok(())
+}
+
+// ============================================================
+// status
+// ============================================================
+
+/// Line printed by `status --format plain`
+const STATUS_PLAIN: &str = "{unread} unread, {alerts} alerts, trending {trending}";
+
+fn run_status(ctx: &Context, format: StatusFormat) -> CliResult {
+    let status = Status::gather(ctx.db, &ctx.resolved.watchlist);
+    let time = TimeDisplay::new(ctx.resolved.timezone.as_deref(), &ctx.resolved.date_format);
+    // Always a single line, so prompts and status bars can embed it
+    let line = match format {
+        StatusFormat::Plain if !ctx.json => status.render(STATUS_PLAIN, &time),
+        StatusFormat::Plain | StatusFormat::Json => status.to_json().to_string(),
+        StatusFormat::Waybar => status.waybar(&time).to_string(),
+    };
+    out!(ctx, "{}", line);
+    Ok(())
+}
+
+// ============================================================
+// doctor
+// ============================================================
+
+fn run_doctor(ctx: &Context) -> CliResult {
+    // (section, check, ok, detail)
+    let mut checks: Vec<(&str, String, bool, String)> = Vec::new();
+
+    // Config
+    let config_name = ctx.config_path.display().to_string();
+    match config::check_config(&ctx.config_path) {
+        Ok(Some((_, unknown))) => {
+            for key in unknown {
+                checks.push(("config", key, false, "unknown key, ignored".to_string()));
+            }
+            checks.push(("config", config_name, true, "parsed".to_string()));
+        }
+        Ok(None) => checks.push((
+            "config",
+            config_name,
+            true,
+            "not found, using defaults".to_string(),
+        )),
+        Err(e) => checks.push(("config", config_name, false, e)),
+    }
+
+    // Environment overrides
+    for (name, value) in std::env::vars().filter(|(k, _)| k.starts_with("STOCKNEWSTUI_")) {
+        checks.push(("env", name, true, value));
+    }
+
+    // Ticker patterns
+    for pattern in &ctx.resolved.tickers.patterns {
+        match regex::Regex::new(&pattern.regex) {
+            Ok(_) => checks.push(("tickers", pattern.regex.clone(), true, "valid".to_string())),
+            Err(e) => checks.push(("tickers", pattern.regex.clone(), false, e.to_string())),
+        }
+    }
+
+    // Boilerplate rules
+    for pattern in &ctx.resolved.boilerplate.patterns {
+        match regex::Regex::new(pattern) {
+            Ok(_) => checks.push(("boilerplate", pattern.clone(), true, "valid".to_string())),
+            Err(e) => checks.push(("boilerplate", pattern.clone(), false, e.to_string())),
+        }
+    }
+    for selector in &ctx.resolved.boilerplate.selectors {
+        match scraper::Selector::parse(selector) {
+            Ok(_) => checks.push(("boilerplate", selector.clone(), true, "valid".to_string())),
+            Err(e) => checks.push(("boilerplate", selector.clone(), false, e.to_string())),
+        }
+    }
+    let (_, key_errors) = keymap::Keymap::from_config(&ctx.resolved.keys);
+    for binding in key_errors {
+        checks.push(("keys", binding, false, "unknown key name".to_string()));
+    }
+    if let Some(ref path) = ctx.resolved.script {
+        let name = path.display().to_string();
+        match Script::load(path) {
+            Ok(_) => checks.push(("script", name, true, "compiled".to_string())),
+            Err(e) => checks.push(("script", name, false, e)),
+        }
+    }
+    for source in ctx.sources {
+        for selector in &source.content_selectors {
+            let name = format!("{}: {}", source.name, selector);
+            match scraper::Selector::parse(selector) {
+                Ok(_) => checks.push(("selectors", name, true, "valid".to_string())),
+                Err(e) => checks.push(("selectors", name, false, e.to_string())),
+            }
+        }
+    }
+
+    // Sources
+    let rt = tokio::runtime::Runtime::new()?;
+    let client = build_client()?;
+    for source in ctx.sources {
+        if !source.enabled {
+            checks.push(("source", source.name.clone(), true, "disabled".to_string()));
+            continue;
+        }
+        match rt.block_on(feed::check_source(&client, source)) {
+            Ok((status, entries)) => checks.push((
+                "source",
+                source.name.clone(),
+                entries > 0,
+                format!("HTTP {}, {} entries", status, entries),
+            )),
+            Err(e) => checks.push(("source", source.name.clone(), false, e)),
+        }
+    }
+
+    // Database
+    let db_name = ctx.db_path.display().to_string();
+    match ctx.db.integrity_check() {
+        Ok(result) => checks.push(("database", db_name.clone(), result == "ok", result)),
+        Err(e) => checks.push(("database", db_name.clone(), false, e.to_string())),
+    }
+    let writable = ctx
+        .db_path
+        .metadata()
+        .map(|m| !m.permissions().readonly())
+        .unwrap_or(false);
+    checks.push((
+        "database",
+        db_name,
+        writable,
+        if writable { "writable" } else { "not writable" }.to_string(),
+    ));
+
+    // Saved view state
+    let state_dir = config::state_dir();
+    checks.push((
+        "state",
+        state_dir.display().to_string(),
+        state_dir.is_dir(),
+        if state_dir.is_dir() { "ok" } else { "missing" }.to_string(),
+    ));
+
+    // Terminal
+    let term = std::env::var("TERM").unwrap_or_default();
+    let colorterm = std::env::var("COLORTERM").unwrap_or_default();
+    let truecolor = colorterm == "truecolor" || colorterm == "24bit";
+    checks.push((
+        "terminal",
+        format!("TERM={}", term),
+        !term.is_empty() && term != "dumb",
+        if truecolor {
+            "truecolor supported (solarized/gruvbox render exactly)".to_string()
+        } else {
+            "no truecolor; prefer the dark or light theme".to_string()
+        },
+    ));
+    let is_tty = io::IsTerminal::is_terminal(&io::stdout());
+    checks.push((
+        "terminal",
+        "stdout".to_string(),
+        is_tty,
+        if is_tty {
+            "interactive, mouse capture available".to_string()
+        } else {
+            "not a terminal; the TUI needs an interactive terminal".to_string()
+        },
+    ));
+
+    if ctx.json {
+        let report: Vec<_> = checks
+            .iter()
+            .map(|(section, name, ok, detail)| {
+                serde_json::json!({ "section": section, "name": name, "ok": ok, "detail": detail })
+            })
+            .collect();
+        print_json(ctx, &serde_json::Value::from(report));
+    } else {
+        for (section, name, ok, detail) in &checks {
+            let mark = if *ok { "ok  " } else { "FAIL" };
+            out!(ctx, "[{}] {:<9} {:<20} {}", mark, section, name, detail);
+        }
+    }
+
+    let failures = checks.iter().filter(|(_, _, ok, _)| !ok).count();
+    if let Some((section, name, _, detail)) = checks.iter().find(|(_, _, ok, _)| !ok) {
+        let msg = format!(
+            "{} check(s) failed, first: {} {}: {}",
+            failures, section, name, detail
+        );
+        return Err(match *section {
+            "config" => CliError::Config(msg),
+            "source" => CliError::Network(msg),
+            "database" => CliError::Db(msg),
+            _ => CliError::Other(msg),
+        });
+    }
+    Ok(())
+}
+
+// ============================================================
+// digest
+// ============================================================
+
+fn run_digest(ctx: &Context, hours: u64, by: DigestGroup) -> CliResult {
+    let articles = load_digest_articles(ctx, hours)?;
+    if articles.is_empty() {
+        return Err(CliError::NoResults(format!(
+            "No articles in the last {}h",
+            hours
+        )));
+    }
+
+    if ctx.json {
+        print_json(
+            ctx,
+            &serde_json::json!({ "hours": hours, "articles": articles }),
+        );
+        return Ok(());
+    }
+
+    if !ctx.quiet {
+        let time = TimeDisplay::new(ctx.resolved.timezone.as_deref(), &ctx.resolved.date_format);
+        print!("{}", digest::render_text(hours, &articles, by, &time));
+    }
+    Ok(())
+}
+
+fn load_digest_articles(ctx: &Context, hours: u64) -> Result<Vec<Article>, CliError> {
+    let since = chrono::Utc::now().timestamp() - (hours as i64) * 3600;
+    Ok(ctx
+        .db
+        .get_articles_since(since, 1000)?
+        .into_iter()
+        .filter(|a| matches_watchlist(a, &ctx.resolved.watchlist))
+        .collect())
+}
+
+// ============================================================
+// email-digest
+// ============================================================
+
+fn run_email_digest(ctx: &Context, hours: u64) -> CliResult {
+    let articles = load_digest_articles(ctx, hours)?;
+    if articles.is_empty() {
+        return Err(CliError::NoResults(format!(
+            "No articles in the last {}h, nothing sent",
+            hours
+        )));
+    }
+    send_digest_email(ctx, hours, &articles).map_err(CliError::Other)?;
+    if ctx.json {
+        print_json(
+            ctx,
+            &serde_json::json!({ "sent": true, "articles": articles.len() }),
+        );
+    } else {
+        out!(ctx, "Sent digest with {} articles", articles.len());
+    }
+    Ok(())
+}
+
+fn send_digest_email(ctx: &Context, hours: u64, articles: &[Article]) -> Result<(), String> {
+    let cfg = &ctx.resolved.email;
+    let time = TimeDisplay::new(ctx.resolved.timezone.as_deref(), &ctx.resolved.date_format);
+    let subject = format!(
+        "{} - {}",
+        cfg.subject,
+        time.format(chrono::Utc::now().timestamp(), "%Y-%m-%d")
+    );
+    let body = if cfg.html {
+        digest::render_html(hours, articles, &time)
+    } else {
+        digest::render_text(hours, articles, DigestGroup::Ticker, &time)
+    };
+    digest::send_email(cfg, &subject, &body, cfg.html)
+}
+
+// ============================================================
+// add-article
+// ============================================================
+
+fn run_add_article(ctx: &Context, url: &str) -> CliResult {
+    let rt = tokio::runtime::Runtime::new()?;
+    let client = build_client()?;
+    let (article, content) = rt
+        .block_on(feed::fetch_single_article(&client, url))
+        .map_err(CliError::Network)?;
+
+    let inserted = ctx.db.insert_article(&article)?;
+    if let Some(id) = ctx.db.article_id_by_url(url)? {
+        ctx.db.save_content(id, &content)?;
+    }
+
+    if ctx.json {
+        print_json(
+            ctx,
+            &serde_json::json!({ "inserted": inserted, "article": article }),
+        );
+    } else if inserted {
+        out!(
+            ctx,
+            "Added: {} [{}]",
+            article.title,
+            article.tickers.join(",")
+        );
+    } else {
+        out!(ctx, "Already stored: {}", article.title);
+    }
+    Ok(())
+}
+
+// ============================================================
+// export-rss
+// ============================================================
+
+fn run_export_rss(ctx: &Context, search: Option<&str>, output: Option<&PathBuf>) -> CliResult {
+    let (title, articles) = match search {
+        Some(query) => (
+            format!("StockNewsTUI search: {}", query),
+            ctx.db.search_articles(query, 500)?,
+        ),
+        None => (
+            "StockNewsTUI bookmarks".to_string(),
+            ctx.db.get_bookmarked_articles(500)?,
+        ),
+    };
+    if articles.is_empty() {
+        return Err(CliError::NoResults(format!(
+            "Nothing to export for {}",
+            title
+        )));
+    }
+    let mut highlights = HashMap::new();
+    for article in &articles {
+        highlights.insert(article.id, ctx.db.get_highlights(Some(article.id), 100)?);
+    }
+    let xml = export::render_rss(&title, &articles, &highlights);
+
+    match output {
+        Some(path) => {
+            std::fs::write(path, xml)?;
+            if ctx.json {
+                print_json(
+                    ctx,
+                    &serde_json::json!({ "path": path, "articles": articles.len() }),
+                );
+            } else {
+                out!(
+                    ctx,
+                    "Wrote {} articles to {}",
+                    articles.len(),
+                    path.display()
+                );
+            }
+        }
+        None if !ctx.quiet => print!("{}", xml),
+        None => {}
+    }
+    Ok(())
+}
+
+// ============================================================
+// serve
+// ============================================================
+
+/// Articles listed per section of the served page
+const SERVE_LIMIT: usize = 200;
+
+fn run_serve(ctx: &Context, bind: Option<&str>) -> CliResult {
+    let bind = bind.unwrap_or(&ctx.resolved.serve.bind);
+    let listener = std::net::TcpListener::bind(bind)
+        .map_err(|e| CliError::Config(format!("Cannot listen on {}: {}", bind, e)))?;
+    let addr = listener.local_addr()?;
+    if ctx.json {
+        print_json(ctx, &serde_json::json!({ "listening": addr.to_string() }));
+    } else {
+        out!(ctx, "Serving bookmarks on http://{} (Ctrl-C to stop)", addr);
+    }
+
+    let time = TimeDisplay::new(ctx.resolved.timezone.as_deref(), &ctx.resolved.date_format);
+    let searches = &ctx.resolved.serve.searches;
+    serve::run(listener, || {
+        let bookmarks = ctx
+            .db
+            .get_bookmarked_articles(SERVE_LIMIT)
+            .map_err(|e| e.to_string())?;
+        let mut results = Vec::new();
+        for query in searches {
+            let articles = ctx
+                .db
+                .search_articles(query, SERVE_LIMIT)
+                .map_err(|e| e.to_string())?;
+            results.push((query.clone(), articles));
+        }
+        Ok(serve::render_page(&bookmarks, &results, &time))
+    })?;
+    Ok(())
+}
+
+// ============================================================
+// source add/remove/enable/disable
+// ============================================================
+
+fn run_source_action(ctx: &Context, action: &SourceAction) -> CliResult {
+    // Edit the file's own list; ctx.sources may be a preset's or --demo's
+    let mut sources = match config::check_config(&ctx.config_path) {
+        Ok(Some((cfg, _))) => config::sources_from_config(&cfg),
+        Ok(None) => FeedSource::defaults(),
+        Err(e) => {
+            return Err(CliError::Config(format!(
+                "{}: {}",
+                ctx.config_path.display(),
+                e
+            )))
+        }
+    };
+    let find = |sources: &[FeedSource], name: &str| {
+        sources
+            .iter()
+            .position(|s| s.name.eq_ignore_ascii_case(name))
+            .ok_or_else(|| CliError::Config(format!("No source named '{}'", name)))
+    };
+
+    let (done, name) = match action {
+        SourceAction::Add { name, url } => {
+            if find(&sources, name).is_ok() {
+                return Err(CliError::Config(format!(
+                    "Source '{}' already exists",
+                    name
+                )));
+            }
+            sources.push(FeedSource::new(name, url));
+            ("added", name.clone())
+        }
+        SourceAction::Remove { name } => {
+            let idx = find(&sources, name)?;
+            ("removed", sources.remove(idx).name)
+        }
+        SourceAction::Enable { name } | SourceAction::Disable { name } => {
+            let idx = find(&sources, name)?;
+            let enabled = matches!(action, SourceAction::Enable { .. });
+            sources[idx].enabled = enabled;
+            let done = if enabled { "enabled" } else { "disabled" };
+            (done, sources[idx].name.clone())
+        }
+    };
+
+    config::save_sources(&sources)
+        .map_err(|e| CliError::Config(format!("Sources not saved: {}", e)))?;
+    report(ctx, done, &name);
+    Ok(())
+}
+
+fn report(ctx: &Context, action: &str, name: &str) {
+    if ctx.json {
+        print_json(
+            ctx,
+            &serde_json::json!({ "action": action, "source": name }),
+        );
+    } else {
+        out!(ctx, "{}: {}", name, action);
+    }
+}
+
+// ============================================================
+// companies
+// ============================================================
+
+fn run_companies(ctx: &Context, action: &CompanyAction) -> CliResult {
+    let companies = match action {
+        CompanyAction::List => {
+            let companies = ctx.db.get_companies()?;
+            if ctx.json {
+                let report: Vec<_> = companies
+                    .iter()
+                    .map(|c| {
+                        serde_json::json!({
+                            "ticker": c.ticker,
+                            "name": c.name,
+                            "aliases": c.aliases,
+                        })
+                    })
+                    .collect();
+                print_json(ctx, &serde_json::Value::from(report));
+                return Ok(());
+            }
+            for c in companies {
+                out!(ctx, "{}\t{}\t{}", c.ticker, c.name, c.aliases.join("; "));
+            }
+            return Ok(());
+        }
+        CompanyAction::Import { path } => {
+            let text = std::fs::read_to_string(path)?;
+            let companies = companies::parse_csv(&text);
+            if companies.is_empty() {
+                return Err(CliError::NoResults(format!(
+                    "No companies found in {}",
+                    path.display()
+                )));
+            }
+            companies
+        }
+        CompanyAction::Reset => companies::bundled(),
+    };
+
+    let count = ctx.db.upsert_companies(&companies)?;
+    if ctx.json {
+        print_json(ctx, &serde_json::json!({ "updated": count }));
+    } else {
+        out!(ctx, "Updated {} companies", count);
+    }
+    Ok(())
+}
+
+// ============================================================
+// reanalyze
+// ============================================================
+
+fn run_reanalyze(ctx: &Context) -> CliResult {
+    let total = ctx.db.article_count()?;
+    // Leave scores from an external backend alone
+    let rescore = sentiment::Backend::from_config(&ctx.resolved.sentiment_backend).is_none();
+    let mut after = 0;
+    let mut changed = 0;
+    while let (n, Some(last)) = ctx.db.reanalyze_batch(after, 500, rescore)? {
+        changed += n;
+        after = last;
+    }
+    let companies = ctx.db.get_companies()?;
+    let fingerprint = feed::analysis_fingerprint(&ctx.resolved.tickers, &companies);
+    ctx.db.set_meta(ANALYSIS_KEY, &fingerprint)?;
+
+    if ctx.json {
+        print_json(
+            ctx,
+            &serde_json::json!({ "total": total, "updated": changed }),
+        );
+    } else {
+        out!(ctx, "Re-analyzed {} articles, {} updated", total, changed);
+    }
+    Ok(())
+}
+
+// ============================================================
+// watchlist
+// ============================================================
+
+fn run_watchlist(ctx: &Context, action: &WatchlistAction) -> CliResult {
+    let mut tickers = ctx.resolved.watchlist.clone();
+    match action {
+        WatchlistAction::List => {
+            if ctx.json {
+                print_json(ctx, &serde_json::json!(tickers));
+            } else {
+                for ticker in &tickers {
+                    out!(ctx, "{}", ticker);
+                }
+            }
+        }
+        WatchlistAction::Import { path } => {
+            let text = std::fs::read_to_string(path)?;
+            let imported = watchlist::parse_csv(&text);
+            if imported.is_empty() {
+                return Err(CliError::NoResults(format!(
+                    "No tickers found in {}",
+                    path.display()
+                )));
+            }
+            let added = watchlist::merge(&mut tickers, &imported);
+            config::save_watchlist(&tickers)
+                .map_err(|e| CliError::Config(format!("Watchlist not saved: {}", e)))?;
+            if ctx.json {
+                print_json(
+                    ctx,
+                    &serde_json::json!({
+                        "found": imported.len(),
+                        "added": added,
+                        "watchlist": tickers,
+                    }),
+                );
+            } else {
+                out!(
+                    ctx,
+                    "Found {} tickers, added {}; watchlist now has {}",
+                    imported.len(),
+                    added,
+                    tickers.len()
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+// ============================================================
+// config
+// ============================================================
+
+fn run_config_check(ctx: &Context) -> CliResult {
+    let path = ctx.config_path.display().to_string();
+    let unknown = match config::check_config(&ctx.config_path) {
+        Ok(Some((_, unknown))) => unknown,
+        Ok(None) => {
+            if ctx.json {
+                print_json(ctx, &serde_json::json!({ "path": path, "exists": false }));
+            } else {
+                out!(ctx, "{} not found; using defaults", path);
+            }
+            return Ok(());
+        }
+        Err(e) => return Err(CliError::Config(format!("{}: {}", path, e))),
+    };
+
+    if ctx.json {
+        print_json(
+            ctx,
+            &serde_json::json!({ "path": path, "exists": true, "unknown_keys": unknown }),
+        );
+    } else {
+        for key in &unknown {
+            out!(ctx, "unknown key: {}", key);
+        }
+    }
+    if !unknown.is_empty() {
+        return Err(CliError::Config(format!(
+            "{} has {} unknown key(s); check for typos",
+            path,
+            unknown.len()
+        )));
+    }
+    out!(ctx, "{} is valid", path);
+    Ok(())
+}
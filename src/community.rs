@@ -0,0 +1,89 @@
+use crate::config::CommunityConfig;
+use crate::model::SentimentMeter;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+/// Gap between per-ticker requests to the community endpoint
+const REQUEST_SPACING: Duration = Duration::from_millis(500);
+
+/// Fetch mention count and crowd sentiment for one ticker. The endpoint
+/// (`{ticker}` is substituted) answers with JSON carrying `mentions` (or
+/// `count`) and either a `score`/`sentiment` from -1.0 to 1.0 or raw
+/// `bullish`/`bearish` counts, as forum trackers usually report.
+pub async fn fetch_mentions(
+    client: &reqwest::Client,
+    url: &str,
+    token: Option<&str>,
+    ticker: &str,
+) -> Result<SentimentMeter, String> {
+    let mut req = client.get(url.replace("{ticker}", ticker));
+    if let Some(token) = token {
+        req = req.bearer_auth(token);
+    }
+    let resp = req
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    let bullish = json["bullish"].as_f64();
+    let bearish = json["bearish"].as_f64();
+    let score = json["score"]
+        .as_f64()
+        .or_else(|| json["sentiment"].as_f64())
+        .or_else(|| match (bullish, bearish) {
+            (Some(b), Some(r)) if b + r > 0.0 => Some((b - r) / (b + r)),
+            _ => None,
+        })
+        .unwrap_or(0.0)
+        .clamp(-1.0, 1.0);
+    let count = json["mentions"]
+        .as_i64()
+        .or_else(|| json["count"].as_i64())
+        .or_else(|| Some((bullish? + bearish?) as i64))
+        .ok_or_else(|| "Response has no mention count".to_string())?;
+
+    Ok(SentimentMeter {
+        window: "crowd".to_string(),
+        score,
+        count,
+    })
+}
+
+/// Fetch community stats for `tickers` in the background and send the
+/// ones that answered back over `tx`
+pub fn spawn_community_fetch(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    cfg: &CommunityConfig,
+    tickers: Vec<String>,
+    tx: &mpsc::Sender<Vec<(String, SentimentMeter)>>,
+) {
+    let Some(url) = cfg.url.clone() else {
+        return;
+    };
+    let token = cfg
+        .token_env
+        .as_deref()
+        .and_then(|var| std::env::var(var).ok());
+    let client = client.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let mut stats = Vec::new();
+        for ticker in tickers {
+            if let Ok(meter) = fetch_mentions(&client, &url, token.as_deref(), &ticker).await {
+                stats.push((ticker, meter));
+            }
+            tokio::time::sleep(REQUEST_SPACING).await;
+        }
+        if !stats.is_empty() {
+            let _ = tx.send(stats).await;
+        }
+    });
+}
@@ -0,0 +1,187 @@
+/// An IDX-listed company: ticker code, registered name, and the short
+/// names headlines tend to use instead.
+#[derive(Debug, Clone)]
+pub struct Company {
+    pub ticker: String,
+    pub name: String,
+    pub aliases: Vec<String>,
+}
+
+/// Bundled seed list of actively covered IDX tickers. Users can extend or
+/// correct it with `stocknewstui companies import`.
+const BUNDLED: &[(&str, &str, &[&str])] = &[
+    // Banks
+    ("BBCA", "Bank Central Asia", &["BCA"]),
+    ("BBRI", "Bank Rakyat Indonesia", &["BRI"]),
+    ("BMRI", "Bank Mandiri", &["Mandiri"]),
+    ("BBNI", "Bank Negara Indonesia", &["BNI"]),
+    ("BBTN", "Bank Tabungan Negara", &["BTN"]),
+    ("BRIS", "Bank Syariah Indonesia", &["BSI"]),
+    ("BNGA", "Bank CIMB Niaga", &["CIMB Niaga"]),
+    ("BDMN", "Bank Danamon Indonesia", &["Danamon"]),
+    ("NISP", "Bank OCBC NISP", &["OCBC NISP"]),
+    ("PNBN", "Bank Pan Indonesia", &["Panin Bank"]),
+    ("MEGA", "Bank Mega", &[]),
+    ("ARTO", "Bank Jago", &["Jago"]),
+    ("BBYB", "Bank Neo Commerce", &[]),
+    ("BBHI", "Allo Bank Indonesia", &["Allo Bank"]),
+    // Telco & towers
+    ("TLKM", "Telkom Indonesia", &["Telkom"]),
+    ("EXCL", "XL Axiata", &["XL"]),
+    ("ISAT", "Indosat Ooredoo Hutchison", &["Indosat"]),
+    ("TOWR", "Sarana Menara Nusantara", &["Protelindo"]),
+    ("TBIG", "Tower Bersama Infrastructure", &["Tower Bersama"]),
+    ("MTEL", "Dayamitra Telekomunikasi", &["Mitratel"]),
+    ("WIFI", "Solusi Sinergi Digital", &["Surge"]),
+    // Conglomerates & autos
+    ("ASII", "Astra International", &["Astra"]),
+    ("UNTR", "United Tractors", &[]),
+    ("AUTO", "Astra Otoparts", &[]),
+    ("AALI", "Astra Agro Lestari", &[]),
+    ("SRTG", "Saratoga Investama Sedaya", &["Saratoga"]),
+    // Consumer
+    ("UNVR", "Unilever Indonesia", &["Unilever"]),
+    ("ICBP", "Indofood CBP Sukses Makmur", &["Indofood CBP"]),
+    ("INDF", "Indofood Sukses Makmur", &["Indofood"]),
+    ("MYOR", "Mayora Indah", &["Mayora"]),
+    ("GGRM", "Gudang Garam", &[]),
+    ("HMSP", "HM Sampoerna", &["Sampoerna"]),
+    ("KLBF", "Kalbe Farma", &["Kalbe"]),
+    (
+        "SIDO",
+        "Industri Jamu dan Farmasi Sido Muncul",
+        &["Sido Muncul"],
+    ),
+    ("CPIN", "Charoen Pokphand Indonesia", &["Charoen Pokphand"]),
+    ("JPFA", "Japfa Comfeed Indonesia", &["Japfa"]),
+    // Retail & media
+    ("AMRT", "Sumber Alfaria Trijaya", &["Alfamart"]),
+    ("MIDI", "Midi Utama Indonesia", &["Alfamidi"]),
+    ("ACES", "Aspirasi Hidup Indonesia", &["Ace Hardware"]),
+    ("MAPI", "Mitra Adiperkasa", &[]),
+    ("MAPA", "Map Aktif Adiperkasa", &[]),
+    ("ERAA", "Erajaya Swasembada", &["Erajaya"]),
+    ("GOTO", "GoTo Gojek Tokopedia", &["GoTo"]),
+    ("BUKA", "Bukalapak", &[]),
+    ("EMTK", "Elang Mahkota Teknologi", &["Emtek"]),
+    ("SCMA", "Surya Citra Media", &[]),
+    ("MNCN", "Media Nusantara Citra", &["MNC"]),
+    ("BMTR", "Global Mediacom", &[]),
+    // Energy & mining
+    (
+        "ADRO",
+        "Alamtri Resources Indonesia",
+        &["Adaro Energy", "Alamtri"],
+    ),
+    ("AADI", "Adaro Andalan Indonesia", &["Adaro Andalan"]),
+    ("PTBA", "Bukit Asam", &[]),
+    ("ITMG", "Indo Tambangraya Megah", &[]),
+    ("BUMI", "Bumi Resources", &[]),
+    ("HRUM", "Harum Energy", &[]),
+    ("INDY", "Indika Energy", &["Indika"]),
+    ("INCO", "Vale Indonesia", &["Vale"]),
+    ("ANTM", "Aneka Tambang", &["Antam"]),
+    ("MDKA", "Merdeka Copper Gold", &["Merdeka Copper"]),
+    ("MBMA", "Merdeka Battery Materials", &["Merdeka Battery"]),
+    ("NCKL", "Trimegah Bangun Persada", &["Harita Nickel"]),
+    ("AMMN", "Amman Mineral Internasional", &["Amman Mineral"]),
+    ("TINS", "Timah", &[]),
+    ("PGAS", "Perusahaan Gas Negara", &["PGN"]),
+    ("MEDC", "Medco Energi Internasional", &["Medco"]),
+    ("AKRA", "AKR Corporindo", &[]),
+    ("ELSA", "Elnusa", &[]),
+    ("RAJA", "Rukun Raharja", &[]),
+    (
+        "PGEO",
+        "Pertamina Geothermal Energy",
+        &["Pertamina Geothermal"],
+    ),
+    ("BREN", "Barito Renewables Energy", &["Barito Renewables"]),
+    ("CUAN", "Petrindo Jaya Kreasi", &["Petrindo"]),
+    // Basic industry
+    ("BRPT", "Barito Pacific", &[]),
+    ("TPIA", "Chandra Asri Pacific", &["Chandra Asri"]),
+    ("ESSA", "ESSA Industries Indonesia", &[]),
+    ("INKP", "Indah Kiat Pulp & Paper", &["Indah Kiat"]),
+    ("TKIM", "Pabrik Kertas Tjiwi Kimia", &["Tjiwi Kimia"]),
+    ("SMGR", "Semen Indonesia", &[]),
+    ("INTP", "Indocement Tunggal Prakarsa", &["Indocement"]),
+    ("KRAS", "Krakatau Steel", &[]),
+    // Plantations
+    ("LSIP", "PP London Sumatra Indonesia", &["Lonsum"]),
+    ("DSNG", "Dharma Satya Nusantara", &[]),
+    ("SSMS", "Sawit Sumbermas Sarana", &[]),
+    // Infrastructure & construction
+    ("JSMR", "Jasa Marga", &[]),
+    ("WIKA", "Wijaya Karya", &[]),
+    ("PTPP", "Pembangunan Perumahan", &[]),
+    ("ADHI", "Adhi Karya", &[]),
+    ("WSKT", "Waskita Karya", &["Waskita"]),
+    ("GIAA", "Garuda Indonesia", &[]),
+    // Property
+    ("BSDE", "Bumi Serpong Damai", &[]),
+    ("CTRA", "Ciputra Development", &["Ciputra"]),
+    ("PWON", "Pakuwon Jati", &["Pakuwon"]),
+    ("SMRA", "Summarecon Agung", &["Summarecon"]),
+    ("PANI", "Pantai Indah Kapuk Dua", &["PIK 2"]),
+    // Healthcare
+    ("HEAL", "Medikaloka Hermina", &["Hermina"]),
+    ("MIKA", "Mitra Keluarga Karyasehat", &["Mitra Keluarga"]),
+    ("SILO", "Siloam International Hospitals", &["Siloam"]),
+];
+
+/// Names and aliases that are also everyday words or group names ("timah"
+/// is tin, "semen Indonesia" is the country's cement, "MNC" is the whole
+/// group), counted only when a headline marks them as the listed company:
+/// "PT Timah" or "Timah Tbk"
+pub const MARKED_NAMES: &[&str] = &[
+    "Timah",
+    "XL",
+    "Surge",
+    "MNC",
+    "Mandiri",
+    "Jago",
+    "Vale",
+    "Mega",
+    "Semen Indonesia",
+    "Pembangunan Perumahan",
+];
+
+pub fn bundled() -> Vec<Company> {
+    BUNDLED
+        .iter()
+        .map(|(ticker, name, aliases)| Company {
+            ticker: ticker.to_string(),
+            name: name.to_string(),
+            aliases: aliases.iter().map(|a| a.to_string()).collect(),
+        })
+        .collect()
+}
+
+/// Parse `TICKER,Name,alias;alias` lines (header and blank lines skipped)
+pub fn parse_csv(text: &str) -> Vec<Company> {
+    text.lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, ',').map(|f| f.trim().trim_matches('"'));
+            let ticker = fields.next()?.to_uppercase();
+            let name = fields.next()?.to_string();
+            if ticker.is_empty() || name.is_empty() || ticker == "TICKER" {
+                return None;
+            }
+            let aliases = fields
+                .next()
+                .map(|a| {
+                    a.split(';')
+                        .map(|s| s.trim().to_string())
+                        .filter(|s| !s.is_empty())
+                        .collect()
+                })
+                .unwrap_or_default();
+            Some(Company {
+                ticker,
+                name,
+                aliases,
+            })
+        })
+        .collect()
+}
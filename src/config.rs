@@ -1,8 +1,10 @@
-use crate::model::ThemeName;
-use clap::Parser;
+use crate::model::{parse_window, FeedSource, FilterMode, Holding, SortMode, ThemeName, ViewMode};
+use clap::{Parser, Subcommand, ValueEnum};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::PathBuf;
+use std::sync::OnceLock;
 
 // --- CLI Arguments ---
 
@@ -12,7 +14,7 @@ pub struct CliArgs {
     /// Filter news by ticker symbols (e.g., BBCA TLKM BBRI)
     pub tickers: Vec<String>,
 
-    /// Color theme: dark, light, solarized, gruvbox
+    /// Color theme: dark, light, solarized, gruvbox, or auto to match the terminal
     #[arg(short, long)]
     pub theme: Option<String>,
 
@@ -20,14 +22,201 @@ pub struct CliArgs {
     #[arg(long, default_value = "300")]
     pub refresh: u64,
 
+    /// View to start in: feed or bookmarks
+    #[arg(long)]
+    pub view: Option<String>,
+
+    /// Sort order at startup: time or portfolio
+    #[arg(long)]
+    pub sort: Option<String>,
+
+    /// Filter at startup: all, watchlist, source, unread, or new
+    #[arg(long)]
+    pub filter: Option<String>,
+
     /// Path to config file
     #[arg(short, long)]
     pub config: Option<PathBuf>,
+
+    /// Use the watchlist, sources, and defaults from `[preset.<name>]`
+    #[arg(long, env = "STOCKNEWSTUI_PRESET", global = true)]
+    pub preset: Option<String>,
+
+    /// Path to the article database (overrides --data-dir for the DB)
+    #[arg(long, env = "STOCKNEWSTUI_DB", global = true)]
+    pub db: Option<PathBuf>,
+
+    /// Directory holding the database and downloaded images
+    #[arg(long, env = "STOCKNEWSTUI_DATA_DIR", global = true)]
+    pub data_dir: Option<PathBuf>,
+
+    /// Directory holding saved view state
+    #[arg(long, env = "STOCKNEWSTUI_STATE_DIR", global = true)]
+    pub state_dir: Option<PathBuf>,
+
+    /// Print a digest of the last 24 hours and exit (same as `digest`)
+    #[arg(long)]
+    pub once: bool,
+
+    /// Show bundled sample feeds in a throwaway database, without network
+    /// access or touching saved state
+    #[arg(long, global = true)]
+    pub demo: bool,
+
+    /// Emit JSON instead of text from non-interactive commands
+    #[arg(long, global = true)]
+    pub json: bool,
+
+    /// Suppress normal output from non-interactive commands
+    #[arg(short, long, global = true)]
+    pub quiet: bool,
+
+    /// Report errors from non-interactive commands as JSON on stderr
+    #[arg(long, global = true)]
+    pub errors_json: bool,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Fetch all enabled feeds once, store new articles, and exit
+    Fetch,
+    /// Keep fetching on the refresh interval without a UI
+    Daemon,
+    /// List configured feed sources with their stored article counts
+    Sources,
+    /// List every ticker seen in stored articles with article counts
+    Tickers,
+    /// Print article counts per source and ticker, sentiment breakdown, and DB size
+    Stats,
+    /// Print a one-line summary for a status bar or shell prompt
+    Status {
+        /// Plain text, the raw counts as JSON, or a waybar custom module object
+        #[arg(long, value_enum, default_value = "plain")]
+        format: StatusFormat,
+    },
+    /// Serve a read-only web page of bookmarks and saved searches
+    Serve {
+        /// Address to listen on, over `serve.bind` in the config
+        #[arg(long)]
+        bind: Option<String>,
+    },
+    /// Check config, sources, database, and terminal support
+    Doctor,
+    /// Print a digest of recent news and exit
+    Digest {
+        /// How many hours back to include
+        #[arg(long, default_value = "24")]
+        hours: u64,
+        /// Group articles by ticker or by source
+        #[arg(long, value_enum, default_value = "ticker")]
+        by: DigestGroup,
+    },
+    /// Email a digest of recent watchlist news via the configured mail command
+    EmailDigest {
+        /// How many hours back to include (168 for a weekly digest)
+        #[arg(long, default_value = "24")]
+        hours: u64,
+    },
+    /// Fetch an article by URL and store it like a feed item
+    AddArticle { url: String },
+    /// Write bookmarks or a saved search as an RSS feed
+    ExportRss {
+        /// Export articles matching this search instead of bookmarks
+        #[arg(long)]
+        search: Option<String>,
+        /// Output file (defaults to stdout)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+    /// Add, remove, enable, or disable feed sources
+    Source {
+        #[command(subcommand)]
+        action: SourceAction,
+    },
+    /// List or update the IDX ticker/company database
+    Companies {
+        #[command(subcommand)]
+        action: CompanyAction,
+    },
+    /// Re-run ticker extraction and sentiment scoring over stored articles
+    Reanalyze,
+    /// Show the watchlist or merge tickers into it from a CSV export
+    Watchlist {
+        #[command(subcommand)]
+        action: WatchlistAction,
+    },
+    /// Validate the config file
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
+    },
+}
+
+impl Command {
+    /// The digest `--once` runs
+    pub fn default_digest() -> Self {
+        Command::Digest {
+            hours: 24,
+            by: DigestGroup::Ticker,
+        }
+    }
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum StatusFormat {
+    Plain,
+    Json,
+    Waybar,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq)]
+pub enum DigestGroup {
+    Ticker,
+    Source,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum SourceAction {
+    /// Add a new feed source
+    Add { name: String, url: String },
+    /// Remove a feed source by name
+    Remove { name: String },
+    /// Enable a feed source by name
+    Enable { name: String },
+    /// Disable a feed source by name
+    Disable { name: String },
+}
+
+#[derive(Subcommand, Debug)]
+pub enum ConfigAction {
+    /// Parse the config strictly and list keys no setting reads
+    Check,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum CompanyAction {
+    /// List known tickers, names, and aliases
+    List,
+    /// Add or update companies from a `TICKER,Name,alias;alias` CSV file
+    Import { path: PathBuf },
+    /// Re-apply the bundled company list
+    Reset,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum WatchlistAction {
+    /// Print the configured watchlist
+    List,
+    /// Merge tickers from a broker portfolio export or Stockbit watchlist CSV
+    Import { path: PathBuf },
 }
 
 // --- Config File ---
 
-#[derive(Debug, Deserialize, Serialize, Default)]
+#[derive(Debug, Deserialize, Serialize)]
 pub struct ConfigFile {
     #[serde(default)]
     pub watchlist: Vec<String>,
@@ -35,10 +224,680 @@ pub struct ConfigFile {
     pub refresh_interval: u64,
     #[serde(default)]
     pub theme: Option<String>,
+    /// View to start in: "feed" or "bookmarks"
+    #[serde(default)]
+    pub default_view: Option<String>,
+    /// Sort order at startup, over the one saved from last session
+    #[serde(default)]
+    pub default_sort: Option<String>,
+    /// Filter at startup, over the one saved from last session
+    #[serde(default)]
+    pub default_filter: Option<String>,
     #[serde(default = "default_min_fetch")]
     pub min_fetch_interval: u64,
     #[serde(default)]
     pub sources: Vec<SourceConfig>,
+    #[serde(default)]
+    pub on_new_article: Option<String>,
+    /// Portfolio positions; their articles get a marker and can sort first
+    #[serde(default)]
+    pub holdings: Vec<Holding>,
+    /// Command run for each new article about a holding
+    #[serde(default)]
+    pub on_holding_article: Option<String>,
+    /// Rhai script with on_insert, on_display, and on_key hooks
+    #[serde(default)]
+    pub script: Option<String>,
+    /// Endpoints sent each new watchlist or alert article
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+    #[serde(default)]
+    pub ipc: IpcConfig,
+    #[serde(default)]
+    pub status_file: StatusFileConfig,
+    #[serde(default)]
+    pub serve: ServeConfig,
+    #[serde(default)]
+    pub confirm_quit: bool,
+    /// Show an estimated reading time column in the feed
+    #[serde(default)]
+    pub reading_time_column: bool,
+    /// Text-to-speech command reading the article on stdin, e.g. "espeak" or "say"
+    #[serde(default)]
+    pub tts_command: Option<String>,
+    /// Clipboard command for copying article text, e.g. "wl-copy"; unset
+    /// copies through the terminal (OSC 52)
+    #[serde(default)]
+    pub clipboard_command: Option<String>,
+    /// Command the reader pipes article text into, e.g. "glow -" or "less"
+    #[serde(default)]
+    pub pipe_command: Option<String>,
+    /// Zone for dates and times: WIB, WITA, WIT, UTC, an offset like
+    /// "+07:00", or an IANA name such as "Asia/Makassar"; unset uses the
+    /// system zone
+    #[serde(default)]
+    pub timezone: Option<String>,
+    /// strftime format for dates, e.g. "%d/%m/%Y"
+    #[serde(default = "default_date_format")]
+    pub date_format: String,
+    /// Proxy for feeds and article pages, e.g. "http://127.0.0.1:3128"
+    #[serde(default)]
+    pub proxy: Option<String>,
+    /// Stop auto-refreshing after this many minutes without a keypress (0 = never)
+    #[serde(default)]
+    pub idle_pause_minutes: u64,
+    /// Articles loaded into the feed at once
+    #[serde(default = "default_article_limit")]
+    pub article_limit: usize,
+    /// Older articles loaded when moving past the end of the feed (0 = never)
+    #[serde(default = "default_page_size")]
+    pub page_size: usize,
+    #[serde(default)]
+    pub email: EmailConfig,
+    #[serde(default)]
+    pub quotes: QuotesConfig,
+    /// Windows for the per-ticker sentiment meter, e.g. ["24h", "7d", "30d"]
+    #[serde(default = "default_sentiment_windows")]
+    pub sentiment_windows: Vec<String>,
+    #[serde(default)]
+    pub sentiment_backend: SentimentBackendConfig,
+    /// Topic tags and the keywords that trigger them, e.g. coal = ["batu bara"]
+    #[serde(default = "default_topics")]
+    pub topics: BTreeMap<String, Vec<String>>,
+    #[serde(default)]
+    pub tickers: TickerConfig,
+    #[serde(default)]
+    pub boilerplate: BoilerplateConfig,
+    #[serde(default)]
+    pub network: NetworkConfig,
+    #[serde(default)]
+    pub cache: CacheConfig,
+    #[serde(default)]
+    pub dedup: DedupConfig,
+    #[serde(default)]
+    pub reader: ReaderStyleConfig,
+    #[serde(default)]
+    pub keys: KeysConfig,
+    #[serde(default)]
+    pub community: CommunityConfig,
+    #[serde(default)]
+    pub llm: LlmConfig,
+    #[serde(default)]
+    pub translate: TranslateConfig,
+    #[serde(default)]
+    pub read_later: ReadLaterConfig,
+    /// Named bundles under `[preset.<name>]`
+    #[serde(default)]
+    pub preset: BTreeMap<String, PresetConfig>,
+}
+
+/// Same values serde fills in for an empty file, used when there is no
+/// config file at all
+impl Default for ConfigFile {
+    fn default() -> Self {
+        Self {
+            watchlist: Vec::new(),
+            refresh_interval: default_refresh(),
+            theme: None,
+            default_view: None,
+            default_sort: None,
+            default_filter: None,
+            min_fetch_interval: default_min_fetch(),
+            sources: Vec::new(),
+            on_new_article: None,
+            holdings: Vec::new(),
+            on_holding_article: None,
+            script: None,
+            webhooks: Vec::new(),
+            ipc: IpcConfig::default(),
+            status_file: StatusFileConfig::default(),
+            serve: ServeConfig::default(),
+            confirm_quit: false,
+            reading_time_column: false,
+            tts_command: None,
+            clipboard_command: None,
+            pipe_command: None,
+            timezone: None,
+            date_format: default_date_format(),
+            proxy: None,
+            idle_pause_minutes: 0,
+            article_limit: default_article_limit(),
+            page_size: default_page_size(),
+            email: EmailConfig::default(),
+            quotes: QuotesConfig::default(),
+            sentiment_windows: default_sentiment_windows(),
+            sentiment_backend: SentimentBackendConfig::default(),
+            topics: default_topics(),
+            tickers: TickerConfig::default(),
+            boilerplate: BoilerplateConfig::default(),
+            network: NetworkConfig::default(),
+            cache: CacheConfig::default(),
+            dedup: DedupConfig::default(),
+            reader: ReaderStyleConfig::default(),
+            keys: KeysConfig::default(),
+            community: CommunityConfig::default(),
+            llm: LlmConfig::default(),
+            translate: TranslateConfig::default(),
+            read_later: ReadLaterConfig::default(),
+            preset: BTreeMap::new(),
+        }
+    }
+}
+
+/// Settings that replace the top-level ones while a preset is active;
+/// anything unset keeps the top-level value
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct PresetConfig {
+    #[serde(default)]
+    pub watchlist: Option<Vec<String>>,
+    #[serde(default)]
+    pub sources: Option<Vec<SourceConfig>>,
+    #[serde(default)]
+    pub default_view: Option<String>,
+    #[serde(default)]
+    pub default_sort: Option<String>,
+    #[serde(default)]
+    pub default_filter: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct EmailConfig {
+    #[serde(default)]
+    pub to: Option<String>,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default = "default_email_subject")]
+    pub subject: String,
+    /// sendmail-compatible command that delivers the message, used when
+    /// no [email.smtp] server is set
+    #[serde(default = "default_email_command")]
+    pub command: String,
+    /// SMTP server to send through directly
+    #[serde(default)]
+    pub smtp: Option<SmtpConfig>,
+    #[serde(default)]
+    pub html: bool,
+    /// Hour of day, in the configured timezone, at which the daemon sends
+    /// the daily digest
+    #[serde(default)]
+    pub daily_hour: Option<u32>,
+}
+
+impl Default for EmailConfig {
+    fn default() -> Self {
+        Self {
+            to: None,
+            from: None,
+            subject: default_email_subject(),
+            command: default_email_command(),
+            smtp: None,
+            html: false,
+            daily_hour: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct SmtpConfig {
+    pub host: String,
+    /// Defaults to 587 for starttls, 465 for tls, and 25 for none
+    #[serde(default)]
+    pub port: Option<u16>,
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Environment variable holding the password, kept out of the config
+    #[serde(default)]
+    pub password_env: Option<String>,
+    /// "starttls", "tls" (SMTPS), or "none" for a relay on localhost
+    #[serde(default = "default_smtp_security")]
+    pub security: String,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct QuotesConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Quote endpoint; `{symbol}` is replaced with the ticker plus `suffix`
+    #[serde(default = "default_quotes_url")]
+    pub url: String,
+    /// Exchange suffix the provider expects (".JK" for IDX on Yahoo)
+    #[serde(default = "default_quotes_suffix")]
+    pub suffix: String,
+    /// Seconds before a cached quote is refreshed
+    #[serde(default = "default_refresh")]
+    pub interval: u64,
+    /// Show the market strip (index levels, FX) under the header
+    #[serde(default = "default_true")]
+    pub market_strip: bool,
+    /// Indicators in the market strip, quoted by raw provider symbol
+    #[serde(default = "default_indicators")]
+    pub indicators: Vec<Indicator>,
+    /// Daily bars endpoint used to find large price moves; same `{symbol}`
+    /// substitution and response shape as `url`
+    #[serde(default = "default_history_url")]
+    pub history_url: String,
+    /// Daily change (percent, either direction) that counts as a large move;
+    /// 0 disables move annotation
+    #[serde(default = "default_move_threshold")]
+    pub move_threshold: f64,
+    /// Hours either side of a move day in which articles are linked to it
+    #[serde(default = "default_move_window_hours")]
+    pub move_window_hours: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Indicator {
+    pub label: String,
+    /// Provider symbol, used as-is without the exchange suffix
+    pub symbol: String,
+}
+
+impl Default for QuotesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            url: default_quotes_url(),
+            suffix: default_quotes_suffix(),
+            interval: default_refresh(),
+            market_strip: true,
+            indicators: default_indicators(),
+            history_url: default_history_url(),
+            move_threshold: default_move_threshold(),
+            move_window_hours: default_move_window_hours(),
+        }
+    }
+}
+
+/// Optional community source (forum/Stockbit mention tracker) shown next
+/// to news sentiment in the watchlist overview
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CommunityConfig {
+    /// Endpoint with a `{ticker}` placeholder; unset disables the column
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Environment variable holding a bearer token, if the source needs one
+    #[serde(default)]
+    pub token_env: Option<String>,
+    /// Seconds between refreshes
+    #[serde(default = "default_community_interval")]
+    pub interval: u64,
+}
+
+impl Default for CommunityConfig {
+    fn default() -> Self {
+        Self {
+            url: None,
+            token_env: None,
+            interval: default_community_interval(),
+        }
+    }
+}
+
+/// Key sequences and the keys they stand for, e.g. "g b" = "B". Keys are
+/// separated by spaces; named keys are Enter, Esc, Tab, Space, arrows,
+/// PageUp/PageDown, Home/End, with C- or A- for Ctrl and Alt.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct KeysConfig {
+    /// Bindings in every view
+    #[serde(default)]
+    pub global: BTreeMap<String, String>,
+    /// Bindings for one view, e.g. [keys.reader]; these win over global ones
+    #[serde(flatten)]
+    pub views: BTreeMap<String, BTreeMap<String, String>>,
+}
+
+/// How extracted article text is styled in the reader
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ReaderStyleConfig {
+    /// "accent" (bold, accent color), "bold", or "plain"
+    #[serde(default = "default_heading_style")]
+    pub headings: String,
+    /// "dim" or "plain"
+    #[serde(default = "default_quote_style")]
+    pub quotes: String,
+    /// Render **bold** and *italic* runs; off shows them as plain text
+    #[serde(default = "default_true")]
+    pub emphasis: bool,
+    /// Blank lines between paragraphs
+    #[serde(default = "default_paragraph_spacing")]
+    pub paragraph_spacing: usize,
+}
+
+impl Default for ReaderStyleConfig {
+    fn default() -> Self {
+        Self {
+            headings: default_heading_style(),
+            quotes: default_quote_style(),
+            emphasis: true,
+            paragraph_spacing: default_paragraph_spacing(),
+        }
+    }
+}
+
+/// HTTP client settings for feeds and article pages
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct NetworkConfig {
+    /// Whole-request timeout in seconds
+    #[serde(default = "default_timeout")]
+    pub timeout_secs: u64,
+    #[serde(default = "default_connect_timeout")]
+    pub connect_timeout_secs: u64,
+    /// Redirects followed before giving up (0 = none)
+    #[serde(default = "default_max_redirects")]
+    pub max_redirects: usize,
+    /// Replaces the default browser User-Agent
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    /// Feeds fetched at the same time
+    #[serde(default = "default_max_concurrent")]
+    pub max_concurrent_fetches: usize,
+    /// Feed downloads past this many KB are abandoned (0 = no limit)
+    #[serde(default = "default_max_body_kb")]
+    pub max_feed_kb: u64,
+    /// Same for article pages and images
+    #[serde(default = "default_max_body_kb")]
+    pub max_page_kb: u64,
+    /// Deadline in seconds for an article page across every retry
+    #[serde(default = "default_page_deadline")]
+    pub page_deadline_secs: u64,
+    /// How long an idle connection is kept for the next refresh
+    #[serde(default = "default_idle_connection")]
+    pub idle_connection_secs: u64,
+}
+
+impl Default for NetworkConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: default_timeout(),
+            connect_timeout_secs: default_connect_timeout(),
+            max_redirects: default_max_redirects(),
+            user_agent: None,
+            max_concurrent_fetches: default_max_concurrent(),
+            max_feed_kb: default_max_body_kb(),
+            max_page_kb: default_max_body_kb(),
+            page_deadline_secs: default_page_deadline(),
+            idle_connection_secs: default_idle_connection(),
+        }
+    }
+}
+
+/// Limits on article bodies kept in memory during a session
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct CacheConfig {
+    /// Bodies kept before the least recently read is dropped
+    #[serde(default = "default_cache_entries")]
+    pub max_entries: usize,
+    /// Drop bodies and forget failed fetches after this long; 0 keeps them
+    #[serde(default = "default_cache_age")]
+    pub max_age_minutes: u64,
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            max_entries: default_cache_entries(),
+            max_age_minutes: default_cache_age(),
+        }
+    }
+}
+
+/// How near-identical headlines from different sources are folded together
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct DedupConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// Share of title words two headlines need in common to fold, 0.0 to 1.0
+    #[serde(default = "default_dedup_threshold")]
+    pub threshold: f64,
+    /// Words ignored when comparing titles
+    #[serde(default = "default_dedup_stop_words")]
+    pub stop_words: Vec<String>,
+}
+
+impl Default for DedupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            threshold: default_dedup_threshold(),
+            stop_words: default_dedup_stop_words(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TickerConfig {
+    /// Regexes that find ticker codes; capture group 1, when present, is
+    /// the code (so `\b([A-Z]{4})\.JK\b` yields "BBCA")
+    #[serde(default = "default_ticker_patterns")]
+    pub patterns: Vec<TickerPattern>,
+    /// Extra words that match a pattern but aren't tickers
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    /// Codes always accepted, even if excluded or missing from the company
+    /// database (new listings, for instance)
+    #[serde(default)]
+    pub allow: Vec<String>,
+}
+
+impl Default for TickerConfig {
+    fn default() -> Self {
+        Self {
+            patterns: default_ticker_patterns(),
+            exclude: Vec::new(),
+            allow: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct TickerPattern {
+    pub regex: String,
+    /// Keep only codes found in the company database, plus codes the text
+    /// marks as such ("(DEWA)", "DEWA.JK", "saham DEWA") since the bundled
+    /// list doesn't cover every listing
+    #[serde(default)]
+    pub known_only: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct BoilerplateConfig {
+    /// Regexes matched against each extracted line; matching lines are
+    /// dropped (prefix with `(?i)` for case-insensitive)
+    #[serde(default = "default_boilerplate_patterns")]
+    pub patterns: Vec<String>,
+    /// CSS selectors for elements skipped during extraction, e.g. ".share"
+    #[serde(default)]
+    pub selectors: Vec<String>,
+}
+
+impl Default for BoilerplateConfig {
+    fn default() -> Self {
+        Self {
+            patterns: default_boilerplate_patterns(),
+            selectors: Vec::new(),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct SentimentBackendConfig {
+    /// "http" or "command"; unset keeps the built-in keyword analyzer
+    #[serde(default)]
+    pub kind: Option<String>,
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Environment variable holding a bearer token for the HTTP backend
+    #[serde(default)]
+    pub token_env: Option<String>,
+}
+
+/// Language model used for article summaries in the reader
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct LlmConfig {
+    /// "openai" (any OpenAI-compatible API) or "ollama"; unset disables it
+    #[serde(default)]
+    pub provider: Option<String>,
+    /// Chat endpoint; defaults to the provider's public/local URL
+    #[serde(default)]
+    pub url: Option<String>,
+    #[serde(default)]
+    pub model: Option<String>,
+    /// Environment variable holding the API key, e.g. "OPENAI_API_KEY"
+    #[serde(default)]
+    pub token_env: Option<String>,
+    /// Seconds to wait for a reply, for summaries and LLM translation. A
+    /// local model can take minutes on a long article, so this replaces
+    /// network.timeout_secs for these requests.
+    #[serde(default = "default_llm_timeout")]
+    pub timeout_secs: u64,
+}
+
+impl Default for LlmConfig {
+    fn default() -> Self {
+        Self {
+            provider: None,
+            url: None,
+            model: None,
+            token_env: None,
+            timeout_secs: default_llm_timeout(),
+        }
+    }
+}
+
+/// Reader translation between Indonesian and English
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct TranslateConfig {
+    /// "llm" (uses [llm]), "libretranslate", or "command"; unset disables it
+    #[serde(default)]
+    pub kind: Option<String>,
+    /// LibreTranslate `/translate` endpoint
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Shell command reading stdin; `{target}` becomes "en" or "id"
+    #[serde(default)]
+    pub command: Option<String>,
+    /// Environment variable holding the LibreTranslate API key
+    #[serde(default)]
+    pub token_env: Option<String>,
+}
+
+/// Read-later service the "send to" key saves articles to
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct ReadLaterConfig {
+    /// "pocket", "instapaper", "wallabag", or "readwise"; unset disables it
+    #[serde(default)]
+    pub service: Option<String>,
+    /// Wallabag instance base URL
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Instapaper account name
+    #[serde(default)]
+    pub username: Option<String>,
+    /// Pocket app consumer key
+    #[serde(default)]
+    pub consumer_key: Option<String>,
+    /// Environment variable holding the access token (Instapaper: password)
+    #[serde(default)]
+    pub token_env: Option<String>,
+}
+
+/// Unix socket other programs can drive the running TUI through, one
+/// JSON-RPC request per line
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+pub struct IpcConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Socket path; defaults to ipc.sock in the state directory
+    #[serde(default)]
+    pub socket: Option<String>,
+}
+
+impl IpcConfig {
+    /// Where the socket listens, or None when disabled
+    pub fn socket_path(&self) -> Option<PathBuf> {
+        self.enabled.then(|| {
+            self.socket
+                .as_deref()
+                .map(expand_home)
+                .unwrap_or_else(|| state_dir().join("ipc.sock"))
+        })
+    }
+}
+
+/// Small file kept up to date for tmux, polybar, or waybar to show
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct StatusFileConfig {
+    /// Where to write it; unset disables the file
+    #[serde(default)]
+    pub path: Option<String>,
+    /// Text with {unread}, {watchlist}, {alerts}, {trending}, and
+    /// {last_refresh} placeholders, or "json" for a waybar-style object
+    #[serde(default = "default_status_format")]
+    pub format: String,
+}
+
+impl Default for StatusFileConfig {
+    fn default() -> Self {
+        StatusFileConfig {
+            path: None,
+            format: default_status_format(),
+        }
+    }
+}
+
+impl StatusFileConfig {
+    pub fn path(&self) -> Option<PathBuf> {
+        self.path.as_deref().map(expand_home)
+    }
+}
+
+pub fn default_status_format() -> String {
+    "{unread} unread, {watchlist} watchlist".to_string()
+}
+
+/// Read-only web page of bookmarks and saved searches, for `serve`
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct ServeConfig {
+    /// Address to listen on; use "0.0.0.0:8080" to reach it from a phone
+    /// on the same network
+    #[serde(default = "default_serve_bind")]
+    pub bind: String,
+    /// Searches listed under the bookmarks, matched against titles,
+    /// tickers, keywords, and article text
+    #[serde(default)]
+    pub searches: Vec<String>,
+}
+
+impl Default for ServeConfig {
+    fn default() -> Self {
+        ServeConfig {
+            bind: default_serve_bind(),
+            searches: Vec::new(),
+        }
+    }
+}
+
+fn default_serve_bind() -> String {
+    "127.0.0.1:8080".to_string()
+}
+
+/// Endpoint new articles are POSTed to as JSON
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct WebhookConfig {
+    pub url: String,
+    /// Environment variable holding the secret the body is signed with
+    /// (HMAC-SHA256, sent as `X-StockNews-Signature: sha256=<hex>`)
+    #[serde(default)]
+    pub secret_env: Option<String>,
+    /// Further attempts after a network error, 429, or 5xx response
+    #[serde(default = "default_webhook_retries")]
+    pub retries: u32,
+}
+
+fn default_webhook_retries() -> u32 {
+    3
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -47,6 +906,22 @@ pub struct SourceConfig {
     pub url: String,
     #[serde(default = "default_true")]
     pub enabled: bool,
+    /// CSS selectors for the article body, tried before the built-in ones
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub content_selectors: Vec<String>,
+    /// Seconds between fetches of this source, instead of refresh_interval
+    #[serde(default)]
+    pub refresh_interval: Option<u64>,
+    #[serde(default)]
+    pub user_agent: Option<String>,
+    /// Tags added to every article from this source
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    /// Don't fetch article pages; the reader offers the browser instead
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub mute_content: bool,
 }
 
 fn default_refresh() -> u64 {
@@ -57,28 +932,290 @@ fn default_min_fetch() -> u64 {
     60
 }
 
+fn default_date_format() -> String {
+    "%Y-%m-%d".to_string()
+}
+
+fn default_article_limit() -> usize {
+    100
+}
+
+fn default_page_size() -> usize {
+    100
+}
+
 fn default_true() -> bool {
     true
 }
 
+fn default_email_subject() -> String {
+    "StockNewsTUI digest".to_string()
+}
+
+fn default_smtp_security() -> String {
+    "starttls".to_string()
+}
+
+fn default_email_command() -> String {
+    "sendmail -t".to_string()
+}
+
+fn default_sentiment_windows() -> Vec<String> {
+    vec!["24h".to_string(), "7d".to_string(), "30d".to_string()]
+}
+
+fn default_community_interval() -> u64 {
+    900
+}
+
+fn default_heading_style() -> String {
+    "accent".to_string()
+}
+
+fn default_quote_style() -> String {
+    "dim".to_string()
+}
+
+fn default_paragraph_spacing() -> usize {
+    1
+}
+
+fn default_cache_entries() -> usize {
+    200
+}
+
+fn default_cache_age() -> u64 {
+    120
+}
+
+fn default_timeout() -> u64 {
+    15
+}
+
+fn default_connect_timeout() -> u64 {
+    10
+}
+
+fn default_max_redirects() -> usize {
+    10
+}
+
+fn default_max_concurrent() -> usize {
+    8
+}
+
+fn default_max_body_kb() -> u64 {
+    4096
+}
+
+fn default_page_deadline() -> u64 {
+    30
+}
+
+fn default_llm_timeout() -> u64 {
+    120
+}
+
+fn default_idle_connection() -> u64 {
+    600
+}
+
+fn default_dedup_threshold() -> f64 {
+    0.7
+}
+
+fn default_dedup_stop_words() -> Vec<String> {
+    [
+        "dan", "di", "ke", "dari", "yang", "untuk", "dengan", "ini", "itu", "the", "a", "an", "in",
+        "on", "of", "to", "and", "for", "is", "at",
+    ]
+    .iter()
+    .map(|w| w.to_string())
+    .collect()
+}
+
+fn default_ticker_patterns() -> Vec<TickerPattern> {
+    vec![TickerPattern {
+        regex: r"\b[A-Z]{4}\b".to_string(),
+        known_only: true,
+    }]
+}
+
+fn default_boilerplate_patterns() -> Vec<String> {
+    [
+        r"(?i)^baca juga\s*:",
+        r"(?i)^(lihat juga|simak juga|simak video)\b",
+        r"(?i)^advertisement$",
+        r"(?i)^scroll to continue with content$",
+        r"(?i)^(bagikan|share)( artikel ini| this article)?\s*:?$",
+        r"(?i)^\(?(reporter|penulis|editor)\s*:",
+    ]
+    .iter()
+    .map(|p| p.to_string())
+    .collect()
+}
+
+fn default_topics() -> BTreeMap<String, Vec<String>> {
+    let topics: &[(&str, &[&str])] = &[
+        ("cpo", &["cpo", "crude palm oil", "palm oil", "sawit"]),
+        ("coal", &["coal", "batu bara", "batubara"]),
+        ("nickel", &["nickel", "nikel"]),
+        ("gold", &["gold", "emas"]),
+        ("oil", &["crude oil", "minyak mentah", "brent", "wti"]),
+        ("tin", &["timah"]),
+        ("copper", &["copper", "tembaga"]),
+        ("usd-idr", &["usd/idr", "rupiah", "dolar as", "us dollar"]),
+    ];
+    topics
+        .iter()
+        .map(|(tag, keywords)| {
+            let keywords = keywords.iter().map(|k| k.to_string()).collect();
+            (tag.to_string(), keywords)
+        })
+        .collect()
+}
+
+fn default_quotes_url() -> String {
+    "https://query1.finance.yahoo.com/v8/finance/chart/{symbol}?interval=1d&range=1d".to_string()
+}
+
+fn default_indicators() -> Vec<Indicator> {
+    vec![
+        Indicator {
+            label: "IHSG".to_string(),
+            symbol: "^JKSE".to_string(),
+        },
+        Indicator {
+            label: "USD/IDR".to_string(),
+            symbol: "IDR=X".to_string(),
+        },
+    ]
+}
+
+fn default_history_url() -> String {
+    "https://query1.finance.yahoo.com/v8/finance/chart/{symbol}?interval=1d&range=1mo".to_string()
+}
+
+fn default_move_threshold() -> f64 {
+    5.0
+}
+
+fn default_move_window_hours() -> u64 {
+    24
+}
+
+fn default_quotes_suffix() -> String {
+    ".JK".to_string()
+}
+
 // --- Path Helpers ---
 
+/// An XDG base directory from `var` when it's set to an absolute path,
+/// else the platform default
+fn xdg_dir(var: &str, fallback: Option<PathBuf>) -> Option<PathBuf> {
+    std::env::var_os(var)
+        .map(PathBuf::from)
+        .filter(|p| p.is_absolute())
+        .or(fallback)
+}
+
 pub fn config_dir() -> PathBuf {
-    dirs::config_dir()
+    xdg_dir("XDG_CONFIG_HOME", dirs::config_dir())
         .unwrap_or_else(|| PathBuf::from("."))
         .join("stocknewstui")
 }
 
+static CONFIG_FILE_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Read and save config at `path` for the rest of the process
+pub fn set_config_file(path: PathBuf) {
+    let _ = CONFIG_FILE_OVERRIDE.set(path);
+}
+
 pub fn config_file_path() -> PathBuf {
-    config_dir().join("config.toml")
+    CONFIG_FILE_OVERRIDE
+        .get()
+        .cloned()
+        .unwrap_or_else(|| config_dir().join("config.toml"))
 }
 
-pub fn db_path() -> PathBuf {
-    let dir = dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("stocknewstui");
+static DATA_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Point the data directory somewhere else for the rest of the process
+pub fn set_data_dir(dir: PathBuf) {
+    let _ = DATA_DIR_OVERRIDE.set(dir);
+}
+
+pub fn data_dir() -> PathBuf {
+    let dir = DATA_DIR_OVERRIDE.get().cloned().unwrap_or_else(|| {
+        xdg_dir("XDG_DATA_HOME", dirs::data_dir())
+            .unwrap_or_else(|| PathBuf::from("."))
+            .join("stocknewstui")
+    });
+    let _ = fs::create_dir_all(&dir);
+    dir
+}
+
+static STATE_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Point the state directory somewhere else for the rest of the process
+pub fn set_state_dir(dir: PathBuf) {
+    let _ = STATE_DIR_OVERRIDE.set(dir);
+}
+
+/// Where session state such as state.json lives. Platforms without a
+/// state directory (macOS, Windows) use the data directory.
+pub fn state_dir() -> PathBuf {
+    let dir = STATE_DIR_OVERRIDE.get().cloned().unwrap_or_else(|| {
+        match xdg_dir("XDG_STATE_HOME", dirs::state_dir()) {
+            Some(base) => base.join("stocknewstui"),
+            None => data_dir(),
+        }
+    });
     let _ = fs::create_dir_all(&dir);
-    dir.join("articles.db")
+    dir
+}
+
+/// A configured path with a leading `~/` pointing into the home directory
+fn expand_home(path: &str) -> PathBuf {
+    match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => PathBuf::from(path),
+    }
+}
+
+pub fn db_path() -> PathBuf {
+    data_dir().join("articles.db")
+}
+
+/// `path` with a suffix added to the file name, e.g. "config.toml.bak"
+pub fn sibling(path: &std::path::Path, suffix: &str) -> PathBuf {
+    let mut name = path.file_name().unwrap_or_default().to_os_string();
+    name.push(suffix);
+    path.with_file_name(name)
+}
+
+/// Replace a file without ever leaving it half-written: the contents go to
+/// a temp file that is synced and renamed over the original. The previous
+/// version is kept as `<name>.bak`.
+pub fn write_atomic(path: &std::path::Path, contents: &str) -> std::io::Result<()> {
+    use std::io::Write;
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = sibling(path, ".tmp");
+    let mut file = fs::File::create(&tmp)?;
+    file.write_all(contents.as_bytes())?;
+    file.sync_all()?;
+    drop(file);
+
+    if path.exists() {
+        let _ = fs::copy(path, sibling(path, ".bak"));
+    }
+    fs::rename(&tmp, path).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
 }
 
 // --- Load Config ---
@@ -91,58 +1228,428 @@ pub fn load_config(path: Option<&PathBuf>) -> ConfigFile {
         .unwrap_or_default()
 }
 
+/// Parse the config file strictly, for diagnostics. Ok(None) means no file
+/// exists; otherwise the config comes back with the dotted paths of any
+/// keys that no setting reads. Errors carry the TOML line and column.
+pub fn check_config(path: &PathBuf) -> Result<Option<(ConfigFile, Vec<String>)>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.to_string()),
+    };
+    let config: ConfigFile = toml::from_str(&text).map_err(|e| e.to_string())?;
+    let raw: toml::Value = toml::from_str(&text).map_err(|e| e.to_string())?;
+    let mut unknown = Vec::new();
+    if let Ok(known) = toml::Value::try_from(&config) {
+        collect_unknown_keys(&raw, &known, "", &mut unknown);
+    }
+    Ok(Some((config, unknown)))
+}
+
+/// Keys in `raw` missing from the re-serialized config were ignored when
+/// parsing. Empty lists and false are skipped since some settings leave
+/// those out when saving.
+fn collect_unknown_keys(raw: &toml::Value, known: &toml::Value, path: &str, out: &mut Vec<String>) {
+    match (raw, known) {
+        (toml::Value::Table(raw), toml::Value::Table(known)) => {
+            for (key, value) in raw {
+                let path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+                match known.get(key) {
+                    Some(known) => collect_unknown_keys(value, known, &path, out),
+                    None => match value {
+                        toml::Value::Boolean(false) => {}
+                        toml::Value::Array(items) if items.is_empty() => {}
+                        _ => out.push(path),
+                    },
+                }
+            }
+        }
+        (toml::Value::Array(raw), toml::Value::Array(known)) => {
+            for (i, (raw, known)) in raw.iter().zip(known).enumerate() {
+                collect_unknown_keys(raw, known, &format!("{}[{}]", path, i), out);
+            }
+        }
+        _ => {}
+    }
+}
+
 // --- Resolve ---
 
 pub struct ResolvedConfig {
     pub watchlist: Vec<String>,
+    /// Set by --tickers or STOCKNEWSTUI_WATCHLIST rather than the file
+    pub watchlist_overridden: bool,
     pub refresh_interval: u64,
     pub min_fetch_interval: u64,
-    pub theme: ThemeName,
+    /// None when unset or "auto": match the terminal background
+    pub theme: Option<ThemeName>,
+    pub start_view: ViewMode,
+    /// None keeps the saved sort and filter
+    pub sort_mode: Option<SortMode>,
+    pub filter_mode: Option<FilterMode>,
+    pub on_new_article: Option<String>,
+    pub holdings: Vec<Holding>,
+    pub on_holding_article: Option<String>,
+    pub script: Option<PathBuf>,
+    pub ipc: IpcConfig,
+    pub status_file: StatusFileConfig,
+    pub serve: ServeConfig,
+    pub webhooks: Vec<WebhookConfig>,
+    pub confirm_quit: bool,
+    pub tts_command: Option<String>,
+    pub clipboard_command: Option<String>,
+    pub pipe_command: Option<String>,
+    pub reading_time_column: bool,
+    pub idle_pause_minutes: u64,
+    pub article_limit: usize,
+    pub page_size: usize,
+    pub timezone: Option<String>,
+    pub date_format: String,
+    pub proxy: Option<String>,
+    pub email: EmailConfig,
+    pub quotes: QuotesConfig,
+    pub sentiment_windows: Vec<(String, i64)>,
+    pub sentiment_backend: SentimentBackendConfig,
+    pub topics: BTreeMap<String, Vec<String>>,
+    pub tickers: TickerConfig,
+    pub boilerplate: BoilerplateConfig,
+    pub network: NetworkConfig,
+    pub cache: CacheConfig,
+    pub dedup: DedupConfig,
+    pub reader: ReaderStyleConfig,
+    pub keys: KeysConfig,
+    pub community: CommunityConfig,
+    pub llm: LlmConfig,
+    pub translate: TranslateConfig,
+    pub read_later: ReadLaterConfig,
+}
+
+/// Non-empty value of a `STOCKNEWSTUI_*` override
+fn env_override(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty())
 }
 
+/// Merge settings with CLI flags first, then `STOCKNEWSTUI_*` environment
+/// variables, then the config file. The database path override is
+/// `STOCKNEWSTUI_DB`, handled by the `--db` flag itself.
 pub fn resolve(args: &CliArgs, config: &ConfigFile) -> ResolvedConfig {
     let watchlist = if !args.tickers.is_empty() {
         args.tickers.iter().map(|s| s.to_uppercase()).collect()
+    } else if let Some(list) = env_override("STOCKNEWSTUI_WATCHLIST") {
+        list.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|s| !s.is_empty())
+            .map(|s| s.to_uppercase())
+            .collect()
     } else {
         config.watchlist.clone()
     };
+    let watchlist_overridden =
+        !args.tickers.is_empty() || env_override("STOCKNEWSTUI_WATCHLIST").is_some();
 
     let refresh_interval = if args.refresh != 300 {
         args.refresh
     } else {
-        config.refresh_interval
+        env_override("STOCKNEWSTUI_REFRESH")
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(config.refresh_interval)
     };
 
-    let theme_str = args
+    let theme_env = env_override("STOCKNEWSTUI_THEME");
+    let theme = args
         .theme
         .as_deref()
+        .or(theme_env.as_deref())
         .or(config.theme.as_deref())
-        .unwrap_or("dark");
-    let theme = ThemeName::from_str(theme_str);
+        .filter(|t| !t.eq_ignore_ascii_case("auto"))
+        .map(ThemeName::from_str);
+
+    let start_view = match args.view.as_deref().or(config.default_view.as_deref()) {
+        Some(v) if v.eq_ignore_ascii_case("bookmarks") => ViewMode::Bookmarks,
+        _ => ViewMode::Feed,
+    };
+    let sort_mode = args
+        .sort
+        .as_deref()
+        .or(config.default_sort.as_deref())
+        .map(|s| SortMode::from_str(&s.to_lowercase()));
+    let filter_mode = args
+        .filter
+        .as_deref()
+        .or(config.default_filter.as_deref())
+        .map(|f| FilterMode::from_str(&f.to_lowercase()));
 
     ResolvedConfig {
         watchlist,
+        watchlist_overridden,
         refresh_interval,
         min_fetch_interval: config.min_fetch_interval,
         theme,
+        start_view,
+        sort_mode,
+        filter_mode,
+        on_new_article: config.on_new_article.clone(),
+        holdings: config
+            .holdings
+            .iter()
+            .map(|h| Holding {
+                ticker: h.ticker.to_uppercase(),
+                size: h.size,
+            })
+            .collect(),
+        on_holding_article: config.on_holding_article.clone(),
+        script: config.script.as_deref().map(expand_home),
+        ipc: config.ipc.clone(),
+        status_file: config.status_file.clone(),
+        serve: config.serve.clone(),
+        webhooks: config.webhooks.clone(),
+        confirm_quit: config.confirm_quit,
+        tts_command: config.tts_command.clone(),
+        clipboard_command: config.clipboard_command.clone(),
+        pipe_command: config.pipe_command.clone(),
+        reading_time_column: config.reading_time_column,
+        idle_pause_minutes: config.idle_pause_minutes,
+        article_limit: config.article_limit.max(1),
+        page_size: config.page_size,
+        timezone: config.timezone.clone(),
+        date_format: config.date_format.clone(),
+        proxy: env_override("STOCKNEWSTUI_PROXY").or_else(|| config.proxy.clone()),
+        email: config.email.clone(),
+        quotes: config.quotes.clone(),
+        sentiment_windows: config
+            .sentiment_windows
+            .iter()
+            .filter_map(|w| parse_window(w).map(|secs| (w.trim().to_string(), secs)))
+            .collect(),
+        sentiment_backend: config.sentiment_backend.clone(),
+        topics: config
+            .topics
+            .iter()
+            .map(|(tag, keywords)| {
+                let keywords = keywords.iter().map(|k| k.to_lowercase()).collect();
+                (tag.to_lowercase(), keywords)
+            })
+            .collect(),
+        tickers: config.tickers.clone(),
+        boilerplate: config.boilerplate.clone(),
+        network: NetworkConfig {
+            timeout_secs: config.network.timeout_secs.max(1),
+            connect_timeout_secs: config.network.connect_timeout_secs.max(1),
+            max_concurrent_fetches: config.network.max_concurrent_fetches.max(1),
+            page_deadline_secs: config.network.page_deadline_secs.max(1),
+            ..config.network.clone()
+        },
+        cache: config.cache.clone(),
+        dedup: DedupConfig {
+            threshold: config.dedup.threshold.clamp(0.0, 1.0),
+            stop_words: config
+                .dedup
+                .stop_words
+                .iter()
+                .map(|w| w.to_lowercase())
+                .collect(),
+            ..config.dedup.clone()
+        },
+        reader: config.reader.clone(),
+        keys: config.keys.clone(),
+        community: config.community.clone(),
+        llm: config.llm.clone(),
+        translate: config.translate.clone(),
+        read_later: config.read_later.clone(),
     }
 }
 
-// --- Save Sources ---
+// --- Sources ---
 
-pub fn save_sources(sources: &[crate::model::FeedSource]) {
-    let path = config_file_path();
-    let mut cfg = load_config(None);
-    cfg.sources = sources
+pub fn sources_from_config(config: &ConfigFile) -> Vec<FeedSource> {
+    feed_sources(&config.sources)
+}
+
+fn feed_sources(sources: &[SourceConfig]) -> Vec<FeedSource> {
+    if sources.is_empty() {
+        return FeedSource::defaults();
+    }
+    sources
         .iter()
-        .map(|s| SourceConfig {
+        .map(|s| FeedSource {
             name: s.name.clone(),
             url: s.url.clone(),
             enabled: s.enabled,
+            content_selectors: s.content_selectors.clone(),
+            refresh_interval: s.refresh_interval.filter(|&secs| secs > 0),
+            user_agent: s.user_agent.clone(),
+            tags: s.tags.iter().map(|t| t.to_lowercase()).collect(),
+            category: s.category.clone(),
+            mute_content: s.mute_content,
         })
-        .collect();
-    if let Ok(toml_str) = toml::to_string_pretty(&cfg) {
-        let _ = fs::create_dir_all(config_dir());
-        let _ = fs::write(path, toml_str);
+        .collect()
+}
+
+// --- Presets ---
+
+/// A preset ready to switch to from the TUI
+#[derive(Debug, Clone)]
+pub struct Preset {
+    pub name: String,
+    pub watchlist: Vec<String>,
+    pub sources: Vec<FeedSource>,
+    pub sort_mode: Option<SortMode>,
+    pub filter_mode: Option<FilterMode>,
+}
+
+/// Overlay `[preset.<name>]` onto the top-level settings, so everything
+/// downstream (TUI and headless commands) sees the preset's values
+pub fn apply_preset(config: &mut ConfigFile, name: &str) -> Result<(), String> {
+    let Some(preset) = config.preset.get(name).cloned() else {
+        let known: Vec<&str> = config.preset.keys().map(String::as_str).collect();
+        return Err(if known.is_empty() {
+            format!("unknown preset \"{}\" (no [preset.*] sections)", name)
+        } else {
+            format!("unknown preset \"{}\" (have: {})", name, known.join(", "))
+        });
+    };
+    if let Some(watchlist) = preset.watchlist {
+        config.watchlist = watchlist;
+    }
+    if let Some(sources) = preset.sources {
+        config.sources = sources;
+    }
+    if preset.default_view.is_some() {
+        config.default_view = preset.default_view;
+    }
+    if preset.default_sort.is_some() {
+        config.default_sort = preset.default_sort;
+    }
+    if preset.default_filter.is_some() {
+        config.default_filter = preset.default_filter;
+    }
+    Ok(())
+}
+
+/// The top-level settings as "default", followed by each named preset
+pub fn resolve_presets(config: &ConfigFile) -> Vec<Preset> {
+    let base = Preset {
+        name: "default".to_string(),
+        watchlist: config.watchlist.iter().map(|t| t.to_uppercase()).collect(),
+        sources: sources_from_config(config),
+        sort_mode: config
+            .default_sort
+            .as_deref()
+            .map(|s| SortMode::from_str(&s.to_lowercase())),
+        filter_mode: config
+            .default_filter
+            .as_deref()
+            .map(|f| FilterMode::from_str(&f.to_lowercase())),
+    };
+    let mut presets = vec![base.clone()];
+    for (name, preset) in &config.preset {
+        presets.push(Preset {
+            name: name.clone(),
+            watchlist: preset
+                .watchlist
+                .as_ref()
+                .map(|w| w.iter().map(|t| t.to_uppercase()).collect())
+                .unwrap_or_else(|| base.watchlist.clone()),
+            sources: preset
+                .sources
+                .as_deref()
+                .map(feed_sources)
+                .unwrap_or_else(|| base.sources.clone()),
+            sort_mode: preset
+                .default_sort
+                .as_deref()
+                .map(|s| SortMode::from_str(&s.to_lowercase()))
+                .or(base.sort_mode),
+            filter_mode: preset
+                .default_filter
+                .as_deref()
+                .map(|f| FilterMode::from_str(&f.to_lowercase()))
+                .or(base.filter_mode),
+        });
+    }
+    presets
+}
+
+// --- Save Watchlist ---
+
+pub fn save_watchlist(watchlist: &[String]) -> Result<(), String> {
+    edit_config_file(|doc| {
+        let tickers: toml_edit::Array = watchlist.iter().map(String::as_str).collect();
+        doc["watchlist"] = toml_edit::value(tickers);
+    })
+}
+
+/// Change keys in the config file in place, keeping the user's comments,
+/// ordering, and everything left unset. A missing file is created holding
+/// only what `edit` sets.
+fn edit_config_file(edit: impl FnOnce(&mut toml_edit::DocumentMut)) -> Result<(), String> {
+    let path = config_file_path();
+    // Never overwrite a file that doesn't parse
+    check_config(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(format!("{}: {}", path.display(), e)),
+    };
+    let mut doc: toml_edit::DocumentMut = text
+        .parse()
+        .map_err(|e| format!("{}: {}", path.display(), e))?;
+    edit(&mut doc);
+    write_atomic(&path, &doc.to_string()).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+// --- Save Sources ---
+
+pub fn save_sources(sources: &[FeedSource]) -> Result<(), String> {
+    #[derive(Serialize)]
+    struct Sources {
+        sources: Vec<SourceConfig>,
+    }
+    let sources = Sources {
+        sources: sources
+            .iter()
+            .map(|s| SourceConfig {
+                name: s.name.clone(),
+                url: s.url.clone(),
+                enabled: s.enabled,
+                content_selectors: s.content_selectors.clone(),
+                refresh_interval: s.refresh_interval,
+                user_agent: s.user_agent.clone(),
+                tags: s.tags.clone(),
+                category: s.category.clone(),
+                mute_content: s.mute_content,
+            })
+            .collect(),
+    };
+    // Serialized on its own so only the [[sources]] tables are rewritten
+    let rendered: toml_edit::DocumentMut = toml::to_string(&sources)
+        .map_err(|e| e.to_string())?
+        .parse()
+        .map_err(|e: toml_edit::TomlError| e.to_string())?;
+    edit_config_file(|doc| {
+        doc.remove("sources");
+        if let Some(tables) = rendered.get("sources") {
+            doc["sources"] = tables.clone();
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_matches_empty_file() {
+        let parsed: ConfigFile = toml::from_str("").unwrap();
+        assert_eq!(
+            toml::to_string(&ConfigFile::default()).unwrap(),
+            toml::to_string(&parsed).unwrap()
+        );
     }
 }
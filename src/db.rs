@@ -1,7 +1,24 @@
-use crate::model::{Article, Sentiment};
-use rusqlite::{params, Connection, Result};
+use crate::companies::Company;
+use crate::feed;
+use crate::keywords;
+use crate::ratings::{self, Rating, RatingAction, RatingEntry};
+use crate::model::{
+    classify_event, intern, sentiment_from_score, sentiment_score, word_count, AlertRecord,
+    Article, ClusterPref, EventKind, Highlight, Language, Link, Sentiment,
+};
+use rusqlite::{params, Connection, OptionalExtension, Result};
+use std::collections::HashMap;
 use std::path::Path;
 
+/// Meta key holding the fingerprint stored articles were analyzed under
+pub const ANALYSIS_KEY: &str = "analysis_fingerprint";
+/// Meta key holding when the last full refresh finished (unix time), from
+/// the TUI or a headless fetch
+pub const LAST_REFRESH_KEY: &str = "last_refresh";
+/// Meta key holding the local date (YYYY-MM-DD) the daemon last sent the
+/// daily email digest
+pub const LAST_EMAIL_KEY: &str = "last_email_digest";
+
 pub struct Db {
     conn: Connection,
 }
@@ -9,6 +26,13 @@ pub struct Db {
 impl Db {
     pub fn open(path: &Path) -> Result<Self> {
         let conn = Connection::open(path)?;
+        let has_ratings: bool = conn
+            .query_row(
+                "SELECT COUNT(*) FROM sqlite_master WHERE type='table' AND name='ratings'",
+                [],
+                |row| row.get::<_, i64>(0),
+            )
+            .map(|n| n > 0)?;
         conn.execute_batch(
             "CREATE TABLE IF NOT EXISTS articles (
                 id          INTEGER PRIMARY KEY AUTOINCREMENT,
@@ -24,7 +48,62 @@ impl Db {
             );
             CREATE INDEX IF NOT EXISTS idx_published ON articles(published_at DESC);
             CREATE INDEX IF NOT EXISTS idx_source ON articles(source);
-            CREATE INDEX IF NOT EXISTS idx_bookmarked ON articles(bookmarked);",
+            CREATE INDEX IF NOT EXISTS idx_bookmarked ON articles(bookmarked);
+            CREATE TABLE IF NOT EXISTS ratings (
+                article_id   INTEGER NOT NULL,
+                ticker       TEXT NOT NULL,
+                broker       TEXT,
+                action       TEXT,
+                rating       TEXT,
+                target_price REAL,
+                PRIMARY KEY (article_id, ticker)
+            );
+            CREATE TABLE IF NOT EXISTS translations (
+                article_id  INTEGER NOT NULL,
+                lang        TEXT NOT NULL,
+                content     TEXT NOT NULL,
+                PRIMARY KEY (article_id, lang)
+            );
+            CREATE TABLE IF NOT EXISTS sent_articles (
+                article_id  INTEGER NOT NULL,
+                service     TEXT NOT NULL,
+                sent_at     INTEGER NOT NULL,
+                PRIMARY KEY (article_id, service)
+            );
+            CREATE TABLE IF NOT EXISTS highlights (
+                id          INTEGER PRIMARY KEY AUTOINCREMENT,
+                article_id  INTEGER NOT NULL,
+                text        TEXT NOT NULL,
+                note        TEXT,
+                created_at  INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS term_df (
+                term        TEXT PRIMARY KEY,
+                docs        INTEGER NOT NULL
+            );
+            CREATE TABLE IF NOT EXISTS companies (
+                ticker      TEXT PRIMARY KEY,
+                name        TEXT NOT NULL,
+                aliases     TEXT NOT NULL DEFAULT '[]'
+            );
+            CREATE TABLE IF NOT EXISTS alerts (
+                id           INTEGER PRIMARY KEY AUTOINCREMENT,
+                rule         TEXT NOT NULL,
+                article_id   INTEGER NOT NULL,
+                created_at   INTEGER NOT NULL,
+                acknowledged INTEGER NOT NULL DEFAULT 0,
+                UNIQUE (rule, article_id)
+            );
+            CREATE TABLE IF NOT EXISTS cluster_prefs (
+                cluster_id       INTEGER PRIMARY KEY,
+                expanded         INTEGER NOT NULL DEFAULT 0,
+                dismissed        INTEGER NOT NULL DEFAULT 0,
+                preferred_source TEXT
+            );
+            CREATE TABLE IF NOT EXISTS meta (
+                key   TEXT PRIMARY KEY,
+                value TEXT NOT NULL
+            );",
         )?;
 
         // Migration: add content column if missing
@@ -39,7 +118,277 @@ impl Db {
             let _ = conn.execute_batch("ALTER TABLE articles ADD COLUMN content TEXT DEFAULT NULL;");
         }
 
-        Ok(Db { conn })
+        let db = Db { conn };
+
+        // Migration: add event column and classify what's already stored
+        if !schema.contains("event") {
+            db.conn
+                .execute_batch("ALTER TABLE articles ADD COLUMN event TEXT DEFAULT NULL;")?;
+            db.backfill_events()?;
+        }
+
+        // Migration: add continuous sentiment score and rescore stored titles
+        if !schema.contains("sentiment_score") {
+            db.conn.execute_batch(
+                "ALTER TABLE articles ADD COLUMN sentiment_score REAL NOT NULL DEFAULT 0;",
+            )?;
+            db.backfill_sentiment()?;
+        }
+
+        // Migration: add cached LLM summary column
+        if !schema.contains("summary") {
+            db.conn
+                .execute_batch("ALTER TABLE articles ADD COLUMN summary TEXT DEFAULT NULL;")?;
+        }
+
+        // Migration: add local path of the downloaded lead image
+        if !schema.contains("lead_image") {
+            db.conn
+                .execute_batch("ALTER TABLE articles ADD COLUMN lead_image TEXT DEFAULT NULL;")?;
+        }
+
+        // Migration: add links found in the article body, as JSON
+        if !schema.contains("links") {
+            db.conn
+                .execute_batch("ALTER TABLE articles ADD COLUMN links TEXT DEFAULT NULL;")?;
+        }
+
+        // Migration: add reader scroll position so long pieces resume
+        if !schema.contains("read_position") {
+            db.conn.execute_batch(
+                "ALTER TABLE articles ADD COLUMN read_position INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+
+        // Migration: add body word count for reading time estimates
+        if !schema.contains("word_count") {
+            db.conn.execute_batch(
+                "ALTER TABLE articles ADD COLUMN word_count INTEGER DEFAULT NULL;",
+            )?;
+            db.backfill_word_counts()?;
+        }
+
+        // Migration: add detected content language
+        if !schema.contains("language") {
+            db.conn
+                .execute_batch("ALTER TABLE articles ADD COLUMN language TEXT DEFAULT NULL;")?;
+            db.backfill_languages()?;
+        }
+
+        // Migration: add TF-IDF keywords and count terms in stored bodies
+        if !schema.contains("keywords") {
+            db.conn.execute_batch(
+                "ALTER TABLE articles ADD COLUMN keywords TEXT NOT NULL DEFAULT '[]';",
+            )?;
+            db.backfill_keywords()?;
+        }
+
+        // Migration: extract ratings from articles stored before the table existed
+        if !has_ratings {
+            db.backfill_ratings()?;
+        }
+
+        // Seed the company table from the bundled list on first run
+        let companies: i64 = db
+            .conn
+            .query_row("SELECT COUNT(*) FROM companies", [], |row| row.get(0))?;
+        if companies == 0 {
+            db.upsert_companies(&crate::companies::bundled())?;
+        }
+
+        Ok(db)
+    }
+
+    fn backfill_events(&self) -> Result<()> {
+        let mut stmt = self.conn.prepare("SELECT id, title FROM articles")?;
+        let rows: Vec<(i64, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_>>()?;
+        for (id, title) in rows {
+            if let Some(event) = classify_event(&title) {
+                self.conn.execute(
+                    "UPDATE articles SET event = ?1 WHERE id = ?2",
+                    params![event.as_str(), id],
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    fn backfill_word_counts(&self) -> Result<()> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, content FROM articles WHERE content IS NOT NULL")?;
+        let rows: Vec<(i64, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_>>()?;
+        for (id, content) in rows {
+            self.conn.execute(
+                "UPDATE articles SET word_count = ?1 WHERE id = ?2",
+                params![word_count(&content) as i64, id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn backfill_keywords(&self) -> Result<()> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, content FROM articles WHERE content IS NOT NULL")?;
+        let rows: Vec<(i64, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_>>()?;
+        self.conn.execute("DELETE FROM term_df", [])?;
+        for (_, content) in &rows {
+            self.count_terms(content)?;
+        }
+        for (id, content) in &rows {
+            self.store_keywords(*id, content)?;
+        }
+        Ok(())
+    }
+
+    fn backfill_languages(&self) -> Result<()> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, title, content FROM articles")?;
+        let rows: Vec<(i64, String, Option<String>)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<_>>()?;
+        for (id, title, content) in rows {
+            let language = content
+                .as_deref()
+                .and_then(Language::detect)
+                .or_else(|| Language::detect(&title));
+            self.conn.execute(
+                "UPDATE articles SET language = ?1 WHERE id = ?2",
+                params![language.map(|l| l.as_str()), id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn backfill_sentiment(&self) -> Result<()> {
+        let mut stmt = self.conn.prepare("SELECT id, title FROM articles")?;
+        let rows: Vec<(i64, String)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+            .collect::<Result<_>>()?;
+        for (id, title) in rows {
+            let score = sentiment_score(&title);
+            self.conn.execute(
+                "UPDATE articles SET sentiment = ?1, sentiment_score = ?2 WHERE id = ?3",
+                params![sentiment_from_score(score).as_str(), score, id],
+            )?;
+        }
+        Ok(())
+    }
+
+    fn backfill_ratings(&self) -> Result<()> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT id, title, tickers, content FROM articles")?;
+        let rows: Vec<(i64, String, String, Option<String>)> = stmt
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))?
+            .collect::<Result<_>>()?;
+        for (id, title, tickers, content) in rows {
+            let tickers: Vec<String> = serde_json::from_str(&tickers).unwrap_or_default();
+            self.store_rating(id, &title, &tickers, content.as_deref())?;
+        }
+        Ok(())
+    }
+
+    /// Extract a rating action for the article's main ticker and store it
+    fn store_rating(
+        &self,
+        article_id: i64,
+        title: &str,
+        tickers: &[String],
+        content: Option<&str>,
+    ) -> Result<()> {
+        let Some(ticker) = tickers.first() else {
+            return Ok(());
+        };
+        let text = match content {
+            Some(body) => format!("{}\n{}", title, body),
+            None => title.to_string(),
+        };
+        if let Some(rating) = ratings::extract_rating(&text, ticker) {
+            self.conn.execute(
+                "INSERT OR REPLACE INTO ratings (article_id, ticker, broker, action, rating, target_price)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    article_id,
+                    rating.ticker,
+                    rating.broker,
+                    rating.action.map(|a| a.as_str()),
+                    rating.rating,
+                    rating.target_price,
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Rating actions published at or after `since`, newest first,
+    /// optionally for a single ticker
+    pub fn get_ratings(
+        &self,
+        since: i64,
+        ticker: Option<&str>,
+        limit: usize,
+    ) -> Result<Vec<RatingEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT r.ticker, r.broker, r.action, r.rating, r.target_price,
+                    a.id, a.title, a.url, a.published_at
+             FROM ratings r JOIN articles a ON a.id = r.article_id
+             WHERE a.published_at >= ?1 AND (?2 IS NULL OR r.ticker = ?2)
+             ORDER BY a.published_at DESC LIMIT ?3",
+        )?;
+        let rows = stmt.query_map(params![since, ticker, limit as i64], |row| {
+            let action: Option<String> = row.get(2)?;
+            Ok(RatingEntry {
+                rating: Rating {
+                    ticker: row.get(0)?,
+                    broker: row.get(1)?,
+                    action: action.as_deref().and_then(RatingAction::from_str),
+                    rating: row.get(3)?,
+                    target_price: row.get(4)?,
+                },
+                article_id: row.get(5)?,
+                title: row.get(6)?,
+                url: row.get(7)?,
+                published_at: row.get(8)?,
+            })
+        })?;
+        rows.collect()
+    }
+
+    pub fn upsert_companies(&self, companies: &[Company]) -> Result<usize> {
+        let mut stmt = self.conn.prepare(
+            "INSERT INTO companies (ticker, name, aliases) VALUES (?1, ?2, ?3)
+             ON CONFLICT(ticker) DO UPDATE SET name = excluded.name, aliases = excluded.aliases",
+        )?;
+        let mut count = 0;
+        for company in companies {
+            let aliases_json = serde_json::to_string(&company.aliases).unwrap_or_default();
+            count += stmt.execute(params![company.ticker, company.name, aliases_json])?;
+        }
+        Ok(count)
+    }
+
+    pub fn get_companies(&self) -> Result<Vec<Company>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT ticker, name, aliases FROM companies ORDER BY ticker")?;
+        let rows = stmt.query_map([], |row| {
+            let aliases_str: String = row.get(2)?;
+            Ok(Company {
+                ticker: row.get(0)?,
+                name: row.get(1)?,
+                aliases: serde_json::from_str(&aliases_str).unwrap_or_default(),
+            })
+        })?;
+        rows.collect()
     }
 
     pub fn insert_article(&self, article: &Article) -> Result<bool> {
@@ -51,8 +400,8 @@ impl Db {
         };
 
         let result = self.conn.execute(
-            "INSERT OR IGNORE INTO articles (title, source, url, tickers, published_at, fetched_at, sentiment)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            "INSERT OR IGNORE INTO articles (title, source, url, tickers, published_at, fetched_at, sentiment, event, sentiment_score, language)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
             params![
                 article.title,
                 article.source,
@@ -61,40 +410,36 @@ impl Db {
                 article.published_at,
                 article.fetched_at,
                 sentiment_str,
+                article.event.map(|e| e.as_str()),
+                article.sentiment_score,
+                article.language.map(|l| l.as_str()),
             ],
         )?;
+        if result > 0 {
+            let id = self.conn.last_insert_rowid();
+            self.store_rating(id, &article.title, &article.tickers, None)?;
+        }
         Ok(result > 0)
     }
 
     pub fn get_articles(&self, limit: usize) -> Result<Vec<Article>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
              FROM articles ORDER BY published_at DESC LIMIT ?1",
         )?;
 
-        let rows = stmt.query_map(params![limit as i64], |row| {
-            let tickers_str: String = row.get(4)?;
-            let tickers: Vec<String> =
-                serde_json::from_str(&tickers_str).unwrap_or_default();
-            let sentiment_str: String = row.get(9)?;
-            let sentiment = match sentiment_str.as_str() {
-                "positive" => Sentiment::Positive,
-                "negative" => Sentiment::Negative,
-                _ => Sentiment::Neutral,
-            };
-            Ok(Article {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                source: row.get(2)?,
-                url: row.get(3)?,
-                tickers,
-                published_at: row.get(5)?,
-                fetched_at: row.get(6)?,
-                read: row.get::<_, i32>(7)? != 0,
-                bookmarked: row.get::<_, i32>(8)? != 0,
-                sentiment,
-            })
-        })?;
+        let rows = stmt.query_map(params![limit as i64], article_from_row)?;
+
+        rows.collect()
+    }
+
+    pub fn get_articles_since(&self, since: i64, limit: usize) -> Result<Vec<Article>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
+             FROM articles WHERE published_at >= ?1 ORDER BY published_at DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![since, limit as i64], article_from_row)?;
 
         rows.collect()
     }
@@ -112,111 +457,177 @@ impl Db {
         let where_clause = conditions.join(" OR ");
 
         let query = format!(
-            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
              FROM articles WHERE {} ORDER BY published_at DESC LIMIT ?1",
             where_clause
         );
 
         let mut stmt = self.conn.prepare(&query)?;
-        let rows = stmt.query_map(params![limit as i64], |row| {
-            let tickers_str: String = row.get(4)?;
-            let article_tickers: Vec<String> =
-                serde_json::from_str(&tickers_str).unwrap_or_default();
-            let sentiment_str: String = row.get(9)?;
-            let sentiment = match sentiment_str.as_str() {
-                "positive" => Sentiment::Positive,
-                "negative" => Sentiment::Negative,
-                _ => Sentiment::Neutral,
-            };
-            Ok(Article {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                source: row.get(2)?,
-                url: row.get(3)?,
-                tickers: article_tickers,
-                published_at: row.get(5)?,
-                fetched_at: row.get(6)?,
-                read: row.get::<_, i32>(7)? != 0,
-                bookmarked: row.get::<_, i32>(8)? != 0,
-                sentiment,
-            })
-        })?;
+        let rows = stmt.query_map(params![limit as i64], article_from_row)?;
 
         rows.collect()
     }
 
     pub fn get_unread_articles(&self, limit: usize) -> Result<Vec<Article>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
              FROM articles WHERE read = 0 ORDER BY published_at DESC LIMIT ?1",
         )?;
 
-        let rows = stmt.query_map(params![limit as i64], |row| {
-            let tickers_str: String = row.get(4)?;
-            let tickers: Vec<String> =
-                serde_json::from_str(&tickers_str).unwrap_or_default();
-            let sentiment_str: String = row.get(9)?;
-            let sentiment = match sentiment_str.as_str() {
-                "positive" => Sentiment::Positive,
-                "negative" => Sentiment::Negative,
-                _ => Sentiment::Neutral,
-            };
-            Ok(Article {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                source: row.get(2)?,
-                url: row.get(3)?,
-                tickers,
-                published_at: row.get(5)?,
-                fetched_at: row.get(6)?,
-                read: row.get::<_, i32>(7)? != 0,
-                bookmarked: row.get::<_, i32>(8)? != 0,
-                sentiment,
-            })
-        })?;
+        let rows = stmt.query_map(params![limit as i64], article_from_row)?;
 
         rows.collect()
     }
 
+    pub fn get_article(&self, id: i64) -> Result<Option<Article>> {
+        self.conn
+            .query_row(
+                "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
+                 FROM articles WHERE id = ?1",
+                params![id],
+                article_from_row,
+            )
+            .optional()
+    }
+
     pub fn get_bookmarked_articles(&self, limit: usize) -> Result<Vec<Article>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
              FROM articles WHERE bookmarked = 1 ORDER BY published_at DESC LIMIT ?1",
         )?;
 
-        let rows = stmt.query_map(params![limit as i64], |row| {
-            let tickers_str: String = row.get(4)?;
-            let tickers: Vec<String> =
-                serde_json::from_str(&tickers_str).unwrap_or_default();
-            let sentiment_str: String = row.get(9)?;
-            let sentiment = match sentiment_str.as_str() {
-                "positive" => Sentiment::Positive,
-                "negative" => Sentiment::Negative,
-                _ => Sentiment::Neutral,
-            };
-            Ok(Article {
-                id: row.get(0)?,
-                title: row.get(1)?,
-                source: row.get(2)?,
-                url: row.get(3)?,
-                tickers,
-                published_at: row.get(5)?,
-                fetched_at: row.get(6)?,
-                read: row.get::<_, i32>(7)? != 0,
-                bookmarked: row.get::<_, i32>(8)? != 0,
-                sentiment,
-            })
+        let rows = stmt.query_map(params![limit as i64], article_from_row)?;
+
+        rows.collect()
+    }
+
+    pub fn get_articles_by_event(&self, event: EventKind, limit: usize) -> Result<Vec<Article>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
+             FROM articles WHERE event = ?1 ORDER BY published_at DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![event.as_str(), limit as i64], article_from_row)?;
+
+        rows.collect()
+    }
+
+    /// Articles that may announce a shareholder meeting, with stored bodies
+    pub fn get_meeting_articles(
+        &self,
+        since: i64,
+        limit: usize,
+    ) -> Result<Vec<(Article, Option<String>)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords, content
+             FROM articles
+             WHERE published_at >= ?1
+               AND (title LIKE '%rups%' OR title LIKE '%pemegang saham%'
+                    OR title LIKE '%general meeting%' OR title LIKE '%shareholder%'
+                    OR content LIKE '%rups%' OR content LIKE '%rapat umum pemegang saham%')
+             ORDER BY published_at DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![since, limit as i64], |row| {
+            Ok((article_from_row(row)?, row.get(15)?))
         })?;
 
         rows.collect()
     }
 
+    /// Articles whose title, tickers, or stored content contain the query
+    pub fn search_articles(&self, query: &str, limit: usize) -> Result<Vec<Article>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, source, url, tickers, published_at, fetched_at, read, bookmarked, sentiment, event, sentiment_score, word_count, language, keywords
+             FROM articles
+             WHERE title LIKE ?1 OR tickers LIKE ?1 OR keywords LIKE ?1 OR content LIKE ?1
+             ORDER BY published_at DESC LIMIT ?2",
+        )?;
+
+        let pattern = format!("%{}%", query);
+        let rows = stmt.query_map(params![pattern, limit as i64], article_from_row)?;
+
+        rows.collect()
+    }
+
+    pub fn article_id_by_url(&self, url: &str) -> Result<Option<i64>> {
+        let mut stmt = self.conn.prepare("SELECT id FROM articles WHERE url = ?1")?;
+        let mut rows = stmt.query_map(params![url], |row| row.get(0))?;
+        rows.next().transpose()
+    }
+
     pub fn mark_read(&self, id: i64) -> Result<()> {
         self.conn
             .execute("UPDATE articles SET read = 1 WHERE id = ?1", params![id])?;
         Ok(())
     }
 
+    pub fn update_sentiment(&self, id: i64, score: f64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE articles SET sentiment = ?1, sentiment_score = ?2 WHERE id = ?3",
+            params![sentiment_from_score(score).as_str(), score, id],
+        )?;
+        Ok(())
+    }
+
+    /// Re-run ticker extraction and, when `rescore` is set, the sentiment
+    /// lexicon over up to `limit` articles with ids above `after_id`.
+    /// Returns how many rows changed and the last id looked at, or None
+    /// once there is nothing left
+    pub fn reanalyze_batch(
+        &self,
+        after_id: i64,
+        limit: usize,
+        rescore: bool,
+    ) -> Result<(usize, Option<i64>)> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, title, tickers, sentiment_score FROM articles WHERE id > ?1 ORDER BY id LIMIT ?2",
+        )?;
+        let rows: Vec<(i64, String, String, f64)> = stmt
+            .query_map(params![after_id, limit as i64], |row| {
+                Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?))
+            })?
+            .collect::<Result<_>>()?;
+
+        let mut changed = 0;
+        for (id, title, tickers, score) in &rows {
+            let new_tickers =
+                serde_json::to_string(&feed::title_tickers(title)).unwrap_or_default();
+            let new_score = if rescore {
+                sentiment_score(title)
+            } else {
+                *score
+            };
+            if new_tickers == *tickers && new_score == *score {
+                continue;
+            }
+            self.conn.execute(
+                "UPDATE articles SET tickers = ?1, sentiment = ?2, sentiment_score = ?3 WHERE id = ?4",
+                params![new_tickers, sentiment_from_score(new_score).as_str(), new_score, id],
+            )?;
+            changed += 1;
+        }
+        Ok((changed, rows.last().map(|r| r.0)))
+    }
+
+    pub fn get_meta(&self, key: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT value FROM meta WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    pub fn set_meta(&self, key: &str, value: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO meta (key, value) VALUES (?1, ?2)",
+            params![key, value],
+        )?;
+        Ok(())
+    }
+
     pub fn toggle_bookmark(&self, id: i64) -> Result<bool> {
         self.conn.execute(
             "UPDATE articles SET bookmarked = CASE WHEN bookmarked = 0 THEN 1 ELSE 0 END WHERE id = ?1",
@@ -244,14 +655,152 @@ impl Db {
         )
     }
 
+    /// Article count and most recent fetch time per source name
+    pub fn source_stats(&self) -> Result<Vec<(String, i64, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT source, COUNT(*), MAX(fetched_at) FROM articles GROUP BY source",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+        rows.collect()
+    }
+
+    /// Every ticker seen in stored articles with its article count, most frequent first
+    pub fn ticker_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT j.value, COUNT(*) AS n FROM articles, json_each(articles.tickers) j
+             GROUP BY j.value ORDER BY n DESC, j.value ASC",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    }
+
+    /// Per-ticker sentiment score sum and article count for articles
+    /// published at or after `since`
+    pub fn ticker_sentiment_since(&self, since: i64) -> Result<Vec<(String, f64, i64)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT j.value, SUM(sentiment_score), COUNT(*)
+             FROM articles, json_each(articles.tickers) j
+             WHERE published_at >= ?1
+             GROUP BY j.value",
+        )?;
+        let rows = stmt.query_map(params![since], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })?;
+        rows.collect()
+    }
+
+    pub fn sentiment_counts(&self) -> Result<Vec<(String, i64)>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT sentiment, COUNT(*) FROM articles GROUP BY sentiment")?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect()
+    }
+
+    pub fn bookmarked_count(&self) -> Result<i64> {
+        self.conn.query_row(
+            "SELECT COUNT(*) FROM articles WHERE bookmarked = 1",
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    /// Result of SQLite's integrity check ("ok" when healthy)
+    pub fn integrity_check(&self) -> Result<String> {
+        self.conn
+            .query_row("PRAGMA integrity_check", [], |row| row.get(0))
+    }
+
+    /// On-disk size of the database in bytes
+    pub fn size_bytes(&self) -> Result<i64> {
+        self.conn.query_row(
+            "SELECT page_count * page_size FROM pragma_page_count(), pragma_page_size()",
+            [],
+            |row| row.get(0),
+        )
+    }
+
     pub fn save_content(&self, article_id: i64, content: &str) -> Result<()> {
+        // Count terms once per article, not on every refetch
+        let first_fetch: bool = self.conn.query_row(
+            "SELECT content IS NULL FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )?;
+        if first_fetch {
+            self.count_terms(content)?;
+        }
         self.conn.execute(
-            "UPDATE articles SET content = ?1 WHERE id = ?2",
-            params![content, article_id],
+            "UPDATE articles SET content = ?1, word_count = ?2,
+                 language = COALESCE(?3, language) WHERE id = ?4",
+            params![
+                content,
+                word_count(content) as i64,
+                Language::detect(content).map(|l| l.as_str()),
+                article_id
+            ],
+        )?;
+
+        self.store_keywords(article_id, content)?;
+
+        // The body often carries the target price the headline leaves out
+        let (title, tickers): (String, String) = self.conn.query_row(
+            "SELECT title, tickers FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let tickers: Vec<String> = serde_json::from_str(&tickers).unwrap_or_default();
+        self.store_rating(article_id, &title, &tickers, Some(content))
+    }
+
+    /// Add one to the document frequency of each term in `content`
+    fn count_terms(&self, content: &str) -> Result<()> {
+        let mut stmt = self.conn.prepare(
+            "INSERT INTO term_df (term, docs) VALUES (?1, 1)
+             ON CONFLICT(term) DO UPDATE SET docs = docs + 1",
+        )?;
+        for term in keywords::terms(content) {
+            stmt.execute(params![term])?;
+        }
+        Ok(())
+    }
+
+    fn store_keywords(&self, article_id: i64, content: &str) -> Result<()> {
+        let docs: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM articles WHERE content IS NOT NULL",
+            [],
+            |row| row.get(0),
+        )?;
+        let mut stmt = self
+            .conn
+            .prepare("SELECT docs FROM term_df WHERE term = ?1")?;
+        let keywords = keywords::extract(
+            content,
+            |term| {
+                stmt.query_row(params![term], |row| row.get::<_, i64>(0))
+                    .unwrap_or(0) as usize
+            },
+            docs as usize,
+        );
+        self.conn.execute(
+            "UPDATE articles SET keywords = ?1 WHERE id = ?2",
+            params![
+                serde_json::to_string(&keywords).unwrap_or_default(),
+                article_id
+            ],
         )?;
         Ok(())
     }
 
+    pub fn get_keywords(&self, article_id: i64) -> Result<Vec<String>> {
+        let json: String = self.conn.query_row(
+            "SELECT keywords FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )?;
+        Ok(serde_json::from_str(&json).unwrap_or_default())
+    }
+
     pub fn get_content(&self, article_id: i64) -> Result<Option<String>> {
         self.conn.query_row(
             "SELECT content FROM articles WHERE id = ?1",
@@ -259,4 +808,296 @@ impl Db {
             |row| row.get(0),
         )
     }
+
+    pub fn save_read_position(&self, article_id: i64, position: u16) -> Result<()> {
+        self.conn.execute(
+            "UPDATE articles SET read_position = ?1 WHERE id = ?2",
+            params![position, article_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_read_position(&self, article_id: i64) -> Result<u16> {
+        self.conn.query_row(
+            "SELECT read_position FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )
+    }
+
+    pub fn save_links(&self, article_id: i64, links: &[Link]) -> Result<()> {
+        let json = serde_json::to_string(links).unwrap_or_else(|_| "[]".to_string());
+        self.conn.execute(
+            "UPDATE articles SET links = ?1 WHERE id = ?2",
+            params![json, article_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_links(&self, article_id: i64) -> Result<Vec<Link>> {
+        let json: Option<String> = self.conn.query_row(
+            "SELECT links FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )?;
+        Ok(json
+            .and_then(|j| serde_json::from_str(&j).ok())
+            .unwrap_or_default())
+    }
+
+    pub fn save_lead_image(&self, article_id: i64, path: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE articles SET lead_image = ?1 WHERE id = ?2",
+            params![path, article_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_lead_image(&self, article_id: i64) -> Result<Option<String>> {
+        self.conn.query_row(
+            "SELECT lead_image FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )
+    }
+
+    /// Bookmarked articles still missing their content or lead image, as
+    /// (id, url, source) for an offline reading pack
+    pub fn get_offline_candidates(&self) -> Result<Vec<(i64, String, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, url, source FROM articles
+             WHERE bookmarked = 1 AND (content IS NULL OR lead_image IS NULL)
+             ORDER BY published_at DESC",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?;
+
+        rows.collect()
+    }
+
+    pub fn mark_sent(&self, article_id: i64, service: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO sent_articles (article_id, service, sent_at) VALUES (?1, ?2, ?3)",
+            params![article_id, service, chrono::Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// Ids of articles already sent to a read-later service
+    pub fn get_sent_ids(&self) -> Result<std::collections::HashSet<i64>> {
+        let mut stmt = self
+            .conn
+            .prepare("SELECT DISTINCT article_id FROM sent_articles")?;
+        let rows = stmt.query_map([], |row| row.get(0))?;
+
+        rows.collect()
+    }
+
+    /// Record that an alert rule fired for an article; firing twice is a no-op
+    pub fn record_alert(&self, rule: &str, article_id: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR IGNORE INTO alerts (rule, article_id, created_at) VALUES (?1, ?2, ?3)",
+            params![rule, article_id, chrono::Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// Fired alerts, newest first; `unacknowledged_only` skips reviewed ones
+    pub fn get_alerts(&self, unacknowledged_only: bool, limit: usize) -> Result<Vec<AlertRecord>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT l.id, l.rule, l.article_id, a.title, a.url, l.created_at, l.acknowledged
+             FROM alerts l JOIN articles a ON a.id = l.article_id
+             WHERE ?1 = 0 OR l.acknowledged = 0
+             ORDER BY l.created_at DESC, l.id DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![unacknowledged_only, limit as i64], |row| {
+            Ok(AlertRecord {
+                id: row.get(0)?,
+                rule: row.get(1)?,
+                article_id: row.get(2)?,
+                title: row.get(3)?,
+                url: row.get(4)?,
+                created_at: row.get(5)?,
+                acknowledged: row.get(6)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    pub fn set_alert_acknowledged(&self, id: i64, acknowledged: bool) -> Result<()> {
+        self.conn.execute(
+            "UPDATE alerts SET acknowledged = ?1 WHERE id = ?2",
+            params![acknowledged, id],
+        )?;
+        Ok(())
+    }
+
+    pub fn unacknowledged_alert_count(&self) -> Result<i64> {
+        self.conn.query_row(
+            "SELECT COUNT(*) FROM alerts WHERE acknowledged = 0",
+            [],
+            |row| row.get(0),
+        )
+    }
+
+    pub fn acknowledge_all_alerts(&self) -> Result<()> {
+        self.conn.execute(
+            "UPDATE alerts SET acknowledged = 1 WHERE acknowledged = 0",
+            [],
+        )?;
+        Ok(())
+    }
+
+    /// Duplicate-cluster choices, keyed by the cluster's oldest article id
+    pub fn get_cluster_prefs(&self) -> Result<HashMap<i64, ClusterPref>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT cluster_id, expanded, dismissed, preferred_source FROM cluster_prefs",
+        )?;
+        let rows = stmt.query_map([], |row| {
+            Ok((
+                row.get(0)?,
+                ClusterPref {
+                    expanded: row.get(1)?,
+                    dismissed: row.get(2)?,
+                    preferred_source: row.get(3)?,
+                },
+            ))
+        })?;
+
+        rows.collect()
+    }
+
+    /// Store a cluster's choices; a cluster back at the defaults is removed
+    pub fn save_cluster_pref(&self, cluster_id: i64, pref: &ClusterPref) -> Result<()> {
+        if *pref == ClusterPref::default() {
+            self.conn.execute(
+                "DELETE FROM cluster_prefs WHERE cluster_id = ?1",
+                params![cluster_id],
+            )?;
+        } else {
+            self.conn.execute(
+                "INSERT OR REPLACE INTO cluster_prefs (cluster_id, expanded, dismissed, preferred_source)
+                 VALUES (?1, ?2, ?3, ?4)",
+                params![
+                    cluster_id,
+                    pref.expanded,
+                    pref.dismissed,
+                    pref.preferred_source
+                ],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn add_highlight(&self, article_id: i64, text: &str, note: Option<&str>) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO highlights (article_id, text, note, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![article_id, text, note, chrono::Utc::now().timestamp()],
+        )?;
+        Ok(())
+    }
+
+    /// Saved highlights, newest first; `article_id` narrows to one article
+    pub fn get_highlights(&self, article_id: Option<i64>, limit: usize) -> Result<Vec<Highlight>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT h.id, h.article_id, a.title, a.url, h.text, h.note, h.created_at
+             FROM highlights h JOIN articles a ON a.id = h.article_id
+             WHERE ?1 IS NULL OR h.article_id = ?1
+             ORDER BY h.created_at DESC, h.id DESC LIMIT ?2",
+        )?;
+
+        let rows = stmt.query_map(params![article_id, limit as i64], |row| {
+            Ok(Highlight {
+                id: row.get(0)?,
+                article_id: row.get(1)?,
+                title: row.get(2)?,
+                url: row.get(3)?,
+                text: row.get(4)?,
+                note: row.get(5)?,
+                created_at: row.get(6)?,
+            })
+        })?;
+
+        rows.collect()
+    }
+
+    pub fn delete_highlight(&self, id: i64) -> Result<()> {
+        self.conn
+            .execute("DELETE FROM highlights WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    pub fn save_summary(&self, article_id: i64, summary: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE articles SET summary = ?1 WHERE id = ?2",
+            params![summary, article_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn save_translation(&self, article_id: i64, lang: &str, content: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT OR REPLACE INTO translations (article_id, lang, content) VALUES (?1, ?2, ?3)",
+            params![article_id, lang, content],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_translation(&self, article_id: i64, lang: &str) -> Result<Option<String>> {
+        self.conn
+            .query_row(
+                "SELECT content FROM translations WHERE article_id = ?1 AND lang = ?2",
+                params![article_id, lang],
+                |row| row.get(0),
+            )
+            .optional()
+    }
+
+    pub fn get_summary(&self, article_id: i64) -> Result<Option<String>> {
+        self.conn.query_row(
+            "SELECT summary FROM articles WHERE id = ?1",
+            params![article_id],
+            |row| row.get(0),
+        )
+    }
+}
+
+/// Map a row selected as `id, title, source, url, tickers, published_at,
+/// fetched_at, read, bookmarked, sentiment, event, sentiment_score,
+/// word_count, language, keywords`
+fn article_from_row(row: &rusqlite::Row) -> Result<Article> {
+    let tickers_str: String = row.get(4)?;
+    let mut tickers: Vec<String> = serde_json::from_str(&tickers_str).unwrap_or_default();
+    tickers.retain(|t| feed::keep_ticker(t));
+    let sentiment_str: String = row.get(9)?;
+    let sentiment = match sentiment_str.as_str() {
+        "positive" => Sentiment::Positive,
+        "negative" => Sentiment::Negative,
+        _ => Sentiment::Neutral,
+    };
+    let event: Option<String> = row.get(10)?;
+    let title: String = row.get(1)?;
+    let source: String = row.get(2)?;
+    Ok(Article {
+        id: row.get(0)?,
+        topics: feed::article_topics(&title, &source),
+        title: title.into(),
+        source: intern(&source),
+        url: row.get::<_, String>(3)?.into(),
+        tickers,
+        published_at: row.get(5)?,
+        fetched_at: row.get(6)?,
+        read: row.get::<_, i32>(7)? != 0,
+        bookmarked: row.get::<_, i32>(8)? != 0,
+        sentiment,
+        sentiment_score: row.get(11)?,
+        event: event.as_deref().and_then(EventKind::from_str),
+        word_count: row.get::<_, Option<i64>>(12)?.map(|n| n as usize),
+        language: row
+            .get::<_, Option<String>>(13)?
+            .as_deref()
+            .and_then(Language::from_str),
+        keywords: serde_json::from_str(&row.get::<_, String>(14)?).unwrap_or_default(),
+    })
 }
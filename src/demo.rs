@@ -0,0 +1,189 @@
+use crate::config::{
+    EmailConfig, LlmConfig, ReadLaterConfig, ResolvedConfig, SentimentBackendConfig,
+    TranslateConfig,
+};
+use crate::db::Db;
+use crate::feed::{self, ArticlePage};
+use crate::model::{Article, FeedSource};
+
+/// Bundled sample feeds for `--demo`, as (source name, URL, RSS)
+const FEEDS: &[(&str, &str, &str)] = &[
+    (
+        "CNBC Indo",
+        "demo:cnbc",
+        include_str!("../assets/demo/cnbc.xml"),
+    ),
+    (
+        "Tempo Bisnis",
+        "demo:tempo",
+        include_str!("../assets/demo/tempo.xml"),
+    ),
+    (
+        "IDX Channel",
+        "demo:idxchannel",
+        include_str!("../assets/demo/idxchannel.xml"),
+    ),
+];
+
+/// Where every bundled article link points; the host never resolves
+const ARTICLE_PREFIX: &str = "https://demo.invalid/";
+
+/// Publish time of the newest bundled item. Every item is shifted by the
+/// same amount so it lands a few minutes before now.
+const LATEST: &str = "2026-01-05T09:20:00+07:00";
+
+/// Turn off everything that would reach the network or the user's own
+/// programs, so sample articles never land in real webhooks, hooks,
+/// scripts, mailboxes, or read-later accounts
+pub fn isolate(resolved: &mut ResolvedConfig) {
+    resolved.quotes.enabled = false;
+    resolved.community.url = None;
+    resolved.webhooks.clear();
+    resolved.on_new_article = None;
+    resolved.on_holding_article = None;
+    resolved.script = None;
+    resolved.sentiment_backend = SentimentBackendConfig::default();
+    resolved.llm = LlmConfig::default();
+    resolved.translate = TranslateConfig::default();
+    resolved.read_later = ReadLaterConfig::default();
+    resolved.email = EmailConfig::default();
+    resolved.ipc.enabled = false;
+    resolved.status_file.path = None;
+}
+
+/// Sources that read the bundled feeds instead of the network
+pub fn sources() -> Vec<FeedSource> {
+    FEEDS
+        .iter()
+        .map(|(name, url, _)| FeedSource::new(name, url))
+        .collect()
+}
+
+/// The bundled RSS behind a `demo:` source URL
+pub fn feed(url: &str) -> Option<&'static str> {
+    FEEDS
+        .iter()
+        .find(|(_, u, _)| *u == url)
+        .map(|(_, _, xml)| *xml)
+}
+
+/// Move bundled publish times up to the present, keeping their spacing
+pub fn rebase(mut articles: Vec<Article>) -> Vec<Article> {
+    let latest = chrono::DateTime::parse_from_rfc3339(LATEST)
+        .map(|t| t.timestamp())
+        .unwrap_or_default();
+    let shift = chrono::Utc::now().timestamp() - 5 * 60 - latest;
+    for article in &mut articles {
+        article.published_at += shift;
+    }
+    articles
+}
+
+/// The body of a bundled article, served in place of fetching its page
+pub fn article_page(url: &str) -> Option<ArticlePage> {
+    if !url.starts_with(ARTICLE_PREFIX) {
+        return None;
+    }
+    FEEDS.iter().find_map(|(_, _, xml)| {
+        let parsed = feed_rs::parser::parse(xml.as_bytes()).ok()?;
+        let entry = parsed
+            .entries
+            .into_iter()
+            .find(|e| e.links.iter().any(|l| l.href == url))?;
+        let content = entry
+            .summary
+            .map(|t| t.content)
+            .unwrap_or_default()
+            .split("\n\n")
+            .map(|p| p.split_whitespace().collect::<Vec<_>>().join(" "))
+            .filter(|p| !p.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        Some(ArticlePage {
+            title: entry.title.map(|t| t.content),
+            content,
+            image: None,
+            links: Vec::new(),
+        })
+    })
+}
+
+/// Store every bundled article, so the feed is full before the first
+/// refresh and headless commands have something to work with
+pub fn seed(db: &Db) {
+    for source in sources() {
+        let Some(xml) = feed(&source.url) else {
+            continue;
+        };
+        if let Ok(articles) = feed::parse_feed(xml.as_bytes(), &source) {
+            for article in rebase(articles) {
+                let _ = db.insert_article(&article);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::{self, CliArgs, ConfigFile};
+    use crate::{llm, readlater, sentiment, translate, webhook};
+    use clap::Parser;
+
+    const CONFIG: &str = r#"
+on_new_article = "notify-send new"
+on_holding_article = "notify-send holding"
+script = "~/.config/stocknewstui/hooks.rhai"
+
+[[webhooks]]
+url = "https://hooks.slack.com/services/T000/B000/XXXX"
+
+[ipc]
+enabled = true
+
+[status_file]
+path = "/tmp/stocknewstui-status"
+
+[sentiment_backend]
+kind = "http"
+url = "http://localhost:8000/score"
+
+[llm]
+provider = "ollama"
+
+[translate]
+kind = "llm"
+
+[read_later]
+service = "pocket"
+
+[email]
+to = "me@example.com"
+daily_hour = 7
+
+[community]
+url = "https://example.com/sentiment"
+"#;
+
+    #[test]
+    fn isolate_leaves_no_outbound_sinks() {
+        let cfg: ConfigFile = toml::from_str(CONFIG).unwrap();
+        let mut resolved = config::resolve(&CliArgs::parse_from(["stocknewstui"]), &cfg);
+        isolate(&mut resolved);
+
+        assert!(webhook::Webhook::from_config(&resolved.webhooks).is_empty());
+        assert!(resolved.on_new_article.is_none());
+        assert!(resolved.on_holding_article.is_none());
+        assert!(resolved.script.is_none());
+        assert!(resolved.ipc.socket_path().is_none());
+        assert!(resolved.status_file.path().is_none());
+        assert!(sentiment::Backend::from_config(&resolved.sentiment_backend).is_none());
+        let llm = llm::Llm::from_config(&resolved.llm);
+        assert!(llm.is_none());
+        assert!(translate::Translator::from_config(&resolved.translate, llm.as_ref()).is_none());
+        assert!(readlater::Service::from_config(&resolved.read_later).is_none());
+        assert!(resolved.email.to.is_none() && resolved.email.daily_hour.is_none());
+        assert!(!resolved.quotes.enabled);
+        assert!(resolved.community.url.is_none());
+    }
+}
@@ -0,0 +1,212 @@
+use crate::config::{DigestGroup, EmailConfig, SmtpConfig};
+use crate::model::{Article, TimeDisplay};
+use lettre::message::header::{ContentType, MIME_VERSION_1_0};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+/// Give up on an SMTP server that stops responding
+const SMTP_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Group articles by ticker or source, headings sorted, untagged articles last
+pub fn group_articles(articles: &[Article], by: DigestGroup) -> Vec<(String, Vec<&Article>)> {
+    let mut groups: BTreeMap<String, Vec<&Article>> = BTreeMap::new();
+    let mut other: Vec<&Article> = Vec::new();
+    for article in articles {
+        match by {
+            DigestGroup::Source => groups
+                .entry(article.source.to_string())
+                .or_default()
+                .push(article),
+            DigestGroup::Ticker if article.tickers.is_empty() => other.push(article),
+            DigestGroup::Ticker => {
+                for ticker in &article.tickers {
+                    groups.entry(ticker.clone()).or_default().push(article);
+                }
+            }
+        }
+    }
+
+    let mut result: Vec<(String, Vec<&Article>)> = groups.into_iter().collect();
+    if !other.is_empty() {
+        result.push(("Other".to_string(), other));
+    }
+    result
+}
+
+/// Article time in the configured zone
+fn format_time(time: &TimeDisplay, ts: i64) -> String {
+    time.format(ts, "%m-%d %H:%M")
+}
+
+/// Plain-text digest, as printed by `digest` and sent by `email-digest`
+pub fn render_text(
+    hours: u64,
+    articles: &[Article],
+    by: DigestGroup,
+    time: &TimeDisplay,
+) -> String {
+    let mut out = format!(
+        "StockNewsTUI digest: last {}h, {} articles\n",
+        hours,
+        articles.len()
+    );
+
+    for (heading, items) in group_articles(articles, by) {
+        out.push_str(&format!("\n== {} ({}) ==\n", heading, items.len()));
+        for article in items {
+            let detail = match by {
+                DigestGroup::Ticker => article.source.to_string(),
+                DigestGroup::Source => article.tickers.join(","),
+            };
+            out.push_str(&format!(
+                "  {} {}  {}  [{}]\n",
+                article.sentiment.label(),
+                format_time(time, article.published_at),
+                article.title,
+                detail
+            ));
+        }
+    }
+    out
+}
+
+pub fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// HTML digest grouped per ticker, with sentiment colors and article links
+pub fn render_html(hours: u64, articles: &[Article], time: &TimeDisplay) -> String {
+    let mut out = format!(
+        "<html><body style=\"font-family: sans-serif\">\n<h2>StockNewsTUI digest</h2>\n<p>Last {}h, {} articles</p>\n",
+        hours,
+        articles.len()
+    );
+
+    for (heading, items) in group_articles(articles, DigestGroup::Ticker) {
+        out.push_str(&format!(
+            "<h3>{} ({})</h3>\n<ul>\n",
+            escape_html(&heading),
+            items.len()
+        ));
+        for article in items {
+            let color = match article.sentiment {
+                crate::model::Sentiment::Positive => "#2e7d32",
+                crate::model::Sentiment::Negative => "#c62828",
+                crate::model::Sentiment::Neutral => "#757575",
+            };
+            out.push_str(&format!(
+                "<li><b style=\"color: {}\">{}</b> {} <a href=\"{}\">{}</a> <i>{}</i></li>\n",
+                color,
+                article.sentiment.label(),
+                format_time(time, article.published_at),
+                escape_html(&article.url),
+                escape_html(&article.title),
+                escape_html(&article.source)
+            ));
+        }
+        out.push_str("</ul>\n");
+    }
+    out.push_str("</body></html>\n");
+    out
+}
+
+/// Send a message through the [email.smtp] server when one is set, else
+/// hand it to the configured mail command (sendmail-compatible, e.g.
+/// `sendmail -t` or `msmtp -t`), which does the actual delivery.
+pub fn send_email(cfg: &EmailConfig, subject: &str, body: &str, html: bool) -> Result<(), String> {
+    let message = build_message(cfg, subject, body, html)?;
+    match cfg.smtp {
+        Some(ref smtp) => send_smtp(smtp, &message),
+        None => send_command(&cfg.command, &message.formatted()),
+    }
+}
+
+/// Headers are RFC 2047-encoded as needed, so a non-ASCII subject survives
+fn build_message(
+    cfg: &EmailConfig,
+    subject: &str,
+    body: &str,
+    html: bool,
+) -> Result<Message, String> {
+    let to = cfg.to.as_deref().ok_or("email.to is not set in config")?;
+    let from = cfg.from.as_deref().unwrap_or("stocknewstui@localhost");
+    let from = from
+        .parse()
+        .map_err(|e| format!("email.from '{}': {}", from, e))?;
+    let mut builder = Message::builder()
+        .from(from)
+        .subject(subject)
+        .header(MIME_VERSION_1_0)
+        .header(if html {
+            ContentType::TEXT_HTML
+        } else {
+            ContentType::TEXT_PLAIN
+        });
+    for address in to.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+        let mailbox = address
+            .parse()
+            .map_err(|e| format!("email.to '{}': {}", address, e))?;
+        builder = builder.to(mailbox);
+    }
+    builder.body(body.to_string()).map_err(|e| e.to_string())
+}
+
+fn send_smtp(cfg: &SmtpConfig, message: &Message) -> Result<(), String> {
+    let builder = match cfg.security.as_str() {
+        "starttls" => SmtpTransport::starttls_relay(&cfg.host),
+        "tls" => SmtpTransport::relay(&cfg.host),
+        "none" => Ok(SmtpTransport::builder_dangerous(&cfg.host)),
+        other => {
+            return Err(format!(
+                "email.smtp.security must be starttls, tls, or none, not '{}'",
+                other
+            ))
+        }
+    };
+    let mut builder = builder
+        .map_err(|e| format!("{}: {}", cfg.host, e))?
+        .timeout(Some(SMTP_TIMEOUT));
+    if let Some(port) = cfg.port {
+        builder = builder.port(port);
+    }
+    if let Some(ref username) = cfg.username {
+        let password = cfg
+            .password_env
+            .as_deref()
+            .and_then(|var| std::env::var(var).ok())
+            .unwrap_or_default();
+        builder = builder.credentials(Credentials::new(username.clone(), password));
+    }
+    builder
+        .build()
+        .send(message)
+        .map(|_| ())
+        .map_err(|e| format!("{}: {}", cfg.host, e))
+}
+
+fn send_command(command: &str, message: &[u8]) -> Result<(), String> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(command)
+        .stdin(Stdio::piped())
+        .spawn()
+        .map_err(|e| format!("Failed to run '{}': {}", command, e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(message)
+            .map_err(|e| format!("Failed to write message: {}", e))?;
+    }
+    let status = child.wait().map_err(|e| e.to_string())?;
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("'{}' exited with {}", command, status))
+    }
+}
@@ -0,0 +1,128 @@
+use crate::model::Article;
+use chrono::Datelike;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Reporting period named in a results headline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Period {
+    pub year: i32,
+    /// Months covered by the report (3 = Q1, 6 = H1/Q2, 9 = 9M/Q3, 12 = FY/Q4)
+    pub months: u8,
+    /// Quarterly (vs. cumulative) figures, used only for the label
+    pub quarter: bool,
+}
+
+impl Period {
+    pub fn label(&self) -> String {
+        let part = match (self.months, self.quarter) {
+            (m, true) => format!("Q{}", m / 3),
+            (6, false) => "H1".to_string(),
+            (9, false) => "9M".to_string(),
+            _ => "FY".to_string(),
+        };
+        format!("{} {}", part, self.year)
+    }
+}
+
+static QUARTER_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:Q|kuartal\s+|triwulan\s+)(IV|I{1,3}|[1-4])\b(?:\s*[/-]?\s*(20\d{2}))?")
+        .unwrap()
+});
+static SHORT_QUARTER_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b([1-4])Q\s?(\d{2})\b").unwrap());
+static HALF_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:semester\s+(II|I|[12])|([12])H|H([12]))\b(?:\s*[/-]?\s*(20\d{2}))?")
+        .unwrap()
+});
+static NINE_MONTH_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:9M|9\s+bulan|sembilan\s+bulan)\b(?:\s*[/-]?\s*(20\d{2}))?").unwrap()
+});
+static FULL_YEAR_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:FY\s?|tahun\s+buku\s+|full[\s-]year\s+|sepanjang\s+(?:tahun\s+)?)(20\d{2})\b",
+    )
+    .unwrap()
+});
+
+fn roman_or_digit(s: &str) -> Option<u8> {
+    match s.to_uppercase().as_str() {
+        "I" | "1" => Some(1),
+        "II" | "2" => Some(2),
+        "III" | "3" => Some(3),
+        "IV" | "4" => Some(4),
+        _ => None,
+    }
+}
+
+/// Pull the reporting period out of a headline. When the year is missing,
+/// assume the most recent period of that kind that had ended by
+/// `published_at`.
+pub fn extract_period(text: &str, published_at: i64) -> Option<Period> {
+    let published = chrono::DateTime::from_timestamp(published_at, 0)?;
+    let resolve_year = |year: Option<&str>, months: u8| -> i32 {
+        match year.and_then(|y| y.parse::<i32>().ok()) {
+            Some(y) if y < 100 => 2000 + y,
+            Some(y) => y,
+            None if (published.month() as u8) <= months => published.year() - 1,
+            None => published.year(),
+        }
+    };
+
+    if let Some(c) = FULL_YEAR_RE.captures(text) {
+        return Some(Period {
+            year: resolve_year(c.get(1).map(|m| m.as_str()), 12),
+            months: 12,
+            quarter: false,
+        });
+    }
+    if let Some(c) = NINE_MONTH_RE.captures(text) {
+        return Some(Period {
+            year: resolve_year(c.get(1).map(|m| m.as_str()), 9),
+            months: 9,
+            quarter: false,
+        });
+    }
+    if let Some(c) = HALF_RE.captures(text) {
+        let half = c
+            .get(1)
+            .or(c.get(2))
+            .or(c.get(3))
+            .and_then(|m| roman_or_digit(m.as_str()))?;
+        let months = if half == 1 { 6 } else { 12 };
+        return Some(Period {
+            year: resolve_year(c.get(4).map(|m| m.as_str()), months),
+            months,
+            quarter: false,
+        });
+    }
+    if let Some(c) = QUARTER_RE
+        .captures(text)
+        .or_else(|| SHORT_QUARTER_RE.captures(text))
+    {
+        let q = roman_or_digit(c.get(1)?.as_str())?;
+        let months = q * 3;
+        return Some(Period {
+            year: resolve_year(c.get(2).map(|m| m.as_str()), months),
+            months,
+            quarter: true,
+        });
+    }
+    None
+}
+
+/// Results articles grouped for the earnings calendar: newest period first,
+/// then by ticker; articles without a detectable period sort last.
+pub fn calendar(articles: Vec<Article>) -> Vec<(Option<Period>, Article)> {
+    let mut rows: Vec<(Option<Period>, Article)> = articles
+        .into_iter()
+        .map(|a| (extract_period(&a.title, a.published_at), a))
+        .collect();
+    // Descending on Option<Period> puts None (undated) last
+    rows.sort_by(|(pa, a), (pb, b)| {
+        pb.cmp(pa)
+            .then_with(|| a.tickers.first().cmp(&b.tickers.first()))
+            .then_with(|| b.published_at.cmp(&a.published_at))
+    });
+    rows
+}
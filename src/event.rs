@@ -1,25 +1,90 @@
 use crate::app::{App, InputMode, SourceFetchState, SourceInputField};
+use crate::community;
 use crate::config;
-use crate::db::Db;
+use crate::db::{Db, ANALYSIS_KEY, LAST_REFRESH_KEY};
+use crate::earnings;
 use crate::feed;
+use crate::hook;
+use crate::ingest;
+use crate::ipc;
+use crate::ipo;
+use crate::keymap;
+use crate::llm;
 use crate::model::*;
+use crate::quotes::{self, Quote};
+use crate::ratings::RatingAction;
+use crate::readlater;
+use crate::rups;
+use crate::sentiment;
+use crate::statusline::{self, Status};
+use crate::translate;
 use crate::ui;
-use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crate::watchlist;
+use crate::webhook;
+use crossterm::event::{
+    self, DisableMouseCapture, EnableMouseCapture, Event, KeyCode, KeyEventKind, KeyModifiers,
+};
+use crossterm::execute;
+use crossterm::terminal::{
+    disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+};
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
+use std::collections::HashMap;
 use std::io;
 use std::time::{Duration, Instant};
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, watch};
 
 const POLL_RATE: Duration = Duration::from_millis(100);
+// Redraw intervals with nothing new to show: once a second while the
+// refresh countdown or a status message is up, else for the time column
+const CLOCK_REDRAW: Duration = Duration::from_secs(1);
+const IDLE_REDRAW: Duration = Duration::from_secs(30);
+// Articles re-analyzed per pass of the event loop
+const REANALYSIS_BATCH: usize = 200;
+/// How often view state and the read position are snapshotted, so a crash
+/// or a closed terminal loses little
+const SNAPSHOT_INTERVAL: Duration = Duration::from_secs(10);
+/// How often the status bar file is checked for changes between refreshes,
+/// so reading articles brings its unread count down
+const STATUS_FILE_INTERVAL: Duration = Duration::from_secs(15);
+/// How long quitting waits for in-flight fetches and sends to land
+const QUIT_GRACE: Duration = Duration::from_secs(5);
+
+/// Time webhooks queued by the final flush get even when the quit grace
+/// has already run out
+const FINAL_SEND_GRACE: Duration = Duration::from_secs(2);
 
 struct FeedMsg {
     results: Vec<(String, Result<Vec<Article>, String>)>,
+    // Wall time of the refresh
+    elapsed: Duration,
 }
 
 struct ContentMsg {
     url: String,
     content: String,
+    links: Vec<Link>,
+    /// `content` is an error message rather than the article body
+    failed: bool,
+}
+
+/// One article of an offline pack: its page and downloaded lead image
+struct OfflineMsg {
+    id: i64,
+    result: Result<(feed::ArticlePage, Option<std::path::PathBuf>), String>,
+}
+
+/// A URL entered at the add-article prompt, with its article and body
+struct AddArticleMsg {
+    url: String,
+    result: Result<(Article, String), String>,
+}
+
+/// A linked page fetched for the reader's back-stack
+struct LinkMsg {
+    url: String,
+    result: Result<feed::ArticlePage, String>,
 }
 
 pub fn run_loop(
@@ -28,17 +93,78 @@ pub fn run_loop(
     db: Db,
 ) -> io::Result<()> {
     let rt = tokio::runtime::Runtime::new()?;
-    let client = reqwest::Client::builder()
-        .timeout(Duration::from_secs(15))
-        .user_agent("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36")
-        .build()
-        .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    let client = feed::build_client().map_err(io::Error::other)?;
 
     let (feed_tx, mut feed_rx) = mpsc::channel::<FeedMsg>(8);
     let (content_tx, mut content_rx) = mpsc::channel::<ContentMsg>(8);
+    let (quote_tx, mut quote_rx) = mpsc::channel::<Vec<Quote>>(8);
+    let (score_tx, mut score_rx) = mpsc::channel::<Vec<(i64, f64)>>(8);
+    let (community_tx, mut community_rx) = mpsc::channel::<Vec<(String, SentimentMeter)>>(8);
+    let (offline_tx, mut offline_rx) = mpsc::channel::<OfflineMsg>(8);
+    let (link_tx, mut link_rx) = mpsc::channel::<LinkMsg>(8);
+    let (add_tx, mut add_rx) = mpsc::channel::<AddArticleMsg>(8);
+    let (send_tx, mut send_rx) = mpsc::channel::<(i64, Result<(), String>)>(8);
+    let (summary_tx, mut summary_rx) = mpsc::channel::<(i64, Result<String, String>)>(8);
+    let (translation_tx, mut translation_rx) =
+        mpsc::channel::<(i64, String, Result<String, String>)>(8);
+    let (webhook_tx, mut webhook_rx) = mpsc::channel::<Vec<String>>(8);
+    let (ipc_tx, mut ipc_rx) = mpsc::channel::<ipc::Request>(8);
+    let (job_tx, mut job_rx) = mpsc::channel::<JobSignal>(4);
+    let (quote_tickers_tx, quote_tickers_rx) = watch::channel(app.quote_tickers());
+
+    if app.quotes_config.enabled {
+        quotes::spawn_quote_task(
+            &rt,
+            &client,
+            app.quotes_config.clone(),
+            quote_tickers_rx,
+            quote_tx,
+        );
+    }
+
+    // `kill -TSTP` and resuming after `kill -STOP` bypass the Ctrl+Z key
+    spawn_job_signals(&rt, job_tx);
+
+    // Removes the socket file when the loop ends
+    let _ipc_server = match app.ipc_socket.clone() {
+        Some(path) => match ipc::start(&rt, &path, ipc_tx) {
+            Ok(server) => Some(server),
+            Err(e) => {
+                app.set_status(format!("Control socket not started: {}", e));
+                None
+            }
+        },
+        None => None,
+    };
 
     // Load existing articles from DB
     reload_articles(&db, &mut app);
+    if app.view_mode == ViewMode::Bookmarks {
+        load_bookmarks(&db, &mut app);
+    }
+    load_sentiment_meters(&db, &mut app);
+    load_meetings(&db, &mut app);
+    app.sent_ids = db.get_sent_ids().unwrap_or_default();
+    app.cluster_prefs = db.get_cluster_prefs().unwrap_or_default();
+    // Alerts that fired while away, e.g. under `daemon`, stay on the banner
+    // until acknowledged
+    let unacknowledged = db.get_alerts(true, 50).unwrap_or_default();
+    for alert in unacknowledged.into_iter().rev() {
+        if let Ok(Some(article)) = db.get_article(alert.article_id) {
+            app.trading_alerts.push(article);
+        }
+    }
+    // Stored articles were tagged under older ticker rules, company names,
+    // or lexicon; a brand new database has nothing to redo
+    match db.get_meta(ANALYSIS_KEY) {
+        Ok(Some(ref stored)) if *stored != app.analysis_fingerprint => {
+            app.reanalysis_requested = true;
+        }
+        Ok(None) => {
+            let _ = db.set_meta(ANALYSIS_KEY, &app.analysis_fingerprint);
+        }
+        _ => {}
+    }
 
     // Initial fetch (all sources, bypass rate limit for first fetch)
     spawn_fetch(&rt, &client, &app.sources, &feed_tx);
@@ -52,101 +178,345 @@ pub fn run_loop(
             .last_fetch = Some(Instant::now());
     }
 
+    let mut last_snapshot = Instant::now();
+    let mut snapshot = app.to_view_state();
+    let status_path = app.status_file.path();
+    let mut status_written = String::new();
+    let mut last_status_check: Option<Instant> = None;
+    let mut last_draw = Instant::now();
+    // Refresh countdown and status message visibility as last drawn
+    let mut timed_shown = (false, false);
+
     loop {
         // Recompute display cache if data changed (filter + dedup)
         if app.display_dirty {
             app.recompute_display();
+            app.needs_redraw = true;
         }
 
-        // Render
-        terminal.draw(|f| ui::draw(f, &app))?;
+        // Render only after a change, while a spinner turns, or when the
+        // countdown, status message, or time column have moved on
+        let timed = (app.auto_refresh_active(), app.status_text().is_some());
+        let heartbeat = if timed.0 || timed.1 {
+            CLOCK_REDRAW
+        } else {
+            IDLE_REDRAW
+        };
+        if app.needs_redraw
+            || app.animating()
+            || timed != timed_shown
+            || last_draw.elapsed() >= heartbeat
+        {
+            terminal.draw(|f| ui::draw(f, &app))?;
+            app.needs_redraw = false;
+            timed_shown = timed;
+            last_draw = Instant::now();
+        }
 
         // Poll events
         if event::poll(POLL_RATE)? {
+            app.needs_redraw = true;
             match event::read()? {
                 Event::Key(key) => {
                     if key.kind != KeyEventKind::Press {
                         continue;
                     }
+                    app.last_input = Instant::now();
                     handle_key(&mut app, key, &rt, &client, &feed_tx, &content_tx, &db);
+                    if app.suspend_requested {
+                        app.suspend_requested = false;
+                        // A suspended job is often killed rather than resumed
+                        save_session(&app, &db);
+                        suspend(terminal)?;
+                    }
+                    if let Some(text) = app.pending_pipe.take() {
+                        let cmd = app.pipe_command.clone().unwrap_or_default();
+                        let status = pipe_to_command(terminal, &cmd, &text)?;
+                        app.set_status(status);
+                    }
+                }
+                Event::Resize(width, height) => {
+                    app.terminal_size = (width, height);
+                    // Rewrapped text is shorter or longer, so an old scroll
+                    // position can now point past the end
+                    if app.view_mode == ViewMode::Reader && !app.content_loading {
+                        clamp_reader_scroll(&mut app);
+                    }
                 }
-                Event::Resize(_, _) => {}
                 _ => {}
             }
         }
+        while let Ok(signal) = job_rx.try_recv() {
+            app.needs_redraw = true;
+            match signal {
+                JobSignal::Stop => {
+                    save_session(&app, &db);
+                    suspend(terminal)?;
+                }
+                JobSignal::Continue => restore_terminal(terminal)?,
+            }
+        }
+        flush_pending_keys(&mut app, &rt, &client, &feed_tx, &content_tx, &db);
+        if app.view_mode != ViewMode::Reader {
+            cancel_content_fetch(&mut app);
+        }
+
+        if last_snapshot.elapsed() >= SNAPSHOT_INTERVAL {
+            last_snapshot = Instant::now();
+            let state = app.to_view_state();
+            if state != snapshot || app.view_mode == ViewMode::Reader {
+                save_session(&app, &db);
+                snapshot = state;
+            }
+        }
 
         // Drain feed messages
         while let Ok(msg) = feed_rx.try_recv() {
-            app.is_fetching = false;
-            let mut total_new = 0;
-            let mut fetch_results = Vec::new();
-
-            for (source_name, result) in msg.results {
-                // Update per-source rate limit state
-                let state = app
-                    .source_fetch_state
-                    .entry(source_name.clone())
-                    .or_insert_with(SourceFetchState::new);
-                match &result {
-                    Ok(_) => state.record_success(),
-                    Err(_) => state.record_failure(),
-                }
+            last_status_check = None;
+            app.needs_redraw = true;
+            apply_feed_msg(&mut app, &db, msg);
+        }
 
-                match result {
-                    Ok(articles) => {
-                        let mut inserted = 0;
-                        for article in &articles {
-                            if let Ok(true) = db.insert_article(article) {
-                                inserted += 1;
-                            }
-                        }
-                        total_new += inserted;
-                        fetch_results.push((source_name, Ok(inserted)));
-                    }
-                    Err(e) => {
-                        fetch_results.push((source_name, Err(e)));
-                    }
+        // Keep the status bar file current, rewriting it only on change
+        if let Some(ref path) = status_path {
+            if last_status_check.is_none_or(|t| t.elapsed() >= STATUS_FILE_INTERVAL) {
+                last_status_check = Some(Instant::now());
+                let text = Status::gather(&db, &app.watchlist)
+                    .render(&app.status_file.format, &app.time_display);
+                if text != status_written && statusline::write(path, &text).is_ok() {
+                    status_written = text;
                 }
             }
+        }
 
-            app.last_fetch_results = fetch_results;
-            reload_articles(&db, &mut app);
-
-            if total_new > 0 {
-                app.set_status(format!("{} new articles fetched", total_new));
-            } else {
-                app.set_status("Feeds refreshed, no new articles".to_string());
-            }
+        // Requests from other programs on the control socket
+        while let Ok(request) = ipc_rx.try_recv() {
+            app.needs_redraw = true;
+            handle_ipc_request(&mut app, &db, &rt, &client, &feed_tx, &content_tx, request);
         }
 
         // Drain content messages
         while let Ok(msg) = content_rx.try_recv() {
-            // Persist content to DB
-            if let Some(article) = app.articles.iter().find(|a| a.url == msg.url) {
-                let _ = db.save_content(article.id, &msg.content);
+            app.needs_redraw = true;
+            apply_content_msg(&mut app, &db, msg);
+        }
+
+        // Hand new articles to the external sentiment backend, if any
+        if !app.pending_rescore.is_empty() {
+            let items = std::mem::take(&mut app.pending_rescore);
+            if let Some(ref backend) = app.sentiment_backend {
+                spawn_rescore(&rt, &client, backend, items, &score_tx);
+                app.rescores_in_flight += 1;
+            }
+        }
+        while let Ok(scores) = score_rx.try_recv() {
+            app.needs_redraw = true;
+            app.rescores_in_flight = app.rescores_in_flight.saturating_sub(1);
+            apply_scores(&mut app, &db, scores);
+        }
+
+        // Push new watchlist and alert articles to the webhooks
+        if !app.pending_webhooks.is_empty() {
+            let payloads = std::mem::take(&mut app.pending_webhooks);
+            spawn_webhooks(&rt, &client, &app.webhooks, payloads, &webhook_tx);
+            app.webhooks_in_flight += 1;
+        }
+        while let Ok(failures) = webhook_rx.try_recv() {
+            app.needs_redraw = true;
+            app.webhooks_in_flight = app.webhooks_in_flight.saturating_sub(1);
+            if failures.is_empty() {
+                continue;
             }
+            let more = match failures.len() {
+                1 => String::new(),
+                n => format!(" (+{} more)", n - 1),
+            };
+            app.set_status(format!("Webhook failed: {}{}", failures[0], more));
+        }
 
-            // Cache in memory
-            if let Some(article) = app.selected_article() {
-                if article.url == msg.url {
-                    app.cache_content(msg.url, msg.content);
-                } else {
-                    app.content_cache.insert(msg.url, msg.content);
+        // Offline pack requested from the feed
+        if std::mem::take(&mut app.offline_requested) {
+            start_offline_pack(&mut app, &db, &rt, &client, &offline_tx);
+        }
+        while let Ok(msg) = offline_rx.try_recv() {
+            app.needs_redraw = true;
+            apply_offline_msg(&mut app, &db, msg);
+        }
+
+        // Re-analyze stored articles a batch per pass so input stays live
+        if std::mem::take(&mut app.reanalysis_requested) {
+            start_reanalysis(&mut app, &db);
+        }
+        if app.reanalysis_progress.is_some() {
+            step_reanalysis(&mut app, &db);
+        }
+
+        // Follow a link from the reader
+        if let Some(url) = app.pending_link.take() {
+            spawn_link_fetch(&rt, &client, url, &link_tx);
+        }
+        while let Ok(msg) = link_rx.try_recv() {
+            app.needs_redraw = true;
+            apply_link_msg(&mut app, msg);
+        }
+
+        // Fetch a URL entered at the add-article prompt
+        if let Some(url) = app.pending_add.take() {
+            spawn_add_article(&rt, &client, url, &add_tx);
+            app.adds_in_flight += 1;
+        }
+        while let Ok(msg) = add_rx.try_recv() {
+            app.needs_redraw = true;
+            app.adds_in_flight = app.adds_in_flight.saturating_sub(1);
+            apply_add_article_msg(&mut app, &db, msg);
+        }
+
+        // Send the chosen article to the read-later service
+        if let Some((id, url, title)) = app.pending_send.take() {
+            if let Some(ref service) = app.read_later {
+                spawn_send(&rt, &client, service, id, url, title, &send_tx);
+                app.sends_in_flight += 1;
+            }
+        }
+        while let Ok((id, result)) = send_rx.try_recv() {
+            app.needs_redraw = true;
+            app.sends_in_flight = app.sends_in_flight.saturating_sub(1);
+            apply_send(&mut app, &db, id, result);
+        }
+
+        // Summarize the article the reader asked for
+        if let Some((id, title, content)) = app.pending_summary.take() {
+            if let Some(ref llm) = app.llm {
+                spawn_summary(&rt, &client, llm, id, title, content, &summary_tx);
+            }
+        }
+        while let Ok((id, result)) = summary_rx.try_recv() {
+            app.needs_redraw = true;
+            apply_summary(&mut app, &db, id, result);
+        }
+
+        // Translate the article the reader asked for
+        if let Some((id, text, target)) = app.pending_translation.take() {
+            if let Some(ref translator) = app.translator {
+                spawn_translation(&rt, &client, translator, id, text, target, &translation_tx);
+            }
+        }
+        while let Ok((id, lang, result)) = translation_rx.try_recv() {
+            app.needs_redraw = true;
+            apply_translation(&mut app, &db, id, lang, result);
+        }
+
+        // Forget playback that ran to the end
+        if let Some(ref mut child) = app.speech {
+            if !matches!(child.try_wait(), Ok(None)) {
+                app.speech = None;
+            }
+        }
+
+        // Community mention counts for the watchlist overview
+        if app.community_due() {
+            app.community_fetched = Some(Instant::now());
+            community::spawn_community_fetch(
+                &rt,
+                &client,
+                &app.community_config,
+                app.watchlist.clone(),
+                &community_tx,
+            );
+        }
+        while let Ok(stats) = community_rx.try_recv() {
+            app.needs_redraw = true;
+            app.community.extend(stats);
+        }
+
+        // Drain quotes and tell the quote task about ticker filter changes
+        while let Ok(batch) = quote_rx.try_recv() {
+            app.needs_redraw = true;
+            for quote in batch {
+                let is_indicator = app
+                    .quotes_config
+                    .indicators
+                    .iter()
+                    .any(|i| i.label == quote.ticker);
+                if is_indicator {
+                    app.market_quotes.insert(quote.ticker.clone(), quote);
+                    continue;
+                }
+                app.quotes.insert(quote.ticker.clone(), quote);
+                if app.moves_only {
+                    app.display_dirty = true;
                 }
-            } else {
-                app.content_cache.insert(msg.url, msg.content);
             }
         }
+        let wanted = app.quote_tickers();
+        quote_tickers_tx.send_if_modified(|current| {
+            if *current == wanted {
+                false
+            } else {
+                *current = wanted;
+                true
+            }
+        });
 
         if app.should_quit {
-            crate::state::save_state(&app.to_view_state());
+            // Give in-flight fetches and sends a moment to land, so their
+            // results are stored rather than dropped
+            let busy = app.unfinished_work();
+            let deadline = *app
+                .quit_deadline
+                .get_or_insert_with(|| Instant::now() + QUIT_GRACE);
+            if !busy.is_empty() && !app.force_quit && Instant::now() < deadline {
+                app.set_status(format!(
+                    "Finishing {} before quitting, [q] to quit now",
+                    busy.join(", ")
+                ));
+                app.tick_count = app.tick_count.wrapping_add(1);
+                continue;
+            }
+            // Flush anything a background task delivered since the last drain
+            while let Ok(msg) = feed_rx.try_recv() {
+                apply_feed_msg(&mut app, &db, msg);
+            }
+            while let Ok(msg) = content_rx.try_recv() {
+                apply_content_msg(&mut app, &db, msg);
+            }
+            while let Ok(msg) = add_rx.try_recv() {
+                apply_add_article_msg(&mut app, &db, msg);
+            }
+            // The flush can queue webhooks for the articles it stored; send
+            // them and wait for every delivery before the runtime goes away
+            if !app.pending_webhooks.is_empty() {
+                let payloads = std::mem::take(&mut app.pending_webhooks);
+                spawn_webhooks(&rt, &client, &app.webhooks, payloads, &webhook_tx);
+                app.webhooks_in_flight += 1;
+            }
+            let until = deadline.max(Instant::now() + FINAL_SEND_GRACE);
+            while app.webhooks_in_flight > 0 && !app.force_quit {
+                let left = until.saturating_duration_since(Instant::now());
+                match rt.block_on(tokio::time::timeout(left, webhook_rx.recv())) {
+                    Ok(Some(_)) => app.webhooks_in_flight -= 1,
+                    _ => break,
+                }
+            }
+            if let Some(child) = app.speech.take() {
+                hook::stop_speech(child);
+            }
+            save_session(&app, &db);
             return Ok(());
         }
 
         // Auto-refresh (using rate-limited eligible sources)
         if let Some(last) = app.last_refresh {
-            if last.elapsed() >= app.refresh_interval && !app.is_fetching {
-                let eligible = app.eligible_sources();
+            if last.elapsed() >= app.refresh_interval
+                && !app.is_fetching
+                && app.auto_refresh_active()
+            {
+                // Sources with their own interval are fetched below
+                let eligible: Vec<FeedSource> = app
+                    .eligible_sources()
+                    .into_iter()
+                    .filter(|s| s.refresh_interval.is_none())
+                    .collect();
                 if !eligible.is_empty() {
                     spawn_fetch(&rt, &client, &eligible, &feed_tx);
                     app.is_fetching = true;
@@ -154,270 +524,2798 @@ pub fn run_loop(
                 app.last_refresh = Some(Instant::now());
             }
         }
+        if !app.is_fetching && app.auto_refresh_active() {
+            let due = app.scheduled_sources();
+            if !due.is_empty() {
+                spawn_fetch(&rt, &client, &due, &feed_tx);
+                app.is_fetching = true;
+            }
+        }
+
+        app.evict_expired_content();
 
         app.tick_count = app.tick_count.wrapping_add(1);
     }
 }
 
-fn spawn_fetch(
-    rt: &tokio::runtime::Runtime,
-    client: &reqwest::Client,
-    sources: &[FeedSource],
-    tx: &mpsc::Sender<FeedMsg>,
-) {
-    let client = client.clone();
-    let sources: Vec<FeedSource> = sources.to_vec();
-    let tx = tx.clone();
-    rt.spawn(async move {
-        let results = feed::fetch_all_feeds(&client, &sources).await;
-        let _ = tx.send(FeedMsg { results }).await;
-    });
+/// Job control signals sent to the process from outside
+enum JobSignal {
+    /// SIGTSTP, e.g. `kill -TSTP`; handled like Ctrl+Z
+    Stop,
+    /// SIGCONT; the terminal may have been reset while we were stopped
+    Continue,
 }
 
-fn spawn_content_fetch(
-    rt: &tokio::runtime::Runtime,
-    client: &reqwest::Client,
-    url: &str,
-    tx: &mpsc::Sender<ContentMsg>,
-) {
-    let client = client.clone();
-    let url = url.to_string();
-    let tx = tx.clone();
+#[cfg(unix)]
+fn spawn_job_signals(rt: &tokio::runtime::Runtime, tx: mpsc::Sender<JobSignal>) {
+    use tokio::signal::unix::{signal, SignalKind};
+
+    let _guard = rt.enter();
+    let (Ok(mut tstp), Ok(mut cont)) = (
+        signal(SignalKind::from_raw(libc::SIGTSTP)),
+        signal(SignalKind::from_raw(libc::SIGCONT)),
+    ) else {
+        return;
+    };
     rt.spawn(async move {
-        let content = match feed::fetch_article_content(&client, &url).await {
-            Ok(text) => text,
-            Err(e) => format!("Failed to load article: {}\n\nPress [o] to open in browser.", e),
-        };
-        let _ = tx.send(ContentMsg { url, content }).await;
+        loop {
+            let signal = tokio::select! {
+                Some(()) = tstp.recv() => JobSignal::Stop,
+                Some(()) = cont.recv() => JobSignal::Continue,
+                else => break,
+            };
+            if tx.send(signal).await.is_err() {
+                break;
+            }
+        }
     });
 }
 
-fn reload_articles(db: &Db, app: &mut App) {
-    match app.filter_mode {
-        FilterMode::All => {
-            if let Ok(articles) = db.get_articles(100) {
-                app.articles = articles;
-            }
-        }
-        FilterMode::Watchlist => {
-            if let Ok(articles) = db.get_articles_by_tickers(&app.watchlist, 100) {
-                app.articles = articles;
-            }
+#[cfg(not(unix))]
+fn spawn_job_signals(_rt: &tokio::runtime::Runtime, _tx: mpsc::Sender<JobSignal>) {}
+
+/// Restore the terminal, stop the process, and set the terminal back up
+/// once the shell resumes us with SIGCONT.
+#[cfg(unix)]
+fn suspend(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    // Blocks until SIGCONT. SIGTSTP is caught by spawn_job_signals, so
+    // stop with SIGSTOP, which the shell reports the same way.
+    unsafe {
+        libc::raise(libc::SIGSTOP);
+    }
+
+    restore_terminal(terminal)
+}
+
+#[cfg(not(unix))]
+fn suspend(_terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    Ok(())
+}
+
+/// Take the terminal back after being stopped and force a full redraw
+fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.hide_cursor()?;
+    // The screen contents are gone
+    terminal.clear()
+}
+
+/// Hand the terminal to the reader's pipe command, wait for it and an
+/// Enter keypress, then take the screen back. Returns a status line.
+fn pipe_to_command(
+    terminal: &mut Terminal<CrosstermBackend<io::Stdout>>,
+    cmd: &str,
+    text: &str,
+) -> io::Result<String> {
+    disable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    terminal.show_cursor()?;
+
+    let status = match hook::pipe_text(cmd, text) {
+        Ok(status) if status.success() => format!("Piped to {}", cmd),
+        Ok(status) => format!("{} exited with {}", cmd, status),
+        Err(e) => format!("Failed to run {}: {}", cmd, e),
+    };
+    println!("\n[{}] Press Enter to return", status);
+    let mut line = String::new();
+    let _ = io::stdin().read_line(&mut line);
+
+    enable_raw_mode()?;
+    execute!(
+        terminal.backend_mut(),
+        EnterAlternateScreen,
+        EnableMouseCapture
+    )?;
+    terminal.hide_cursor()?;
+    terminal.clear()?;
+    Ok(status)
+}
+
+fn apply_feed_msg(app: &mut App, db: &Db, msg: FeedMsg) {
+    app.is_fetching = false;
+    let mut total_new = 0;
+    let mut portfolio_new = 0;
+    let mut fetch_results = Vec::new();
+    let mut script_error = None;
+
+    for (source_name, result) in msg.results {
+        // Update per-source rate limit state
+        let state = app
+            .source_fetch_state
+            .entry(source_name.clone())
+            .or_insert_with(SourceFetchState::new);
+        match &result {
+            Ok(_) => state.record_success(),
+            Err(_) => state.record_failure(),
         }
-        FilterMode::Unread => {
-            if let Ok(articles) = db.get_unread_articles(100) {
-                app.articles = articles;
+
+        match result {
+            Ok(articles) => {
+                let mut inserted = 0;
+                for article in articles {
+                    let Some(holding) = insert_article(app, db, article, &mut script_error) else {
+                        continue;
+                    };
+                    inserted += 1;
+                    if holding {
+                        portfolio_new += 1;
+                    }
+                }
+                total_new += inserted;
+                fetch_results.push((source_name, Ok(inserted)));
             }
-        }
-        FilterMode::Source => {
-            if let Ok(articles) = db.get_articles(100) {
-                app.articles = articles;
+            Err(e) => {
+                fetch_results.push((source_name, Err(e)));
             }
         }
     }
 
-    app.total_articles = db.article_count().unwrap_or(0);
-    app.unread_count = db.unread_count().unwrap_or(0);
-    app.display_dirty = true;
+    let failed = fetch_results.iter().filter(|(_, r)| r.is_err()).count();
+    app.last_fetch_results = fetch_results;
+    app.last_refresh_took = Some(msg.elapsed);
+    let now = chrono::Utc::now().timestamp();
+    let _ = db.set_meta(LAST_REFRESH_KEY, &now.to_string());
+    reload_articles(db, app);
+    load_sentiment_meters(db, app);
+    match app.view_mode {
+        ViewMode::Watchlist => load_watchlist_overview(db, app),
+        ViewMode::Earnings => load_earnings(db, app),
+        ViewMode::Ipo => load_ipo_listings(db, app),
+        ViewMode::Ratings => load_ratings(db, app),
+        ViewMode::Timeline => load_timeline(db, app),
+        _ => {}
+    }
+
+    if let Some(e) = script_error {
+        app.set_status(format!("Script error: {}", e));
+    } else if portfolio_new > 0 {
+        app.set_status(format!(
+            "{} new articles fetched, {} about your holdings",
+            total_new, portfolio_new
+        ));
+    } else if total_new > 0 {
+        app.set_status(format!("{} new articles fetched", total_new));
+    } else if failed > 0 {
+        app.set_status(format!("No new articles, {} source(s) failed", failed));
+    } else {
+        app.set_status("Feeds refreshed, no new articles".to_string());
+    }
+
+    load_meetings(db, app);
+    remind_meetings(app);
 }
 
-fn handle_key(
+/// Run the insert script over a fetched article and store it, queueing its
+/// alert, webhooks, and rescore. Returns whether it is about a holding, or
+/// None when the script dropped it or it was already stored.
+fn insert_article(
     app: &mut App,
-    key: event::KeyEvent,
-    rt: &tokio::runtime::Runtime,
-    client: &reqwest::Client,
-    feed_tx: &mpsc::Sender<FeedMsg>,
-    content_tx: &mpsc::Sender<ContentMsg>,
     db: &Db,
-) {
-    // Global: Ctrl+C always quits
-    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
-        app.should_quit = true;
-        return;
+    mut article: Article,
+    script_error: &mut Option<String>,
+) -> Option<bool> {
+    if let Some(ref script) = app.script {
+        match script.on_insert(&mut article) {
+            Ok(true) => {}
+            Ok(false) => return None,
+            Err(e) => *script_error = Some(e),
+        }
+    }
+    let notify = ingest::Notify {
+        watchlist: &app.watchlist,
+        holdings: &app.holdings,
+        on_new_article: app.on_new_article.as_deref(),
+        on_holding_article: app.on_holding_article.as_deref(),
+        webhooks: !app.webhooks.is_empty(),
+    };
+    let stored = ingest::store(db, &article, &notify)?;
+    if let (true, Some(id)) = (app.sentiment_backend.is_some(), stored.id) {
+        app.pending_rescore.push((id, article.title.to_string()));
     }
+    app.pending_webhooks.extend(stored.payload);
+    // Alerts stay on screen until dismissed
+    if stored.alert.is_some() {
+        app.trading_alerts.push(article);
+    }
+    Some(stored.holding)
+}
 
-    // Help overlay
-    if app.show_help {
-        if key.code == KeyCode::Char('?') || key.code == KeyCode::Esc {
-            app.show_help = false;
+/// Store an article added by URL along with its body. Unlike a refresh it
+/// leaves source fetch state alone and only reports on the status line.
+fn apply_add_article_msg(app: &mut App, db: &Db, msg: AddArticleMsg) {
+    let (article, content) = match msg.result {
+        Ok(fetched) => fetched,
+        Err(e) => {
+            app.set_status(format!("Could not add {}: {}", msg.url, e));
+            return;
+        }
+    };
+    let title = article.title.to_string();
+    let mut script_error = None;
+    let added = insert_article(app, db, article, &mut script_error).is_some();
+    reload_articles(db, app);
+    load_sentiment_meters(db, app);
+    apply_content_msg(
+        app,
+        db,
+        ContentMsg {
+            url: msg.url,
+            content,
+            links: Vec::new(),
+            failed: false,
+        },
+    );
+    if let Some(e) = script_error {
+        app.set_status(format!("Script error: {}", e));
+    } else if added {
+        app.set_status(format!("Added: {}", title));
+    } else {
+        app.set_status(format!("Already stored or skipped: {}", title));
+    }
+}
+
+fn apply_content_msg(app: &mut App, db: &Db, msg: ContentMsg) {
+    // The fetch finished, so there is nothing left to cancel
+    if app
+        .content_task
+        .as_ref()
+        .is_some_and(|(url, _)| *url == msg.url)
+    {
+        app.content_task = None;
+    }
+    let on_screen = app.view_mode == ViewMode::Reader
+        && app.selected_article().is_some_and(|a| *a.url == msg.url);
+
+    // Failures are remembered so the article isn't refetched on every open,
+    // but never stored as its content
+    if msg.failed {
+        app.failed_content_urls.insert(msg.url, Instant::now());
+        if on_screen {
+            app.reader_content = Some(msg.content);
+            app.content_loading = false;
         }
         return;
     }
 
-    match app.input_mode {
-        InputMode::Normal => handle_normal_key(app, key, rt, client, feed_tx, content_tx, db),
-        InputMode::Search => handle_search_key(app, key, db),
-        InputMode::SourceAdd(_) | InputMode::SourceEdit(_) | InputMode::SourceDelete => {
-            handle_source_input_key(app, key);
+    // Persist content to DB
+    if let Some(article) = app.articles.iter_mut().find(|a| *a.url == msg.url) {
+        let _ = db.save_content(article.id, &msg.content);
+        if !msg.links.is_empty() {
+            let _ = db.save_links(article.id, &msg.links);
         }
+        if let Ok(keywords) = db.get_keywords(article.id) {
+            article.keywords = keywords;
+        }
+        article.word_count = Some(word_count(&msg.content));
+        if let Some(language) = Language::detect(&msg.content) {
+            article.language = Some(language);
+        }
+    }
+    if on_screen {
+        app.reader_links = msg.links;
+    }
+
+    // Cache in memory
+    if let Some(article) = app.selected_article() {
+        if *article.url == msg.url {
+            app.cache_content(msg.url, msg.content);
+            if on_screen {
+                clamp_reader_scroll(app);
+            }
+        } else {
+            app.content_cache.insert(msg.url, msg.content);
+        }
+    } else {
+        app.content_cache.insert(msg.url, msg.content);
     }
 }
 
-fn handle_normal_key(
+/// Fetch every source that isn't rate-limited, as the refresh key does
+fn refresh_now(
     app: &mut App,
-    key: event::KeyEvent,
     rt: &tokio::runtime::Runtime,
     client: &reqwest::Client,
     feed_tx: &mpsc::Sender<FeedMsg>,
-    content_tx: &mpsc::Sender<ContentMsg>,
-    db: &Db,
-) {
-    match app.view_mode {
-        ViewMode::Feed | ViewMode::Bookmarks => {
-            handle_feed_key(app, key, rt, client, feed_tx, content_tx, db)
-        }
-        ViewMode::Reader => handle_reader_key(app, key, rt, client, content_tx, db),
-        ViewMode::Sources => handle_sources_key(app, key),
+) -> Result<(), String> {
+    if app.is_fetching {
+        return Err("A refresh is already running".to_string());
     }
+    let eligible = app.eligible_sources();
+    if eligible.is_empty() {
+        return Err("All sources are rate-limited, try again later".to_string());
+    }
+    spawn_fetch(rt, client, &eligible, feed_tx);
+    app.is_fetching = true;
+    app.last_refresh = Some(Instant::now());
+    Ok(())
 }
 
-fn handle_feed_key(
+/// Carry out a control socket call and send back its reply. Methods:
+/// `list_articles {limit}`, `refresh`, `set_ticker_filter {ticker}` (null
+/// clears it), and `open_article {id}`.
+fn handle_ipc_request(
     app: &mut App,
-    key: event::KeyEvent,
+    db: &Db,
     rt: &tokio::runtime::Runtime,
     client: &reqwest::Client,
     feed_tx: &mpsc::Sender<FeedMsg>,
     content_tx: &mpsc::Sender<ContentMsg>,
-    db: &Db,
+    request: ipc::Request,
 ) {
-    match key.code {
-        KeyCode::Char('q') => app.should_quit = true,
-        KeyCode::Char('?') => app.show_help = !app.show_help,
-
-        // Navigation
-        KeyCode::Char('j') | KeyCode::Down => app.select_next(),
-        KeyCode::Char('k') | KeyCode::Up => app.select_prev(),
-        KeyCode::Char('g') => app.select_first(),
-        KeyCode::Char('G') => app.select_last(),
-
-        // Open reader with content fetch
-        KeyCode::Enter => {
-            let article_data = app.selected_article().map(|a| (a.id, a.url.clone()));
-            if let Some((article_id, url)) = article_data {
-                let _ = db.mark_read(article_id);
-                app.enter_reader();
-                // Check DB for content, then network fetch
-                if app.reader_content.is_none() {
-                    if let Ok(Some(content)) = db.get_content(article_id) {
-                        app.cache_content(url, content);
-                    } else if !app.failed_content_urls.contains(&url) {
-                        spawn_content_fetch(rt, client, &url, content_tx);
-                    } else {
-                        app.content_loading = false;
-                    }
-                }
-                reload_articles(db, app);
+    let params = &request.params;
+    let reply = match request.method.as_str() {
+        "list_articles" => {
+            let limit = params.get("limit").and_then(|v| v.as_u64()).unwrap_or(50);
+            if app.display_dirty {
+                app.recompute_display();
             }
+            let articles: Vec<&Article> = app
+                .cached_display
+                .iter()
+                .take(limit as usize)
+                .map(|row| &app.articles[row.article_idx])
+                .collect();
+            Ok(serde_json::json!({ "articles": articles }))
         }
-
-        // Open in browser
-        KeyCode::Char('o') => {
-            let article_data = app.selected_article().map(|a| (a.id, a.url.clone()));
-            if let Some((id, url)) = article_data {
-                let _ = db.mark_read(id);
-                let _ = open::that(&url);
-                app.set_status("Opened in browser".to_string());
-                reload_articles(db, app);
+        "refresh" => refresh_now(app, rt, client, feed_tx)
+            .map(|()| {
+                app.set_status("Refreshing feeds...".to_string());
+                serde_json::json!({ "started": true })
+            })
+            .map_err(|e| (ipc::FAILED, e)),
+        "set_ticker_filter" => match params.get("ticker") {
+            None | Some(serde_json::Value::Null) => {
+                app.set_ticker_filter(None);
+                app.set_status("Ticker filter cleared".to_string());
+                Ok(serde_json::json!({ "ticker": null }))
             }
-        }
-
-        // Bookmark
-        KeyCode::Char('b') => {
-            let article_id = app.selected_article().map(|a| a.id);
-            if let Some(id) = article_id {
-                if let Ok(bookmarked) = db.toggle_bookmark(id) {
-                    let msg = if bookmarked {
-                        "Bookmarked"
-                    } else {
-                        "Unbookmarked"
+            Some(serde_json::Value::String(ticker)) => {
+                let ticker = ticker.trim().to_uppercase();
+                app.set_ticker_filter(Some(ticker.clone()));
+                app.set_status(format!("Ticker filter: {}", ticker));
+                Ok(serde_json::json!({ "ticker": ticker }))
+            }
+            Some(_) => Err((
+                ipc::INVALID_PARAMS,
+                "ticker must be a string or null".to_string(),
+            )),
+        },
+        "open_article" => match params.get("id").and_then(|v| v.as_i64()) {
+            Some(id) => match db.get_article(id) {
+                Ok(Some(article)) => {
+                    let result = serde_json::json!({ "id": id, "title": &*article.title });
+                    app.reader_override = Some(article);
+                    open_reader_with_content(app, rt, client, content_tx, db);
+                    Ok(result)
+                }
+                _ => Err((ipc::FAILED, format!("No article with id {}", id))),
+            },
+            None => Err((ipc::INVALID_PARAMS, "id must be an article id".to_string())),
+        },
+        method => Err((
+            ipc::METHOD_NOT_FOUND,
+            format!("Unknown method '{}'", method),
+        )),
+    };
+    let _ = request.reply.send(reply);
+}
+
+fn spawn_fetch(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    sources: &[FeedSource],
+    tx: &mpsc::Sender<FeedMsg>,
+) {
+    let client = client.clone();
+    let sources: Vec<FeedSource> = sources.to_vec();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let started = Instant::now();
+        let results = feed::fetch_all_feeds(&client, &sources).await;
+        let elapsed = started.elapsed();
+        let _ = tx.send(FeedMsg { results, elapsed }).await;
+    });
+}
+
+fn spawn_rescore(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    backend: &sentiment::Backend,
+    items: Vec<(i64, String)>,
+    tx: &mpsc::Sender<Vec<(i64, f64)>>,
+) {
+    let client = client.clone();
+    let backend = backend.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        // Sent even when empty, so the loop knows the batch is done
+        let scores = sentiment::score_batch(&backend, &client, items).await;
+        let _ = tx.send(scores).await;
+    });
+}
+
+fn spawn_webhooks(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    hooks: &[webhook::Webhook],
+    payloads: Vec<Vec<u8>>,
+    tx: &mpsc::Sender<Vec<String>>,
+) {
+    let client = client.clone();
+    let hooks = hooks.to_vec();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        // Sent even when empty, so the loop knows the deliveries are done
+        let failures = webhook::deliver_all(&client, &hooks, payloads).await;
+        let _ = tx.send(failures).await;
+    });
+}
+
+/// Store backend scores and update any loaded copies of the articles
+fn apply_scores(app: &mut App, db: &Db, scores: Vec<(i64, f64)>) {
+    for (id, score) in scores {
+        let _ = db.update_sentiment(id, score);
+        if let Some(article) = app.articles.iter_mut().find(|a| a.id == id) {
+            article.sentiment_score = score;
+            article.sentiment = sentiment_from_score(score);
+        }
+    }
+    load_sentiment_meters(db, app);
+}
+
+/// Fetch content and lead images for bookmarked articles that lack them,
+/// one at a time, reporting each back over `tx`
+fn start_offline_pack(
+    app: &mut App,
+    db: &Db,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    tx: &mpsc::Sender<OfflineMsg>,
+) {
+    let items: Vec<(i64, String, Option<FeedSource>)> = db
+        .get_offline_candidates()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(id, url, source)| {
+            let source = app.sources.iter().find(|s| s.name == source).cloned();
+            (id, url, source)
+        })
+        .filter(|(_, _, source)| !source.as_ref().is_some_and(|s| s.mute_content))
+        .collect();
+    if items.is_empty() {
+        app.set_status("Bookmarks are already available offline".to_string());
+        return;
+    }
+    app.offline_progress = Some((0, items.len()));
+    app.offline_failed = 0;
+
+    let image_dir = crate::config::data_dir().join("images");
+    let client = client.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        for (id, url, source) in items {
+            let result = match feed::fetch_article_page(&client, &url, source.as_ref()).await {
+                Ok(page) => {
+                    let image = match page.image {
+                        Some(ref src) => {
+                            feed::download_image(&client, src, &image_dir, &id.to_string())
+                                .await
+                                .ok()
+                        }
+                        None => None,
                     };
-                    app.set_status(msg.to_string());
-                    reload_articles(db, app);
+                    Ok((page, image))
+                }
+                Err(e) => Err(e),
+            };
+            if tx.send(OfflineMsg { id, result }).await.is_err() {
+                break;
+            }
+        }
+    });
+}
+
+fn start_reanalysis(app: &mut App, db: &Db) {
+    let total = db.article_count().unwrap_or(0) as usize;
+    app.reanalysis_progress = Some((0, total));
+    app.reanalysis_after = 0;
+    app.reanalysis_changed = 0;
+}
+
+fn step_reanalysis(app: &mut App, db: &Db) {
+    let Some((done, total)) = app.reanalysis_progress else {
+        return;
+    };
+    // Scores from an external backend are better than the lexicon's
+    let rescore = app.sentiment_backend.is_none();
+    let batch = db.reanalyze_batch(app.reanalysis_after, REANALYSIS_BATCH, rescore);
+    let (changed, last) = match batch {
+        Ok(result) => result,
+        Err(e) => {
+            app.reanalysis_progress = None;
+            app.set_status(format!("Re-analysis failed: {}", e));
+            return;
+        }
+    };
+    app.reanalysis_changed += changed;
+    if let Some(id) = last {
+        app.reanalysis_after = id;
+        let done = (done + REANALYSIS_BATCH).min(total);
+        app.reanalysis_progress = Some((done, total));
+        return;
+    }
+
+    app.reanalysis_progress = None;
+    let _ = db.set_meta(ANALYSIS_KEY, &app.analysis_fingerprint);
+    if app.reanalysis_changed > 0 {
+        reload_articles(db, app);
+        load_sentiment_meters(db, app);
+    }
+    app.set_status(format!(
+        "Re-analysis done: {} of {} articles updated",
+        app.reanalysis_changed, total
+    ));
+}
+
+fn apply_offline_msg(app: &mut App, db: &Db, msg: OfflineMsg) {
+    let Some((done, total)) = app.offline_progress else {
+        return;
+    };
+    match msg.result {
+        Ok((page, image)) => {
+            let _ = db.save_content(msg.id, &page.content);
+            let _ = db.save_links(msg.id, &page.links);
+            if let Some(path) = image {
+                let _ = db.save_lead_image(msg.id, &path.to_string_lossy());
+            }
+        }
+        Err(_) => app.offline_failed += 1,
+    }
+
+    let done = done + 1;
+    if done < total {
+        app.offline_progress = Some((done, total));
+        return;
+    }
+    app.offline_progress = None;
+    if app.offline_failed > 0 {
+        app.set_status(format!(
+            "Offline pack ready: {} articles, {} failed",
+            total - app.offline_failed,
+            app.offline_failed
+        ));
+    } else {
+        app.set_status(format!("Offline pack ready: {} articles", total));
+    }
+}
+
+fn spawn_send(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    service: &readlater::Service,
+    id: i64,
+    url: String,
+    title: String,
+    tx: &mpsc::Sender<(i64, Result<(), String>)>,
+) {
+    let client = client.clone();
+    let service = service.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let result = service.send(&client, &url, &title).await;
+        let _ = tx.send((id, result)).await;
+    });
+}
+
+fn apply_send(app: &mut App, db: &Db, id: i64, result: Result<(), String>) {
+    let Some(name) = app.read_later.as_ref().map(|s| s.name().to_string()) else {
+        return;
+    };
+    match result {
+        Ok(()) => {
+            let _ = db.mark_sent(id, &name);
+            app.sent_ids.insert(id);
+            app.set_status(format!("Sent to {}", name));
+        }
+        Err(e) => app.set_status(format!("Send to {} failed: {}", name, e)),
+    }
+}
+
+/// Queue the selected article for the read-later service
+fn request_send(app: &mut App) {
+    let Some(name) = app.read_later.as_ref().map(|s| s.name().to_string()) else {
+        app.set_status("No read-later service configured; set [read_later] in config".to_string());
+        return;
+    };
+    let Some(article) = app.selected_article() else {
+        return;
+    };
+    if app.sent_ids.contains(&article.id) {
+        app.set_status(format!("Already sent to {}", name));
+        return;
+    }
+    app.pending_send = Some((
+        article.id,
+        article.url.to_string(),
+        article.title.to_string(),
+    ));
+    app.set_status(format!("Sending to {}...", name));
+}
+
+fn spawn_summary(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    llm: &llm::Llm,
+    id: i64,
+    title: String,
+    content: String,
+    tx: &mpsc::Sender<(i64, Result<String, String>)>,
+) {
+    let client = client.clone();
+    let llm = llm.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let result = llm.summarize(&client, &title, &content).await;
+        let _ = tx.send((id, result)).await;
+    });
+}
+
+/// Cache a finished summary and show it if its article is still open
+fn apply_summary(app: &mut App, db: &Db, id: i64, result: Result<String, String>) {
+    let is_open =
+        app.view_mode == ViewMode::Reader && app.selected_article().map(|a| a.id) == Some(id);
+    match result {
+        Ok(summary) => {
+            let _ = db.save_summary(id, &summary);
+            if is_open {
+                app.reader_summary = Some(summary);
+                app.summary_loading = false;
+                app.reader_scroll = 0;
+            }
+        }
+        Err(e) => {
+            if is_open {
+                app.summary_loading = false;
+                app.set_status(format!("Summary failed: {}", e));
+            }
+        }
+    }
+}
+
+fn spawn_translation(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    translator: &translate::Translator,
+    id: i64,
+    text: String,
+    target: String,
+    tx: &mpsc::Sender<(i64, String, Result<String, String>)>,
+) {
+    let client = client.clone();
+    let translator = translator.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let result = translator.translate(&client, &text, &target).await;
+        let _ = tx.send((id, target, result)).await;
+    });
+}
+
+/// Cache a finished translation and switch to it if its article is open
+fn apply_translation(
+    app: &mut App,
+    db: &Db,
+    id: i64,
+    lang: String,
+    result: Result<String, String>,
+) {
+    let is_open =
+        app.view_mode == ViewMode::Reader && app.selected_article().map(|a| a.id) == Some(id);
+    match result {
+        Ok(text) => {
+            let _ = db.save_translation(id, &lang, &text);
+            if is_open {
+                app.reader_translation = Some((lang, text));
+                app.show_translation = true;
+                app.translation_loading = false;
+                app.reader_scroll = 0;
+            }
+        }
+        Err(e) => {
+            if is_open {
+                app.translation_loading = false;
+                app.set_status(format!("Translation failed: {}", e));
+            }
+        }
+    }
+}
+
+/// Fetch the reader article's body, replacing any fetch still running for
+/// an article the reader has since left
+fn spawn_content_fetch(
+    app: &mut App,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    url: &str,
+    source: Option<FeedSource>,
+    tx: &mpsc::Sender<ContentMsg>,
+) {
+    cancel_content_fetch(app);
+    let client = client.clone();
+    let task_url = url.to_string();
+    let url = task_url.clone();
+    let tx = tx.clone();
+    let task = rt.spawn(async move {
+        let msg = match feed::fetch_article_page(&client, &url, source.as_ref()).await {
+            Ok(page) => ContentMsg {
+                url,
+                content: page.content,
+                links: page.links,
+                failed: false,
+            },
+            Err(e) => ContentMsg {
+                url,
+                content: format!(
+                    "Failed to load article: {}\n\nPress [r] to retry or [o] to open in browser.",
+                    e
+                ),
+                links: Vec::new(),
+                failed: true,
+            },
+        };
+        let _ = tx.send(msg).await;
+    });
+    app.content_task = Some((task_url, task.abort_handle()));
+}
+
+/// Drop the body fetch for an article no longer on screen, so it can't
+/// land in the cache and database long after the reader moved on
+fn cancel_content_fetch(app: &mut App) {
+    if let Some((_, task)) = app.content_task.take() {
+        task.abort();
+    }
+}
+
+fn spawn_link_fetch(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    url: String,
+    tx: &mpsc::Sender<LinkMsg>,
+) {
+    let client = client.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let result = feed::fetch_article_page(&client, &url, None).await;
+        let _ = tx.send(LinkMsg { url, result }).await;
+    });
+}
+
+/// Fill in the linked page waiting on this fetch, if it's still stacked
+fn apply_link_msg(app: &mut App, msg: LinkMsg) {
+    let Some(page) = app
+        .linked_pages
+        .iter_mut()
+        .find(|p| p.url == msg.url && p.content.is_none())
+    else {
+        return;
+    };
+    match msg.result {
+        Ok(fetched) => {
+            if let Some(title) = fetched.title {
+                page.title = title;
+            }
+            page.content = Some(fetched.content);
+            page.links = fetched.links;
+        }
+        Err(e) => {
+            page.content = Some(format!(
+                "Failed to load page: {}\n\nPress [o] to open in browser.",
+                e
+            ));
+        }
+    }
+}
+
+/// Fetch a manually entered URL with its body
+fn spawn_add_article(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    url: String,
+    tx: &mpsc::Sender<AddArticleMsg>,
+) {
+    let client = client.clone();
+    let tx = tx.clone();
+    rt.spawn(async move {
+        let result = feed::fetch_single_article(&client, &url).await;
+        let _ = tx.send(AddArticleMsg { url, result }).await;
+    });
+}
+
+fn reload_articles(db: &Db, app: &mut App) {
+    let limit = app.article_limit;
+    match app.filter_mode {
+        FilterMode::All => {
+            if let Ok(articles) = db.get_articles(limit) {
+                app.articles = articles;
+            }
+        }
+        FilterMode::Watchlist => {
+            if let Ok(articles) = db.get_articles_by_tickers(&app.watchlist, limit) {
+                app.articles = articles;
+            }
+        }
+        FilterMode::Unread => {
+            if let Ok(articles) = db.get_unread_articles(limit) {
+                app.articles = articles;
+            }
+        }
+        FilterMode::Source | FilterMode::New => {
+            if let Ok(articles) = db.get_articles(limit) {
+                app.articles = articles;
+            }
+        }
+    }
+
+    app.run_display_hook();
+    app.showing_bookmarks = false;
+    app.total_articles = db.article_count().unwrap_or(0);
+    app.unread_count = db.unread_count().unwrap_or(0);
+    app.display_dirty = true;
+}
+
+fn load_bookmarks(db: &Db, app: &mut App) {
+    if let Ok(articles) = db.get_bookmarked_articles(app.article_limit) {
+        app.articles = articles;
+        app.run_display_hook();
+        app.showing_bookmarks = true;
+        app.display_dirty = true;
+    }
+}
+
+/// Mark an article read without re-querying the feed. Only the unread
+/// filter's rows change, and not while the article is open in the reader.
+fn mark_article_read(db: &Db, app: &mut App, id: i64) {
+    let _ = db.mark_read(id);
+    app.update_article(id, |a| a.read = true);
+    app.unread_count = db.unread_count().unwrap_or(app.unread_count);
+    if app.filter_mode == FilterMode::Unread && app.view_mode != ViewMode::Reader {
+        app.display_dirty = true;
+    }
+}
+
+/// Flip an article's bookmark in place; the bookmarks list drops it
+/// straight away when unbookmarked there
+fn toggle_article_bookmark(db: &Db, app: &mut App, id: i64) {
+    let Ok(bookmarked) = db.toggle_bookmark(id) else {
+        return;
+    };
+    app.update_article(id, |a| a.bookmarked = bookmarked);
+    if app.view_mode == ViewMode::Bookmarks && !bookmarked {
+        app.articles.retain(|a| a.id != id);
+        app.display_dirty = true;
+    }
+    let msg = if bookmarked {
+        "Bookmarked"
+    } else {
+        "Unbookmarked"
+    };
+    app.set_status(msg.to_string());
+}
+
+/// On the last row of a full window, widen it by a page of older articles
+fn load_more_at_end(db: &Db, app: &mut App) {
+    if app.page_size == 0
+        || app.view_mode != ViewMode::Feed
+        || app.selected_index + 1 < app.cached_display.len()
+        || app.articles.len() < app.article_limit
+    {
+        return;
+    }
+    app.article_limit += app.page_size;
+    let before = app.articles.len();
+    reload_articles(db, app);
+    app.recompute_display();
+    let loaded = app.articles.len().saturating_sub(before);
+    if loaded > 0 {
+        app.set_status(format!("Loaded {} older articles", loaded));
+    }
+}
+
+/// Aggregate stored sentiment per ticker for each configured window
+fn load_sentiment_meters(db: &Db, app: &mut App) {
+    let now = chrono::Utc::now().timestamp();
+    let mut meters: HashMap<String, Vec<SentimentMeter>> = HashMap::new();
+    for (i, (label, secs)) in app.sentiment_windows.iter().enumerate() {
+        let Ok(rows) = db.ticker_sentiment_since(now - secs) else {
+            return;
+        };
+        for (ticker, sum, count) in rows {
+            let entry = meters.entry(ticker).or_default();
+            // Fill windows with no coverage so indices line up
+            while entry.len() < i {
+                let window = app.sentiment_windows[entry.len()].0.clone();
+                entry.push(SentimentMeter {
+                    window,
+                    score: 0.0,
+                    count: 0,
+                });
+            }
+            entry.push(SentimentMeter {
+                window: label.clone(),
+                score: if count > 0 { sum / count as f64 } else { 0.0 },
+                count,
+            });
+        }
+    }
+    app.sentiment_meters = meters;
+}
+
+fn handle_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    feed_tx: &mpsc::Sender<FeedMsg>,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    // Global: Ctrl+C always quits
+    let ctrl_c = key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL);
+    // While quitting waits on background work, q or Ctrl+C skips the wait
+    if app.should_quit {
+        if ctrl_c || key.code == KeyCode::Char('q') {
+            app.force_quit = true;
+        }
+        return;
+    }
+    if ctrl_c {
+        app.should_quit = true;
+        return;
+    }
+
+    // Global: Ctrl+Z suspends to the shell
+    if key.code == KeyCode::Char('z') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.suspend_requested = true;
+        return;
+    }
+
+    // Key sequences only apply to view keys, not text entry
+    if app.input_mode == InputMode::Normal && !app.show_help && !app.keymap.is_empty() {
+        app.pending_keys.push(key);
+        match app.keymap.step(app.view_mode, &app.pending_keys) {
+            keymap::Step::Pending => {}
+            keymap::Step::Replay(keys) => {
+                app.pending_keys.clear();
+                for key in keys {
+                    dispatch_key(app, key, rt, client, feed_tx, content_tx, db);
                 }
             }
         }
+        return;
+    }
+
+    dispatch_key(app, key, rt, client, feed_tx, content_tx, db);
+}
+
+/// Handle the first keys of a sequence on their own once it times out
+fn flush_pending_keys(
+    app: &mut App,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    feed_tx: &mpsc::Sender<FeedMsg>,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    if app.pending_keys.is_empty() || app.last_input.elapsed() < keymap::SEQUENCE_TIMEOUT {
+        return;
+    }
+    app.needs_redraw = true;
+    for key in std::mem::take(&mut app.pending_keys) {
+        dispatch_key(app, key, rt, client, feed_tx, content_tx, db);
+    }
+}
+
+fn dispatch_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    feed_tx: &mpsc::Sender<FeedMsg>,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    // Help overlay
+    if app.show_help {
+        if key.code == KeyCode::Char('?') || key.code == KeyCode::Esc {
+            app.show_help = false;
+        }
+        return;
+    }
+
+    match app.input_mode {
+        InputMode::Normal => handle_normal_key(app, key, rt, client, feed_tx, content_tx, db),
+        InputMode::Search => handle_search_key(app, key, db),
+        InputMode::SearchHistory => handle_search_history_key(app, key, db),
+        InputMode::AddArticle => match key.code {
+            KeyCode::Enter => {
+                let url = app.input_buffer.trim().to_string();
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+                if !url.is_empty() {
+                    app.set_status(format!("Adding {}", url));
+                    app.pending_add = Some(url);
+                }
+            }
+            KeyCode::Esc => {
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+            }
+            KeyCode::Backspace => {
+                app.input_buffer.pop();
+            }
+            KeyCode::Char(c) => app.input_buffer.push(c),
+            _ => {}
+        },
+        InputMode::ReaderSearch => match key.code {
+            KeyCode::Enter => {
+                let query = app.input_buffer.trim().to_lowercase();
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+                app.reader_match = 0;
+                if query.is_empty() {
+                    app.reader_search = None;
+                } else {
+                    app.reader_search = Some(query);
+                    jump_to_match(app);
+                }
+            }
+            KeyCode::Esc => {
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+            }
+            KeyCode::Backspace => {
+                app.input_buffer.pop();
+            }
+            KeyCode::Char(c) => app.input_buffer.push(c),
+            _ => {}
+        },
+        InputMode::ImportWatchlist => match key.code {
+            KeyCode::Enter => {
+                let path = app.input_buffer.trim().to_string();
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+                if !path.is_empty() {
+                    import_watchlist(db, app, &path);
+                }
+            }
+            KeyCode::Esc => {
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+            }
+            KeyCode::Backspace => {
+                app.input_buffer.pop();
+            }
+            KeyCode::Char(c) => app.input_buffer.push(c),
+            _ => {}
+        },
+        InputMode::AddTicker => match key.code {
+            KeyCode::Enter => {
+                let input = app.input_buffer.clone();
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+                add_tickers(db, app, &input);
+            }
+            KeyCode::Esc => {
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+            }
+            KeyCode::Backspace => {
+                app.input_buffer.pop();
+            }
+            KeyCode::Char(c) => app.input_buffer.push(c),
+            _ => {}
+        },
+        InputMode::HighlightNote => match key.code {
+            KeyCode::Enter => {
+                let note = app.input_buffer.trim().to_string();
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+                save_highlight(app, db, (!note.is_empty()).then_some(note.as_str()));
+            }
+            KeyCode::Esc => {
+                app.input_mode = InputMode::Normal;
+                app.input_buffer.clear();
+            }
+            KeyCode::Backspace => {
+                app.input_buffer.pop();
+            }
+            KeyCode::Char(c) => app.input_buffer.push(c),
+            _ => {}
+        },
+        InputMode::SourceAdd(_) | InputMode::SourceEdit(_) | InputMode::SourceDelete => {
+            handle_source_input_key(app, key);
+        }
+        InputMode::ConfirmQuit => match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.should_quit = true,
+            _ => {
+                app.input_mode = InputMode::Normal;
+                app.set_status("Quit cancelled".to_string());
+            }
+        },
+    }
+}
+
+/// Merge tickers from a broker/Stockbit CSV into the watchlist and save it
+fn import_watchlist(db: &Db, app: &mut App, path: &str) {
+    let path = match (path.strip_prefix("~/"), dirs::home_dir()) {
+        (Some(rest), Some(home)) => home.join(rest),
+        _ => std::path::PathBuf::from(path),
+    };
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(e) => {
+            app.set_status(format!("Import failed: {}", e));
+            return;
+        }
+    };
+    let imported = watchlist::parse_csv(&text);
+    if imported.is_empty() {
+        app.set_status(format!("No tickers found in {}", path.display()));
+        return;
+    }
+    let added = watchlist::merge(&mut app.watchlist, &imported);
+    let note = save_watchlist(app);
+    load_watchlist_overview(db, app);
+    app.set_status(format!(
+        "Imported {} tickers ({} new){}",
+        imported.len(),
+        added,
+        note
+    ));
+}
+
+fn add_tickers(db: &Db, app: &mut App, input: &str) {
+    let tickers = watchlist::parse_codes(input);
+    if tickers.is_empty() {
+        if !input.trim().is_empty() {
+            app.set_status(format!("Not a ticker code: {}", input.trim()));
+        }
+        return;
+    }
+    if watchlist::merge(&mut app.watchlist, &tickers) == 0 {
+        app.set_status(format!("Already watching {}", tickers.join(", ")));
+        return;
+    }
+    let note = save_watchlist(app);
+    load_watchlist_overview(db, app);
+    app.set_status(format!("Added {} to watchlist{}", tickers.join(", "), note));
+}
+
+/// Add the ticker if it isn't watched yet, otherwise remove it
+fn toggle_watched(db: &Db, app: &mut App, ticker: &str) {
+    let status = if let Some(pos) = app.watchlist.iter().position(|t| t == ticker) {
+        app.watchlist.remove(pos);
+        format!("Removed {} from watchlist", ticker)
+    } else {
+        app.watchlist.push(ticker.to_string());
+        format!("Added {} to watchlist", ticker)
+    };
+    let note = save_watchlist(app);
+    if app.view_mode == ViewMode::Watchlist {
+        load_watchlist_overview(db, app);
+        app.selected_index = app
+            .selected_index
+            .min(app.watchlist_overview.len().saturating_sub(1));
+    }
+    app.set_status(format!("{}{}", status, note));
+}
+
+/// Write watchlist edits to the config. A watchlist set by --tickers,
+/// STOCKNEWSTUI_WATCHLIST, or a preset isn't the file's, so edits to it
+/// only last for the session; the returned note says so, as it does when
+/// the write fails.
+fn save_watchlist(app: &App) -> String {
+    if app.watchlist_overridden || app.preset_active() {
+        return " (this session only)".to_string();
+    }
+    match config::save_watchlist(&app.watchlist) {
+        Ok(()) => String::new(),
+        Err(e) => format!(" (this session only, not saved: {})", e),
+    }
+}
+
+/// Write source edits to the config, unless a preset or --demo supplied
+/// them. A failed write replaces the status message, since the edit won't
+/// last.
+fn save_sources(app: &mut App) {
+    if app.preset_active() || app.demo {
+        return;
+    }
+    if let Err(e) = config::save_sources(&app.sources) {
+        app.set_status(format!("Sources not saved: {}", e));
+    }
+}
+
+/// Quit, asking first if enabled and background work is still in flight
+fn request_quit(app: &mut App) {
+    if app.confirm_quit && !app.unfinished_work().is_empty() {
+        app.input_mode = InputMode::ConfirmQuit;
+    } else {
+        app.should_quit = true;
+    }
+}
+
+fn handle_normal_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    feed_tx: &mpsc::Sender<FeedMsg>,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    match app.view_mode {
+        ViewMode::Feed | ViewMode::Bookmarks => {
+            handle_feed_key(app, key, rt, client, feed_tx, content_tx, db)
+        }
+        ViewMode::Reader => handle_reader_key(app, key, rt, client, content_tx, db),
+        ViewMode::Sources => handle_sources_key(app, key),
+        ViewMode::Watchlist => handle_watchlist_key(app, key, db),
+        ViewMode::Earnings => handle_earnings_key(app, key, db),
+        ViewMode::Ipo => handle_ipo_key(app, key, db),
+        ViewMode::Ratings => handle_ratings_key(app, key, db),
+        ViewMode::Timeline => handle_timeline_key(app, key, db),
+        ViewMode::Meetings => handle_meetings_key(app, key, db),
+        ViewMode::Highlights => handle_highlights_key(app, key, rt, client, content_tx, db),
+        ViewMode::Alerts => handle_alerts_key(app, key, rt, client, content_tx, db),
+    }
+}
+
+fn handle_feed_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    feed_tx: &mpsc::Sender<FeedMsg>,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    // The recent-tickers picker takes the keys while open
+    if app.recent_cursor.is_some() {
+        handle_recent_tickers_key(app, key);
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('q') => request_quit(app),
+        KeyCode::Char('?') => app.show_help = !app.show_help,
+
+        // Navigation
+        KeyCode::Char('j') | KeyCode::Down => {
+            load_more_at_end(db, app);
+            app.select_next();
+        }
+        KeyCode::Char('k') | KeyCode::Up => app.select_prev(),
+        KeyCode::Char('g') => app.select_first(),
+        KeyCode::Char('G') => app.select_last(),
+
+        // Open reader with content fetch
+        KeyCode::Enter => open_reader_with_content(app, rt, client, content_tx, db),
+
+        // Open in browser
+        KeyCode::Char('o') => {
+            let article_data = app.selected_article().map(|a| (a.id, a.url.clone()));
+            if let Some((id, url)) = article_data {
+                mark_article_read(db, app, id);
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
+            }
+        }
+
+        // Bookmark
+        KeyCode::Char('b') => {
+            let article_id = app.selected_article().map(|a| a.id);
+            if let Some(id) = article_id {
+                toggle_article_bookmark(db, app, id);
+            }
+        }
+
+        // View bookmarks
+        // Send to the read-later service
+        KeyCode::Char('K') => request_send(app),
+
+        // Prefetch bookmarked articles for offline reading
+        KeyCode::Char('F') => {
+            if app.offline_progress.is_some() {
+                app.set_status("Offline pack already downloading".to_string());
+            } else {
+                app.offline_requested = true;
+            }
+        }
+
+        KeyCode::Char('B') => {
+            if app.view_mode == ViewMode::Bookmarks {
+                app.view_mode = ViewMode::Feed;
+                reload_articles(db, app);
+            } else {
+                app.view_mode = ViewMode::Bookmarks;
+                load_bookmarks(db, app);
+                app.selected_index = 0;
+            }
+        }
+
+        // Watchlist overview
+        KeyCode::Char('W') => {
+            load_watchlist_overview(db, app);
+            app.view_mode = ViewMode::Watchlist;
+            app.selected_index = 0;
+            if app.watchlist.is_empty() {
+                app.set_status("Watchlist is empty, press a to add tickers".to_string());
+            }
+        }
+
+        // Watch or unwatch the selected article's first ticker
+        KeyCode::Char('w') => {
+            let ticker = app
+                .selected_article()
+                .and_then(|a| a.tickers.first().cloned());
+            match ticker {
+                Some(ticker) => toggle_watched(db, app, &ticker),
+                None => app.set_status("No ticker on this article".to_string()),
+            }
+        }
+
+        // Earnings calendar
+        KeyCode::Char('R') => {
+            load_earnings(db, app);
+            app.view_mode = ViewMode::Earnings;
+            app.selected_index = 0;
+        }
+
+        // IPO pipeline
+        KeyCode::Char('I') => {
+            load_ipo_listings(db, app);
+            app.view_mode = ViewMode::Ipo;
+            app.selected_index = 0;
+        }
+
+        // Event timeline for the filtered ticker or the selected article's
+        KeyCode::Char('D') => {
+            let ticker = app.ticker_filter.clone().or_else(|| {
+                app.selected_article()
+                    .and_then(|a| a.tickers.first().cloned())
+            });
+            match ticker {
+                Some(ticker) => open_timeline(db, app, ticker),
+                None => app.set_status("No ticker to show a timeline for".to_string()),
+            }
+        }
+
+        // Dismiss the suspension/UMA banner
+        KeyCode::Char('!') => {
+            if !app.trading_alerts.is_empty() {
+                app.trading_alerts.clear();
+                let _ = db.acknowledge_all_alerts();
+                app.set_status("Alerts dismissed".to_string());
+            }
+        }
+
+        // Alert history
+        KeyCode::Char('a') => {
+            load_alerts(db, app);
+            app.view_mode = ViewMode::Alerts;
+            app.selected_index = 0;
+        }
+
+        // Shareholder meeting calendar
+        KeyCode::Char('V') => {
+            load_meetings(db, app);
+            app.view_mode = ViewMode::Meetings;
+            app.selected_index = 0;
+        }
+
+        // Saved reader highlights
+        KeyCode::Char('Y') => {
+            load_highlights(db, app);
+            app.view_mode = ViewMode::Highlights;
+            app.selected_index = 0;
+        }
+
+        // Broker rating changes
+        KeyCode::Char('U') => {
+            load_ratings(db, app);
+            app.view_mode = ViewMode::Ratings;
+            app.selected_index = 0;
+        }
+
+        // Sources view
+        KeyCode::Char('S') => {
+            app.view_mode = ViewMode::Sources;
+            app.selected_index = app
+                .sources_selected
+                .min(app.sources.len().saturating_sub(1));
+        }
+
+        // Filter
+        KeyCode::Char('f') => {
+            app.cycle_filter();
+            reload_articles(db, app);
+            app.set_status(format!("Filter: {}", app.filter_mode.label()));
+        }
+
+        // Quick ticker filter: pick first ticker from selected article
+        KeyCode::Char('T') => {
+            let ticker = app
+                .selected_article()
+                .and_then(|a| a.tickers.first().cloned());
+            if let Some(ticker) = ticker {
+                app.set_ticker_filter(Some(ticker.clone()));
+                app.set_status(format!("Ticker filter: {}", ticker));
+            } else {
+                app.set_status("No ticker detected in this article".to_string());
+            }
+        }
+
+        // Duplicate clusters: expand/collapse, dismiss, undo, lead source
+        KeyCode::Char('e') => match app.toggle_cluster_expanded() {
+            Some((id, pref)) => {
+                let _ = db.save_cluster_pref(id, &pref);
+                if pref.expanded {
+                    app.set_status("Duplicates expanded".to_string());
+                } else {
+                    app.set_status("Duplicates collapsed".to_string());
+                }
+            }
+            None => app.set_status("No duplicates for this article".to_string()),
+        },
+        KeyCode::Char('x') => match app.dismiss_cluster() {
+            Some((id, pref)) => {
+                let _ = db.save_cluster_pref(id, &pref);
+                app.set_status("Story dismissed (u to undo)".to_string());
+            }
+            None => app.set_status("No duplicates for this article".to_string()),
+        },
+        KeyCode::Char('u') => {
+            if let Some((id, pref)) = app.restore_dismissed_cluster() {
+                let _ = db.save_cluster_pref(id, &pref);
+                app.set_status("Story restored".to_string());
+            }
+        }
+        KeyCode::Char('m') => match app.cycle_cluster_source() {
+            Some((id, pref)) => {
+                let _ = db.save_cluster_pref(id, &pref);
+                let source = pref.preferred_source.unwrap_or_default();
+                app.set_status(format!("Showing the {} copy", source));
+            }
+            None => app.set_status("No duplicates for this article".to_string()),
+        },
+
+        // Pick from recently used ticker filters
+        KeyCode::Char('h') => {
+            if app.recent_tickers.is_empty() {
+                app.set_status("No recent tickers yet".to_string());
+            } else {
+                app.recent_cursor = Some(0);
+            }
+        }
+
+        // Toggle time / portfolio sort
+        KeyCode::Char('O') => {
+            app.cycle_sort();
+            app.set_status(format!("Sort: {}", app.sort_mode.label()));
+        }
+
+        // Cycle corporate action filter
+        KeyCode::Char('C') => {
+            app.cycle_topic_filter();
+            match app.topic_filter {
+                Some(ref topic) => app.set_status(format!("Topic filter: #{}", topic)),
+                None => app.set_status("Topic filter cleared".to_string()),
+            }
+        }
+
+        KeyCode::Char('X') => {
+            if app.quotes_config.move_threshold <= 0.0 || !app.quotes_config.enabled {
+                app.set_status("Price move annotation is disabled in config".to_string());
+            } else {
+                app.toggle_moves_filter();
+                if app.moves_only {
+                    app.set_status(format!(
+                        "Showing articles around moves of {}% or more",
+                        app.quotes_config.move_threshold
+                    ));
+                } else {
+                    app.set_status("Price move filter cleared".to_string());
+                }
+            }
+        }
+
+        KeyCode::Char('L') => {
+            app.toggle_regulatory_filter();
+            if app.regulatory_only {
+                app.set_status("Showing regulatory news only".to_string());
+            } else {
+                app.set_status("Regulatory filter cleared".to_string());
+            }
+        }
+
+        KeyCode::Char('M') => {
+            app.cycle_market_filter();
+            app.set_status(format!("Market news: {}", app.market_filter.label()));
+        }
+
+        KeyCode::Char('E') => {
+            app.cycle_event_filter();
+            match app.event_filter {
+                Some(kind) => app.set_status(format!("Event filter: {}", kind.label())),
+                None => app.set_status("Event filter cleared".to_string()),
+            }
+        }
+
+        KeyCode::Char('N') => {
+            app.cycle_language_filter();
+            match app.language_filter {
+                Some(lang) => app.set_status(format!("Language filter: {}", lang.label())),
+                None => app.set_status("Language filter cleared".to_string()),
+            }
+        }
+
+        // Drop cached bodies and forget failed fetches
+        KeyCode::Char('Z') => {
+            let cached = app.content_cache.len();
+            let failed = app.failed_content_urls.len();
+            app.content_cache.clear();
+            app.failed_content_urls.clear();
+            app.set_status(format!(
+                "Cleared {} cached article(s) and {} failed URL(s)",
+                cached, failed
+            ));
+        }
+
+        // Clear ticker filter
+        KeyCode::Char('c') => {
+            if app.ticker_filter.is_some() {
+                app.set_ticker_filter(None);
+                app.set_status("Ticker filter cleared".to_string());
+            }
+        }
+
+        // Refresh (rate-limited)
+        KeyCode::Char('r') => {
+            if !app.is_fetching {
+                match refresh_now(app, rt, client, feed_tx) {
+                    Ok(()) => app.set_status("Refreshing feeds...".to_string()),
+                    Err(e) => app.set_status(e),
+                }
+            }
+        }
+
+        // Switch preset, then refresh with its sources
+        KeyCode::Char('p') => match app.cycle_preset() {
+            Some(name) => {
+                reload_articles(db, app);
+                let eligible = app.eligible_sources();
+                if !app.is_fetching && !eligible.is_empty() {
+                    spawn_fetch(rt, client, &eligible, feed_tx);
+                    app.is_fetching = true;
+                    app.last_refresh = Some(Instant::now());
+                }
+                app.set_status(format!("Preset: {}", name));
+            }
+            None => app.set_status("No [preset.*] sections in config".to_string()),
+        },
+
+        // Pause/resume auto-refresh
+        KeyCode::Char('P') => {
+            app.toggle_refresh_pause();
+            if app.refresh_paused {
+                app.set_status("Auto-refresh paused".to_string());
+            } else {
+                app.set_status("Auto-refresh resumed".to_string());
+            }
+        }
+
+        // Add an article by URL
+        KeyCode::Char('A') => {
+            app.input_mode = InputMode::AddArticle;
+            app.input_buffer.clear();
+        }
+
+        // Search
+        KeyCode::Char('/') => {
+            app.input_mode = InputMode::Search;
+            app.input_buffer.clear();
+        }
+
+        // Toggle the ticker quotes panel
+        KeyCode::Char('Q') => {
+            if app.quotes_config.enabled {
+                app.show_quotes = !app.show_quotes;
+            } else {
+                app.set_status("Quotes are disabled in config".to_string());
+            }
+        }
+
+        // Toggle the market strip under the header
+        KeyCode::Char('H') => {
+            if app.quotes_config.enabled {
+                app.show_market_strip = !app.show_market_strip;
+            } else {
+                app.set_status("Quotes are disabled in config".to_string());
+            }
+        }
+
+        // Theme
+        KeyCode::Char('t') => {
+            app.cycle_theme();
+            app.set_status(format!("Theme: {}", app.theme_name.label()));
+        }
+
+        KeyCode::Char(c) if app.script.as_ref().is_some_and(|s| s.handles_keys()) => {
+            run_key_hook(db, app, c);
+        }
+
+        _ => {}
+    }
+}
+
+/// Hand a key the feed doesn't use to the script's on_key hook, with the
+/// selected article as `this`. A changed score is stored; tags and mutes
+/// last for the session.
+fn run_key_hook(db: &Db, app: &mut App, key: char) {
+    let (Some(script), Some(mut article)) = (app.script.clone(), app.selected_article().cloned())
+    else {
+        return;
+    };
+    let before = article.sentiment_score;
+    let (keep, message) = match script.on_key(key, &mut article) {
+        Ok(outcome) => outcome,
+        Err(e) => {
+            app.set_status(format!("Script error: {}", e));
+            return;
+        }
+    };
+    if article.sentiment_score != before {
+        let _ = db.update_sentiment(article.id, article.sentiment_score);
+    }
+    if keep {
+        app.update_article(article.id, |a| {
+            a.sentiment = article.sentiment;
+            a.sentiment_score = article.sentiment_score;
+            a.topics = article.topics.clone();
+        });
+    } else {
+        app.script_muted.insert(article.id);
+        app.articles.retain(|a| a.id != article.id);
+    }
+    app.display_dirty = true;
+    if let Some(message) = message {
+        app.set_status(message);
+    }
+}
+
+fn handle_reader_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    let before = app.selected_article().map(|a| a.id);
+    let position = article_scroll(app);
+    dispatch_reader_key(app, key, rt, client, content_tx, db);
+
+    // Leaving an article, for another or for the feed, remembers where
+    // reading stopped
+    let after = app
+        .selected_article()
+        .map(|a| a.id)
+        .filter(|_| app.view_mode == ViewMode::Reader);
+    if let Some(id) = before.filter(|id| after != Some(*id)) {
+        let _ = db.save_read_position(id, position);
+    }
+}
+
+/// Remember the open article's read position and the view state
+fn save_session(app: &App, db: &Db) {
+    if app.view_mode == ViewMode::Reader {
+        if let Some(id) = app.selected_article().map(|a| a.id) {
+            let _ = db.save_read_position(id, article_scroll(app));
+        }
+    }
+    let mut state = app.to_view_state();
+    state.session_end = Some(chrono::Utc::now().timestamp());
+    crate::state::save_state(&state);
+}
+
+/// Scroll position in the article itself, under any linked pages
+fn article_scroll(app: &App) -> u16 {
+    app.linked_pages
+        .first()
+        .map_or(app.reader_scroll, |page| page.return_scroll)
+}
+
+fn dispatch_reader_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    // The link picker and linked pages take the keys while open
+    if app.link_cursor.is_some() {
+        handle_link_picker_key(app, key);
+        return;
+    }
+    if app.highlight_selection.is_some() {
+        handle_highlight_key(app, key.code);
+        return;
+    }
+    if handle_find_key(app, key.code) {
+        return;
+    }
+    if !app.linked_pages.is_empty() {
+        handle_linked_page_key(app, key);
+        return;
+    }
+    if handle_scroll_key(app, key) {
+        return;
+    }
+
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.reader_content = None;
+            app.reader_scroll = 0;
+            app.reader_override = None;
+            // Bookmarks opened from their list give way to the feed, and
+            // articles read meanwhile leave the unread filter
+            if app.showing_bookmarks {
+                reload_articles(db, app);
+            } else if app.filter_mode == FilterMode::Unread {
+                app.display_dirty = true;
+            }
+        }
+
+        // Refetch the article, e.g. after a failed load
+        KeyCode::Char('r') => retry_content(app, rt, client, content_tx),
+
+        // Pick a link from the article to read in place
+        KeyCode::Char('l') => {
+            if app.reader_links.is_empty() {
+                app.set_status("No links in this article".to_string());
+            } else {
+                app.link_cursor = Some(0);
+            }
+        }
+
+        // Next/prev article
+        KeyCode::Char('n') => {
+            app.reader_override = None;
+            app.select_next();
+            open_reader_with_content(app, rt, client, content_tx, db);
+        }
+        KeyCode::Char('p') => {
+            app.reader_override = None;
+            app.select_prev();
+            open_reader_with_content(app, rt, client, content_tx, db);
+        }
+
+        // Jump to an article from the "Previously on" list
+        KeyCode::Char(c @ '1'..='9') => {
+            let idx = c as usize - '1' as usize;
+            if let Some(article) = app.reader_context.get(idx).cloned() {
+                app.reader_override = Some(article);
+                open_reader_with_content(app, rt, client, content_tx, db);
+            }
+        }
 
-        // View bookmarks
-        KeyCode::Char('B') => {
-            if app.view_mode == ViewMode::Bookmarks {
+        // Open in browser
+        KeyCode::Char('o') => {
+            if let Some(article) = app.selected_article() {
+                let url = article.url.clone();
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
+            }
+        }
+
+        // Summarize with the configured language model
+        KeyCode::Char('s') => request_summary(app),
+
+        // Toggle between the original and translated body
+        KeyCode::Char('t') => toggle_translation(app, db),
+
+        // Read aloud / stop
+        KeyCode::Char('v') => toggle_speech(app),
+
+        // Mark paragraphs to save as a highlight
+        KeyCode::Char('m') => start_highlight(app),
+
+        // Copy the article text, or pipe it to pipe_command
+        KeyCode::Char('y') => copy_article(app),
+        KeyCode::Char('|') => request_pipe(app),
+
+        // Send to the read-later service
+        KeyCode::Char('K') => request_send(app),
+
+        // Bookmark
+        KeyCode::Char('b') => {
+            let article_id = app.selected_article().map(|a| a.id);
+            if let Some(id) = article_id {
+                toggle_article_bookmark(db, app, id);
+            }
+        }
+
+        // Ticker filter from reader
+        KeyCode::Char('T') => {
+            let ticker = app
+                .selected_article()
+                .and_then(|a| a.tickers.first().cloned());
+            if let Some(ticker) = ticker {
+                app.set_ticker_filter(Some(ticker.clone()));
+                app.view_mode = ViewMode::Feed;
+                app.reader_content = None;
+                app.reader_scroll = 0;
+                app.reader_override = None;
+                app.set_status(format!("Ticker filter: {}", ticker));
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn handle_sources_key(app: &mut App, key: event::KeyEvent) {
+    match key.code {
+        KeyCode::Esc => {
+            app.sources_selected = app.selected_index;
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down => {
+            if app.selected_index < app.sources.len().saturating_sub(1) {
+                app.selected_index += 1;
+            }
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            if app.selected_index > 0 {
+                app.selected_index -= 1;
+            }
+        }
+
+        KeyCode::Char(' ') | KeyCode::Enter => {
+            if app.selected_index < app.sources.len() {
+                app.sources[app.selected_index].enabled =
+                    !app.sources[app.selected_index].enabled;
+                let name = app.sources[app.selected_index].name.clone();
+                let enabled_str = if app.sources[app.selected_index].enabled {
+                    "enabled"
+                } else {
+                    "disabled"
+                };
+                app.set_status(format!("{}: {}", name, enabled_str));
+                save_sources(app);
+            }
+        }
+
+        // Add source
+        KeyCode::Char('a') => app.start_add_source(),
+
+        // Edit source
+        KeyCode::Char('e') => app.start_edit_source(),
+
+        // Delete source
+        KeyCode::Char('d') => {
+            if app.selected_index < app.sources.len() {
+                app.input_mode = InputMode::SourceDelete;
+            }
+        }
+
+        // Re-run ticker and sentiment analysis over stored articles
+        KeyCode::Char('R') => {
+            if app.reanalysis_progress.is_some() {
+                app.set_status("Re-analysis already running".to_string());
+            } else {
+                app.reanalysis_requested = true;
+            }
+        }
+
+        _ => {}
+    }
+}
+
+fn handle_watchlist_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.watchlist_overview.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
+        }
+
+        // Drill into the ticker's filtered feed
+        KeyCode::Enter => {
+            let ticker = app
+                .watchlist_overview
+                .get(app.selected_index)
+                .map(|s| s.ticker.clone());
+            if let Some(ticker) = ticker {
+                app.view_mode = ViewMode::Feed;
+                app.set_ticker_filter(Some(ticker.clone()));
+                reload_articles(db, app);
+                app.set_status(format!("Ticker filter: {}", ticker));
+            }
+        }
+
+        KeyCode::Char('D') => {
+            let ticker = app
+                .watchlist_overview
+                .get(app.selected_index)
+                .map(|s| s.ticker.clone());
+            if let Some(ticker) = ticker {
+                open_timeline(db, app, ticker);
+            }
+        }
+
+        KeyCode::Char('i') => {
+            app.input_mode = InputMode::ImportWatchlist;
+            app.input_buffer.clear();
+        }
+
+        KeyCode::Char('a') => {
+            app.input_mode = InputMode::AddTicker;
+            app.input_buffer.clear();
+        }
+
+        KeyCode::Char('x') => {
+            let ticker = app
+                .watchlist_overview
+                .get(app.selected_index)
+                .map(|s| s.ticker.clone());
+            if let Some(ticker) = ticker {
+                toggle_watched(db, app, &ticker);
+            }
+        }
+
+        KeyCode::Char('r') => load_watchlist_overview(db, app),
+
+        _ => {}
+    }
+}
+
+/// Rebuild the per-ticker counts shown in the watchlist overview
+fn load_watchlist_overview(db: &Db, app: &mut App) {
+    let since = chrono::Utc::now().timestamp() - 24 * 3600;
+    app.watchlist_overview = app
+        .watchlist
+        .iter()
+        .map(|ticker| {
+            let articles = db
+                .get_articles_by_tickers(std::slice::from_ref(ticker), 200)
+                .unwrap_or_default();
+            TickerSummary::from_articles(ticker, &articles, since)
+        })
+        .collect();
+}
+
+fn handle_earnings_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.earnings.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
+        }
+
+        // Jump to the company's filtered feed
+        KeyCode::Enter => {
+            let ticker = app
+                .earnings
+                .get(app.selected_index)
+                .and_then(|(_, a)| a.tickers.first().cloned());
+            if let Some(ticker) = ticker {
                 app.view_mode = ViewMode::Feed;
+                app.set_ticker_filter(Some(ticker.clone()));
                 reload_articles(db, app);
+                app.set_status(format!("Ticker filter: {}", ticker));
             } else {
-                app.view_mode = ViewMode::Bookmarks;
-                if let Ok(articles) = db.get_bookmarked_articles(100) {
-                    app.articles = articles;
-                    app.display_dirty = true;
+                app.set_status("No ticker detected in this article".to_string());
+            }
+        }
+
+        KeyCode::Char('o') => {
+            let article = app
+                .earnings
+                .get(app.selected_index)
+                .map(|(_, a)| (a.id, a.url.clone()));
+            if let Some((id, url)) = article {
+                mark_article_read(db, app, id);
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
+            }
+        }
+
+        KeyCode::Char('r') => load_earnings(db, app),
+
+        _ => {}
+    }
+}
+
+fn load_earnings(db: &Db, app: &mut App) {
+    let articles = db
+        .get_articles_by_event(EventKind::Earnings, 500)
+        .unwrap_or_default();
+    app.earnings = earnings::calendar(articles);
+}
+
+fn handle_ipo_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.ipo_listings.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
+        }
+
+        // Show all coverage for the listing: by ticker if known, else by name
+        KeyCode::Enter => {
+            let target = app
+                .ipo_listings
+                .get(app.selected_index)
+                .map(|l| (l.ticker.clone(), l.company.clone()));
+            match target {
+                Some((Some(ticker), _)) => {
+                    app.view_mode = ViewMode::Feed;
+                    app.set_ticker_filter(Some(ticker.clone()));
+                    reload_articles(db, app);
+                    app.set_status(format!("Ticker filter: {}", ticker));
                 }
-                app.selected_index = 0;
+                Some((None, Some(company))) => {
+                    app.view_mode = ViewMode::Feed;
+                    app.search_query = company.clone();
+                    app.selected_index = 0;
+                    reload_articles(db, app);
+                    app.set_status(format!("Search: {}", company));
+                }
+                _ => app.set_status("No ticker or company detected".to_string()),
+            }
+        }
+
+        KeyCode::Char('o') => {
+            let article = app
+                .ipo_listings
+                .get(app.selected_index)
+                .map(|l| (l.latest.id, l.latest.url.clone()));
+            if let Some((id, url)) = article {
+                mark_article_read(db, app, id);
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
+            }
+        }
+
+        KeyCode::Char('r') => load_ipo_listings(db, app),
+
+        _ => {}
+    }
+}
+
+fn load_ipo_listings(db: &Db, app: &mut App) {
+    let articles = db
+        .get_articles_by_event(EventKind::Ipo, 500)
+        .unwrap_or_default();
+    app.ipo_listings = ipo::listings(articles);
+}
+
+fn handle_ratings_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.ratings.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
+        }
+
+        KeyCode::Enter => {
+            let ticker = app
+                .ratings
+                .get(app.selected_index)
+                .map(|r| r.rating.ticker.clone());
+            if let Some(ticker) = ticker {
+                app.view_mode = ViewMode::Feed;
+                app.set_ticker_filter(Some(ticker.clone()));
+                reload_articles(db, app);
+                app.set_status(format!("Ticker filter: {}", ticker));
+            }
+        }
+
+        KeyCode::Char('o') => {
+            let article = app
+                .ratings
+                .get(app.selected_index)
+                .map(|r| (r.article_id, r.url.clone()));
+            if let Some((id, url)) = article {
+                mark_article_read(db, app, id);
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
             }
         }
 
-        // Sources view
-        KeyCode::Char('S') => {
-            app.view_mode = ViewMode::Sources;
+        KeyCode::Char('r') => load_ratings(db, app),
+
+        _ => {}
+    }
+}
+
+/// Last 90 days of rating actions, grouped by ticker with the most
+/// recently rated ticker first
+fn load_ratings(db: &Db, app: &mut App) {
+    let since = chrono::Utc::now().timestamp() - 90 * 86400;
+    let entries = db.get_ratings(since, None, 500).unwrap_or_default();
+    let mut order: Vec<String> = Vec::new();
+    for e in &entries {
+        if !order.contains(&e.rating.ticker) {
+            order.push(e.rating.ticker.clone());
+        }
+    }
+    let mut entries = entries;
+    // Stable sort keeps newest-first within each ticker
+    entries.sort_by_key(|e| order.iter().position(|t| *t == e.rating.ticker));
+    app.ratings = entries;
+}
+
+fn handle_timeline_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.timeline.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
+        }
+
+        KeyCode::Enter => {
+            let ticker = app.timeline_ticker.clone();
+            app.view_mode = ViewMode::Feed;
+            app.set_ticker_filter(Some(ticker.clone()));
+            reload_articles(db, app);
+            app.set_status(format!("Ticker filter: {}", ticker));
+        }
+
+        KeyCode::Char('o') => {
+            let article = app
+                .timeline
+                .get(app.selected_index)
+                .map(|e| (e.article_id, e.url.clone()));
+            if let Some((id, url)) = article {
+                mark_article_read(db, app, id);
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
+            }
+        }
+
+        KeyCode::Char('r') => load_timeline(db, app),
+
+        _ => {}
+    }
+}
+
+fn handle_meetings_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
             app.selected_index = 0;
         }
 
-        // Filter
-        KeyCode::Char('f') => {
-            app.cycle_filter();
-            reload_articles(db, app);
-            app.set_status(format!("Filter: {}", app.filter_mode.label()));
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.meetings.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
         }
 
-        // Quick ticker filter: pick first ticker from selected article
-        KeyCode::Char('T') => {
+        KeyCode::Enter => {
             let ticker = app
-                .selected_article()
-                .and_then(|a| a.tickers.first().cloned());
+                .meetings
+                .get(app.selected_index)
+                .map(|m| m.ticker.clone());
             if let Some(ticker) = ticker {
+                app.view_mode = ViewMode::Feed;
                 app.set_ticker_filter(Some(ticker.clone()));
+                reload_articles(db, app);
                 app.set_status(format!("Ticker filter: {}", ticker));
-            } else {
-                app.set_status("No ticker detected in this article".to_string());
             }
         }
 
-        // Clear ticker filter
-        KeyCode::Char('c') => {
-            if app.ticker_filter.is_some() {
-                app.set_ticker_filter(None);
-                app.set_status("Ticker filter cleared".to_string());
+        KeyCode::Char('o') => {
+            let article = app
+                .meetings
+                .get(app.selected_index)
+                .map(|m| (m.article_id, m.url.clone()));
+            if let Some((id, url)) = article {
+                mark_article_read(db, app, id);
+                let _ = open::that(&*url);
+                app.set_status("Opened in browser".to_string());
             }
         }
 
-        // Refresh (rate-limited)
-        KeyCode::Char('r') => {
-            if !app.is_fetching {
-                let eligible = app.eligible_sources();
-                if eligible.is_empty() {
-                    app.set_status("All sources are rate-limited, try again later".to_string());
-                } else {
-                    spawn_fetch(rt, client, &eligible, feed_tx);
-                    app.is_fetching = true;
-                    app.last_refresh = Some(Instant::now());
-                    app.set_status("Refreshing feeds...".to_string());
+        KeyCode::Char('r') => load_meetings(db, app),
+
+        _ => {}
+    }
+}
+
+/// Upcoming RUPS for the watchlist and holdings (every ticker when both
+/// are empty), from announcements in the last 90 days
+fn load_meetings(db: &Db, app: &mut App) {
+    let since = chrono::Utc::now().timestamp() - 90 * 86400;
+    let articles = db.get_meeting_articles(since, 500).unwrap_or_default();
+    let mut tickers = app.watchlist.clone();
+    tickers.extend(app.holdings.iter().map(|h| h.ticker.clone()));
+    let today = chrono::Local::now().date_naive();
+    app.meetings = rups::upcoming(&articles, &tickers, today);
+}
+
+/// Append meetings coming up within `rups::REMIND_DAYS` to the status line
+fn remind_meetings(app: &mut App) {
+    let today = chrono::Local::now().date_naive();
+    let soon: Vec<String> = app
+        .meetings
+        .iter()
+        .filter(|m| m.days_until(today) <= rups::REMIND_DAYS)
+        .map(|m| format!("{} {}", m.ticker, m.when_label(today)))
+        .collect();
+    if !soon.is_empty() {
+        let reminder = format!("Upcoming RUPS: {}", soon.join(", "));
+        let status = match app.status_text() {
+            Some(current) => format!("{} | {}", current, reminder),
+            None => reminder,
+        };
+        app.set_status(status);
+    }
+}
+
+fn open_timeline(db: &Db, app: &mut App, ticker: String) {
+    app.timeline_ticker = ticker;
+    load_timeline(db, app);
+    app.view_mode = ViewMode::Timeline;
+    app.selected_index = 0;
+}
+
+/// Merge classified events and rating actions for the timeline ticker.
+/// A rating article is shown once, as a rating.
+fn load_timeline(db: &Db, app: &mut App) {
+    let ticker = app.timeline_ticker.clone();
+    let ratings = db
+        .get_ratings(0, Some(&ticker), 200)
+        .unwrap_or_default();
+    let articles = db
+        .get_articles_by_tickers(std::slice::from_ref(&ticker), 1000)
+        .unwrap_or_default();
+
+    let mut timeline: Vec<TimelineEntry> = articles
+        .iter()
+        .filter(|a| !ratings.iter().any(|r| r.article_id == a.id))
+        .filter_map(|a| {
+            let kind = a.event?;
+            let detail = match kind {
+                EventKind::Earnings => earnings::extract_period(&a.title, a.published_at)
+                    .map(|p| p.label())
+                    .unwrap_or_else(|| kind.label().to_string()),
+                _ => kind.label().to_string(),
+            };
+            Some(TimelineEntry {
+                published_at: a.published_at,
+                badge: kind.badge().to_string(),
+                detail,
+                title: a.title.to_string(),
+                article_id: a.id,
+                url: a.url.to_string(),
+                sentiment: a.sentiment,
+            })
+        })
+        .collect();
+
+    timeline.extend(ratings.iter().map(|r| {
+        let mut detail: Vec<String> = Vec::new();
+        if let Some(ref broker) = r.rating.broker {
+            detail.push(broker.clone());
+        }
+        if let Some(action) = r.rating.action {
+            detail.push(action.label().to_string());
+        }
+        if let Some(ref rating) = r.rating.rating {
+            detail.push(rating.clone());
+        }
+        if let Some(tp) = r.rating.target_price {
+            detail.push(format!("TP {:.0}", tp));
+        }
+        let sentiment = match r.rating.action {
+            Some(RatingAction::Upgrade) => Sentiment::Positive,
+            Some(RatingAction::Downgrade) => Sentiment::Negative,
+            _ => Sentiment::Neutral,
+        };
+        TimelineEntry {
+            published_at: r.published_at,
+            badge: "RATING".to_string(),
+            detail: detail.join(" "),
+            title: r.title.clone(),
+            article_id: r.article_id,
+            url: r.url.clone(),
+            sentiment,
+        }
+    }));
+
+    timeline.sort_by_key(|e| std::cmp::Reverse(e.published_at));
+    app.timeline = timeline;
+}
+
+fn handle_source_input_key(app: &mut App, key: event::KeyEvent) {
+    match &app.input_mode {
+        InputMode::SourceAdd(field) | InputMode::SourceEdit(field) => {
+            let is_name = matches!(field, SourceInputField::Name);
+            let is_add = matches!(app.input_mode, InputMode::SourceAdd(_));
+            match key.code {
+                KeyCode::Esc => {
+                    app.input_mode = InputMode::Normal;
+                }
+                KeyCode::Tab => {
+                    // Toggle between fields
+                    if is_name {
+                        app.input_mode = if is_add {
+                            InputMode::SourceAdd(SourceInputField::Url)
+                        } else {
+                            InputMode::SourceEdit(SourceInputField::Url)
+                        };
+                    } else {
+                        app.input_mode = if is_add {
+                            InputMode::SourceAdd(SourceInputField::Name)
+                        } else {
+                            InputMode::SourceEdit(SourceInputField::Name)
+                        };
+                    }
+                }
+                KeyCode::Enter => {
+                    if is_name {
+                        // Advance to URL
+                        app.input_mode = if is_add {
+                            InputMode::SourceAdd(SourceInputField::Url)
+                        } else {
+                            InputMode::SourceEdit(SourceInputField::Url)
+                        };
+                    } else {
+                        // Confirm
+                        if is_add {
+                            app.confirm_add_source();
+                        } else {
+                            app.confirm_edit_source();
+                        }
+                        save_sources(app);
+                    }
+                }
+                KeyCode::Backspace => {
+                    if is_name {
+                        app.source_edit_name.pop();
+                    } else {
+                        app.source_edit_url.pop();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if is_name {
+                        app.source_edit_name.push(c);
+                    } else {
+                        app.source_edit_url.push(c);
+                    }
                 }
+                _ => {}
+            }
+        }
+        InputMode::SourceDelete => match key.code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => {
+                app.delete_source();
+                save_sources(app);
+            }
+            _ => {
+                app.input_mode = InputMode::Normal;
+                app.set_status("Delete cancelled".to_string());
+            }
+        },
+        _ => {}
+    }
+}
+
+fn open_reader_with_content(
+    app: &mut App,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    let article_data = app.selected_article().map(|a| (a.id, a.url.to_string()));
+    if let Some((article_id, url)) = article_data {
+        app.enter_reader();
+        mark_article_read(db, app, article_id);
+        app.reader_summary = db.get_summary(article_id).ok().flatten();
+        app.reader_image = db.get_lead_image(article_id).ok().flatten();
+        app.reader_links = db.get_links(article_id).unwrap_or_default();
+        app.reader_scroll = db.get_read_position(article_id).unwrap_or(0);
+        load_reader_context(db, app);
+        let source = app
+            .selected_article()
+            .and_then(|a| app.source_of(a))
+            .cloned();
+        if app.reader_content.is_none() {
+            if let Ok(Some(content)) = db.get_content(article_id) {
+                app.cache_content(url, content);
+            } else if let Some(muted) = source.as_ref().filter(|s| s.mute_content) {
+                app.reader_content = Some(muted_content_notice(muted));
+                app.content_loading = false;
+            } else if !app.failed_content_urls.contains_key(&url) {
+                spawn_content_fetch(app, rt, client, &url, source, content_tx);
+            } else {
+                app.content_loading = false;
             }
         }
+        // A position saved from an older, longer copy or a wider terminal
+        // waits for the body when it is still being fetched
+        if !app.content_loading {
+            clamp_reader_scroll(app);
+        }
+    }
+}
 
-        // Search
+/// Forget the reader article's cached body or failure and fetch it again
+fn retry_content(
+    app: &mut App,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    content_tx: &mpsc::Sender<ContentMsg>,
+) {
+    let Some(url) = app.selected_article().map(|a| a.url.to_string()) else {
+        return;
+    };
+    let source = app
+        .selected_article()
+        .and_then(|a| app.source_of(a))
+        .cloned();
+    if let Some(muted) = source.as_ref().filter(|s| s.mute_content) {
+        app.reader_content = Some(muted_content_notice(muted));
+        return;
+    }
+    app.failed_content_urls.remove(&url);
+    app.content_cache.remove(&url);
+    app.reader_content = None;
+    app.content_loading = true;
+    spawn_content_fetch(app, rt, client, &url, source, content_tx);
+    app.set_status("Reloading article...".to_string());
+}
+
+/// Reader body shown instead of fetching from a source with mute_content
+fn muted_content_notice(source: &FeedSource) -> String {
+    format!(
+        "Content fetching is muted for {}.\n\nPress [o] to open in browser.",
+        source.name
+    )
+}
+
+/// In-article search: `/` to search, n/N between matches while a search
+/// is active, Esc to clear it. True if handled.
+fn handle_find_key(app: &mut App, code: KeyCode) -> bool {
+    match code {
         KeyCode::Char('/') => {
-            app.input_mode = InputMode::Search;
+            app.input_mode = InputMode::ReaderSearch;
             app.input_buffer.clear();
         }
+        KeyCode::Char('n') if app.reader_search.is_some() => {
+            app.reader_match += 1;
+            jump_to_match(app);
+        }
+        KeyCode::Char('N') if app.reader_search.is_some() => {
+            app.reader_match = app.reader_match.wrapping_sub(1);
+            jump_to_match(app);
+        }
+        KeyCode::Esc if app.reader_search.is_some() => {
+            app.reader_search = None;
+            app.set_status("Search cleared".to_string());
+        }
+        _ => return false,
+    }
+    true
+}
 
-        // Theme
-        KeyCode::Char('t') => {
-            app.cycle_theme();
-            app.set_status(format!("Theme: {}", app.theme_name.label()));
+/// Scroll the reader to the current search match, wrapping around
+fn jump_to_match(app: &mut App) {
+    let width = app.terminal_size.0;
+    let rows = ui::reader_match_rows(app, width);
+    if rows.is_empty() {
+        let query = app.reader_search.take().unwrap_or_default();
+        app.set_status(format!("No matches for \"{}\"", query));
+        return;
+    }
+    app.reader_match = if app.reader_match == usize::MAX {
+        rows.len() - 1
+    } else {
+        app.reader_match % rows.len()
+    };
+    // Leave a little context above the match
+    app.reader_scroll = rows[app.reader_match].saturating_sub(2);
+    clamp_reader_scroll(app);
+    app.set_status(format!(
+        "Match {}/{} (n/N next/prev, Esc clear)",
+        app.reader_match + 1,
+        rows.len()
+    ));
+}
+
+/// Reader scrolling shared by articles and linked pages; true if handled
+fn handle_scroll_key(app: &mut App, key: event::KeyEvent) -> bool {
+    match key.code {
+        KeyCode::Char('j') | KeyCode::Down => {
+            app.reader_scroll = app.reader_scroll.saturating_add(1);
+        }
+        KeyCode::Char('k') | KeyCode::Up => {
+            app.reader_scroll = app.reader_scroll.saturating_sub(1);
+        }
+
+        // Page down / page up
+        KeyCode::Char('d') => {
+            app.reader_scroll = app.reader_scroll.saturating_add(10);
+        }
+        KeyCode::Char('u') => {
+            app.reader_scroll = app.reader_scroll.saturating_sub(10);
+        }
+
+        // A full screen, with Shift-Space going back
+        KeyCode::Char(' ') if key.modifiers.contains(KeyModifiers::SHIFT) => {
+            app.reader_scroll = app.reader_scroll.saturating_sub(reader_page_height(app));
+        }
+        KeyCode::PageDown | KeyCode::Char(' ') => {
+            app.reader_scroll = app.reader_scroll.saturating_add(reader_page_height(app));
+        }
+        KeyCode::PageUp => {
+            app.reader_scroll = app.reader_scroll.saturating_sub(reader_page_height(app));
+        }
+
+        // Scroll to top/bottom
+        KeyCode::Char('g') | KeyCode::Home => {
+            app.reader_scroll = 0;
+        }
+        KeyCode::Char('G') | KeyCode::End => {
+            app.reader_scroll = u16::MAX;
+        }
+        _ => return false,
+    }
+    clamp_reader_scroll(app);
+    true
+}
+
+/// Stop the reader at its last page instead of scrolling into blank rows
+fn clamp_reader_scroll(app: &mut App) {
+    let max = ui::reader_max_scroll(app, app.terminal_size);
+    app.reader_scroll = app.reader_scroll.min(max);
+}
+
+/// Rows of article text visible at once, keeping one row of overlap
+fn reader_page_height(app: &App) -> u16 {
+    let (_, height) = ui::reader_text_area(app, app.terminal_size);
+    height.saturating_sub(1).max(1)
+}
+
+/// Begin marking at the first paragraph on screen
+fn start_highlight(app: &mut App) {
+    let width = app.terminal_size.0;
+    let rows = ui::reader_paragraph_rows(app, width);
+    if rows.is_empty() {
+        app.set_status("Article content not loaded yet".to_string());
+        return;
+    }
+    let start = rows
+        .iter()
+        .position(|row| *row >= app.reader_scroll)
+        .unwrap_or(rows.len() - 1);
+    app.highlight_selection = Some((start, start));
+    app.set_status("Marking: j/k extend, Enter save, Esc cancel".to_string());
+}
+
+/// Keys while paragraphs are being marked for a highlight
+fn handle_highlight_key(app: &mut App, code: KeyCode) {
+    let Some((anchor, cursor)) = app.highlight_selection else {
+        return;
+    };
+    let width = app.terminal_size.0;
+    let rows = ui::reader_paragraph_rows(app, width);
+    let last = rows.len().saturating_sub(1);
+    let cursor = match code {
+        KeyCode::Char('j') | KeyCode::Down => (cursor + 1).min(last),
+        KeyCode::Char('k') | KeyCode::Up => cursor.saturating_sub(1),
+        KeyCode::Enter => {
+            app.input_mode = InputMode::HighlightNote;
+            app.input_buffer.clear();
+            return;
+        }
+        KeyCode::Esc => {
+            app.highlight_selection = None;
+            app.set_status("Highlight cancelled".to_string());
+            return;
+        }
+        _ => return,
+    };
+    app.highlight_selection = Some((anchor, cursor));
+
+    // Keep the cursor paragraph on screen
+    if let Some(&row) = rows.get(cursor) {
+        let page = reader_page_height(app);
+        if row < app.reader_scroll {
+            app.reader_scroll = row;
+        } else if row >= app.reader_scroll + page {
+            app.reader_scroll = row.saturating_sub(page / 2);
+        }
+    }
+}
+
+fn save_highlight(app: &mut App, db: &Db, note: Option<&str>) {
+    let Some((lo, hi)) = app.highlight_range() else {
+        return;
+    };
+    app.highlight_selection = None;
+    let (Some(article_id), Some(body)) = (app.selected_article().map(|a| a.id), app.reader_body())
+    else {
+        return;
+    };
+    let text = ui::body_paragraphs(body)
+        .skip(lo)
+        .take(hi - lo + 1)
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    match db.add_highlight(article_id, &text, note) {
+        Ok(()) => app.set_status("Highlight saved ([Y] in the feed lists them)".to_string()),
+        Err(e) => app.set_status(format!("Failed to save highlight: {}", e)),
+    }
+}
+
+fn load_highlights(db: &Db, app: &mut App) {
+    app.highlights = db.get_highlights(None, 500).unwrap_or_default();
+}
+
+fn handle_highlights_key(
+    app: &mut App,
+    key: event::KeyEvent,
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    content_tx: &mpsc::Sender<ContentMsg>,
+    db: &Db,
+) {
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') => {
+            app.view_mode = ViewMode::Feed;
+            app.selected_index = 0;
+        }
+
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.highlights.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
+        }
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
+        }
+
+        // Read the article the highlight came from
+        KeyCode::Enter => {
+            let article = app
+                .highlights
+                .get(app.selected_index)
+                .and_then(|h| db.get_article(h.article_id).ok().flatten());
+            if let Some(article) = article {
+                app.reader_override = Some(article);
+                open_reader_with_content(app, rt, client, content_tx, db);
+            }
+        }
+
+        KeyCode::Char('o') => {
+            if let Some(h) = app.highlights.get(app.selected_index) {
+                let _ = open::that(&h.url);
+                app.set_status("Opened in browser".to_string());
+            }
+        }
+
+        KeyCode::Char('d') => {
+            let id = app.highlights.get(app.selected_index).map(|h| h.id);
+            if let Some(id) = id {
+                let _ = db.delete_highlight(id);
+                load_highlights(db, app);
+                app.selected_index = app
+                    .selected_index
+                    .min(app.highlights.len().saturating_sub(1));
+                app.set_status("Highlight deleted".to_string());
+            }
         }
 
+        KeyCode::Char('r') => load_highlights(db, app),
+
         _ => {}
     }
 }
 
-fn handle_reader_key(
+fn load_alerts(db: &Db, app: &mut App) {
+    app.alert_history = db.get_alerts(false, 500).unwrap_or_default();
+}
+
+fn handle_alerts_key(
     app: &mut App,
     key: event::KeyEvent,
     rt: &tokio::runtime::Runtime,
@@ -428,241 +3326,315 @@ fn handle_reader_key(
     match key.code {
         KeyCode::Esc | KeyCode::Char('q') => {
             app.view_mode = ViewMode::Feed;
-            app.reader_content = None;
-            app.reader_scroll = 0;
-            reload_articles(db, app);
-        }
-
-        // Scroll content
-        KeyCode::Char('j') | KeyCode::Down => {
-            app.reader_scroll = app.reader_scroll.saturating_add(1);
-        }
-        KeyCode::Char('k') | KeyCode::Up => {
-            app.reader_scroll = app.reader_scroll.saturating_sub(1);
-        }
-
-        // Page down / page up
-        KeyCode::Char('d') => {
-            app.reader_scroll = app.reader_scroll.saturating_add(10);
-        }
-        KeyCode::Char('u') => {
-            app.reader_scroll = app.reader_scroll.saturating_sub(10);
+            app.selected_index = 0;
         }
 
-        // Scroll to top/bottom
-        KeyCode::Char('g') => {
-            app.reader_scroll = 0;
+        KeyCode::Char('j') | KeyCode::Down
+            if app.selected_index < app.alert_history.len().saturating_sub(1) =>
+        {
+            app.selected_index += 1;
         }
-        KeyCode::Char('G') => {
-            // Scroll to a large number, UI will clamp
-            app.reader_scroll = u16::MAX;
+        KeyCode::Char('k') | KeyCode::Up if app.selected_index > 0 => {
+            app.selected_index -= 1;
         }
 
-        // Next/prev article
-        KeyCode::Char('n') => {
-            app.select_next();
-            open_reader_with_content(app, rt, client, content_tx, db);
-        }
-        KeyCode::Char('p') => {
-            app.select_prev();
-            open_reader_with_content(app, rt, client, content_tx, db);
+        // Read the article the alert fired for
+        KeyCode::Enter => {
+            let article = app
+                .alert_history
+                .get(app.selected_index)
+                .and_then(|a| db.get_article(a.article_id).ok().flatten());
+            if let Some(article) = article {
+                app.reader_override = Some(article);
+                open_reader_with_content(app, rt, client, content_tx, db);
+            }
         }
 
-        // Open in browser
         KeyCode::Char('o') => {
-            if let Some(article) = app.selected_article() {
-                let url = article.url.clone();
-                let _ = open::that(&url);
+            if let Some(alert) = app.alert_history.get(app.selected_index) {
+                let _ = open::that(&alert.url);
                 app.set_status("Opened in browser".to_string());
             }
         }
 
-        // Bookmark
-        KeyCode::Char('b') => {
-            let article_id = app.selected_article().map(|a| a.id);
-            if let Some(id) = article_id {
-                if let Ok(bookmarked) = db.toggle_bookmark(id) {
-                    let msg = if bookmarked {
-                        "Bookmarked"
-                    } else {
-                        "Unbookmarked"
-                    };
-                    app.set_status(msg.to_string());
-                    reload_articles(db, app);
+        // Acknowledge (or un-acknowledge) the selected alert
+        KeyCode::Char(' ') => {
+            let alert = app
+                .alert_history
+                .get(app.selected_index)
+                .map(|a| (a.id, a.url.clone(), !a.acknowledged));
+            if let Some((id, url, acknowledged)) = alert {
+                let _ = db.set_alert_acknowledged(id, acknowledged);
+                if acknowledged {
+                    app.trading_alerts.retain(|a| *a.url != url);
                 }
+                load_alerts(db, app);
             }
         }
 
-        // Ticker filter from reader
-        KeyCode::Char('T') => {
-            let ticker = app
-                .selected_article()
-                .and_then(|a| a.tickers.first().cloned());
-            if let Some(ticker) = ticker {
-                app.set_ticker_filter(Some(ticker.clone()));
-                app.view_mode = ViewMode::Feed;
-                app.reader_content = None;
-                app.reader_scroll = 0;
-                app.set_status(format!("Ticker filter: {}", ticker));
-            }
+        KeyCode::Char('A') => {
+            let _ = db.acknowledge_all_alerts();
+            app.trading_alerts.clear();
+            load_alerts(db, app);
+            app.set_status("All alerts acknowledged".to_string());
         }
 
+        KeyCode::Char('r') => load_alerts(db, app),
+
         _ => {}
     }
 }
 
-fn handle_sources_key(app: &mut App, key: event::KeyEvent) {
-    match key.code {
-        KeyCode::Esc => {
-            app.view_mode = ViewMode::Feed;
-            app.selected_index = 0;
+fn handle_recent_tickers_key(app: &mut App, key: event::KeyEvent) {
+    let Some(cursor) = app.recent_cursor else {
+        return;
+    };
+    let pick = match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('h') => {
+            app.recent_cursor = None;
+            None
+        }
+        KeyCode::Char('j') | KeyCode::Down if cursor + 1 < app.recent_tickers.len() => {
+            app.recent_cursor = Some(cursor + 1);
+            None
         }
+        KeyCode::Char('k') | KeyCode::Up if cursor > 0 => {
+            app.recent_cursor = Some(cursor - 1);
+            None
+        }
+        KeyCode::Enter => Some(cursor),
+        KeyCode::Char(c @ '1'..='9') => Some(c as usize - '1' as usize),
+        _ => None,
+    };
+    if let Some(ticker) = pick.and_then(|i| app.recent_tickers.get(i).cloned()) {
+        app.recent_cursor = None;
+        app.set_ticker_filter(Some(ticker.clone()));
+        app.set_status(format!("Ticker filter: {}", ticker));
+    }
+}
 
-        KeyCode::Char('j') | KeyCode::Down => {
-            if app.selected_index < app.sources.len().saturating_sub(1) {
-                app.selected_index += 1;
-            }
+fn handle_link_picker_key(app: &mut App, key: event::KeyEvent) {
+    let Some(cursor) = app.link_cursor else {
+        return;
+    };
+    let count = app.current_links().len();
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('l') => app.link_cursor = None,
+        KeyCode::Char('j') | KeyCode::Down if cursor + 1 < count => {
+            app.link_cursor = Some(cursor + 1);
         }
-        KeyCode::Char('k') | KeyCode::Up => {
-            if app.selected_index > 0 {
-                app.selected_index -= 1;
-            }
+        KeyCode::Char('k') | KeyCode::Up if cursor > 0 => {
+            app.link_cursor = Some(cursor - 1);
         }
 
-        KeyCode::Char(' ') | KeyCode::Enter => {
-            if app.selected_index < app.sources.len() {
-                app.sources[app.selected_index].enabled =
-                    !app.sources[app.selected_index].enabled;
-                let name = app.sources[app.selected_index].name.clone();
-                let enabled_str = if app.sources[app.selected_index].enabled {
-                    "enabled"
-                } else {
-                    "disabled"
-                };
-                app.set_status(format!("{}: {}", name, enabled_str));
-                config::save_sources(&app.sources);
+        // Read the link in place, on top of the back-stack
+        KeyCode::Enter => {
+            if let Some(link) = app.current_links().get(cursor).cloned() {
+                app.linked_pages.push(LinkedPage {
+                    title: link.text,
+                    url: link.url.clone(),
+                    content: None,
+                    links: Vec::new(),
+                    return_scroll: app.reader_scroll,
+                });
+                app.reader_scroll = 0;
+                app.link_cursor = None;
+                app.reader_search = None;
+                app.pending_link = Some(link.url);
             }
         }
 
-        // Add source
-        KeyCode::Char('a') => app.start_add_source(),
-
-        // Edit source
-        KeyCode::Char('e') => app.start_edit_source(),
-
-        // Delete source
-        KeyCode::Char('d') => {
-            if app.selected_index < app.sources.len() {
-                app.input_mode = InputMode::SourceDelete;
+        KeyCode::Char('o') => {
+            if let Some(link) = app.current_links().get(cursor) {
+                let _ = open::that(&link.url);
+                app.set_status("Opened in browser".to_string());
             }
         }
-
         _ => {}
     }
 }
 
-fn handle_source_input_key(app: &mut App, key: event::KeyEvent) {
-    match &app.input_mode {
-        InputMode::SourceAdd(field) | InputMode::SourceEdit(field) => {
-            let is_name = matches!(field, SourceInputField::Name);
-            let is_add = matches!(app.input_mode, InputMode::SourceAdd(_));
-            match key.code {
-                KeyCode::Esc => {
-                    app.input_mode = InputMode::Normal;
-                }
-                KeyCode::Tab => {
-                    // Toggle between fields
-                    if is_name {
-                        app.input_mode = if is_add {
-                            InputMode::SourceAdd(SourceInputField::Url)
-                        } else {
-                            InputMode::SourceEdit(SourceInputField::Url)
-                        };
-                    } else {
-                        app.input_mode = if is_add {
-                            InputMode::SourceAdd(SourceInputField::Name)
-                        } else {
-                            InputMode::SourceEdit(SourceInputField::Name)
-                        };
-                    }
-                }
-                KeyCode::Enter => {
-                    if is_name {
-                        // Advance to URL
-                        app.input_mode = if is_add {
-                            InputMode::SourceAdd(SourceInputField::Url)
-                        } else {
-                            InputMode::SourceEdit(SourceInputField::Url)
-                        };
-                    } else {
-                        // Confirm
-                        if is_add {
-                            app.confirm_add_source();
-                        } else {
-                            app.confirm_edit_source();
-                        }
-                        config::save_sources(&app.sources);
-                    }
-                }
-                KeyCode::Backspace => {
-                    if is_name {
-                        app.source_edit_name.pop();
-                    } else {
-                        app.source_edit_url.pop();
-                    }
-                }
-                KeyCode::Char(c) => {
-                    if is_name {
-                        app.source_edit_name.push(c);
-                    } else {
-                        app.source_edit_url.push(c);
-                    }
-                }
-                _ => {}
+fn handle_linked_page_key(app: &mut App, key: event::KeyEvent) {
+    if handle_scroll_key(app, key) {
+        return;
+    }
+    match key.code {
+        // Back to the page underneath
+        KeyCode::Esc | KeyCode::Backspace | KeyCode::Char('q') => {
+            if let Some(page) = app.linked_pages.pop() {
+                app.reader_scroll = page.return_scroll;
+                app.reader_search = None;
             }
         }
-        InputMode::SourceDelete => match key.code {
-            KeyCode::Char('y') | KeyCode::Char('Y') => {
-                app.delete_source();
-                config::save_sources(&app.sources);
+        KeyCode::Char('l') => {
+            if app.current_links().is_empty() {
+                app.set_status("No links on this page".to_string());
+            } else {
+                app.link_cursor = Some(0);
             }
-            _ => {
-                app.input_mode = InputMode::Normal;
-                app.set_status("Delete cancelled".to_string());
+        }
+        KeyCode::Char('o') => {
+            if let Some(page) = app.linked_pages.last() {
+                let _ = open::that(&page.url);
+                app.set_status("Opened in browser".to_string());
             }
-        },
+        }
         _ => {}
     }
 }
 
-fn open_reader_with_content(
-    app: &mut App,
-    rt: &tokio::runtime::Runtime,
-    client: &reqwest::Client,
-    content_tx: &mpsc::Sender<ContentMsg>,
-    db: &Db,
-) {
-    let article_data = app.selected_article().map(|a| (a.id, a.url.clone()));
-    if let Some((article_id, url)) = article_data {
-        let _ = db.mark_read(article_id);
-        app.enter_reader();
-        if app.reader_content.is_none() {
-            if let Ok(Some(content)) = db.get_content(article_id) {
-                app.cache_content(url, content);
-            } else if !app.failed_content_urls.contains(&url) {
-                spawn_content_fetch(rt, client, &url, content_tx);
-            } else {
-                app.content_loading = false;
-            }
+/// Queue the open article for summarizing unless it already has one
+fn request_summary(app: &mut App) {
+    if app.llm.is_none() {
+        app.set_status("No summarizer configured; set [llm] provider in config".to_string());
+        return;
+    }
+    if app.reader_summary.is_some() || app.summary_loading {
+        return;
+    }
+    let Some(content) = app.reader_content.clone() else {
+        app.set_status("Article content not loaded yet".to_string());
+        return;
+    };
+    if let Some(article) = app.selected_article() {
+        app.pending_summary = Some((article.id, article.title.to_string(), content));
+        app.summary_loading = true;
+    }
+}
+
+/// Title, body (translated when shown), and URL of the reader article
+fn reader_text(app: &App) -> Option<String> {
+    let body = app.reader_body()?;
+    let article = app.selected_article()?;
+    Some(format!("{}\n\n{}\n\n{}\n", article.title, body, article.url))
+}
+
+fn copy_article(app: &mut App) {
+    let Some(text) = reader_text(app) else {
+        app.set_status("Article content not loaded yet".to_string());
+        return;
+    };
+    match hook::copy_text(app.clipboard_command.as_deref(), &text) {
+        Ok(()) => app.set_status("Copied article text".to_string()),
+        Err(e) => app.set_status(format!("Copy failed: {}", e)),
+    }
+}
+
+fn request_pipe(app: &mut App) {
+    if app.pipe_command.is_none() {
+        app.set_status("No pipe command configured; set pipe_command in config".to_string());
+        return;
+    }
+    match reader_text(app) {
+        Some(text) => app.pending_pipe = Some(text),
+        None => app.set_status("Article content not loaded yet".to_string()),
+    }
+}
+
+/// Start reading the open article aloud, or stop the current playback.
+/// Playback keeps going after leaving the reader.
+fn toggle_speech(app: &mut App) {
+    if let Some(child) = app.speech.take() {
+        hook::stop_speech(child);
+        app.set_status("Playback stopped".to_string());
+        return;
+    }
+    let Some(cmd) = app.tts_command.clone() else {
+        app.set_status("No TTS command configured; set tts_command in config".to_string());
+        return;
+    };
+    let Some(body) = app.reader_body().cloned() else {
+        app.set_status("Article content not loaded yet".to_string());
+        return;
+    };
+    let title = app
+        .selected_article()
+        .map(|a| a.title.clone())
+        .unwrap_or_default();
+    match hook::start_speech(&cmd, &format!("{}.\n\n{}", title, body)) {
+        Some(child) => {
+            app.speech = Some(child);
+            app.set_status("Reading aloud, [v] to stop".to_string());
         }
-        reload_articles(db, app);
+        None => app.set_status(format!("Failed to start: {}", cmd)),
+    }
+}
+
+/// Show the translated body, translating on first use, or go back to the
+/// original. Indonesian articles are translated to English and vice versa.
+fn toggle_translation(app: &mut App, db: &Db) {
+    if app.show_translation {
+        app.show_translation = false;
+        app.set_status("Showing original".to_string());
+        return;
+    }
+    if app.reader_translation.is_some() {
+        app.show_translation = true;
+        return;
     }
+    if app.translator.is_none() {
+        app.set_status("No translator configured; set [translate] kind in config".to_string());
+        return;
+    }
+    if app.translation_loading {
+        return;
+    }
+    let Some(content) = app.reader_content.clone() else {
+        app.set_status("Article content not loaded yet".to_string());
+        return;
+    };
+    let Some(id) = app.selected_article().map(|a| a.id) else {
+        return;
+    };
+    let target = translate::target_for(&content).to_string();
+    if let Ok(Some(text)) = db.get_translation(id, &target) {
+        app.reader_translation = Some((target, text));
+        app.show_translation = true;
+        return;
+    }
+    app.pending_translation = Some((id, content, target));
+    app.translation_loading = true;
+}
+
+/// Older articles on the same tickers or the same story, newest first,
+/// capped at what the number keys can reach
+fn load_reader_context(db: &Db, app: &mut App) {
+    let Some(current) = app.selected_article().cloned() else {
+        app.reader_context.clear();
+        return;
+    };
+
+    let mut context: Vec<Article> = app
+        .articles
+        .iter()
+        .filter(|a| {
+            title_similarity(&a.title, &current.title, &app.dedup.stop_words)
+                >= app.dedup.threshold
+        })
+        .cloned()
+        .collect();
+    if !current.tickers.is_empty() {
+        context.extend(
+            db.get_articles_by_tickers(&current.tickers, 50)
+                .unwrap_or_default(),
+        );
+    }
+
+    let mut seen = std::collections::HashSet::new();
+    context.retain(|a| {
+        a.id != current.id && a.published_at <= current.published_at && seen.insert(a.id)
+    });
+    context.sort_by_key(|a| std::cmp::Reverse(a.published_at));
+    context.truncate(9);
+    app.reader_context = context;
 }
 
 fn handle_search_key(app: &mut App, key: event::KeyEvent, _db: &Db) {
     match key.code {
         KeyCode::Enter => {
             app.search_query = app.input_buffer.clone();
+            let query = app.search_query.trim().to_string();
+            app.push_search_history(&query);
             app.input_mode = InputMode::Normal;
             app.input_buffer.clear();
             app.selected_index = 0;
@@ -676,10 +3648,18 @@ fn handle_search_key(app: &mut App, key: event::KeyEvent, _db: &Db) {
         KeyCode::Esc => {
             app.input_mode = InputMode::Normal;
             app.input_buffer.clear();
+            app.history_cursor = None;
             app.search_query.clear();
             app.selected_index = 0;
             app.display_dirty = true;
         }
+        KeyCode::Up => app.history_prev(),
+        KeyCode::Down => app.history_next(),
+        KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            app.input_mode = InputMode::SearchHistory;
+            app.history_pattern.clear();
+            app.history_match = 0;
+        }
         KeyCode::Backspace => {
             app.input_buffer.pop();
         }
@@ -689,3 +3669,38 @@ fn handle_search_key(app: &mut App, key: event::KeyEvent, _db: &Db) {
         _ => {}
     }
 }
+
+/// Ctrl-R over past searches: typing narrows the list, Ctrl-R steps to
+/// older matches, Enter searches, Tab edits the match first
+fn handle_search_history_key(app: &mut App, key: event::KeyEvent, db: &Db) {
+    let older = matches!(key.code, KeyCode::Up)
+        || (key.code == KeyCode::Char('r') && key.modifiers.contains(KeyModifiers::CONTROL));
+    if older {
+        if app.history_match + 1 < app.history_matches().len() {
+            app.history_match += 1;
+        }
+        return;
+    }
+    match key.code {
+        KeyCode::Down => app.history_match = app.history_match.saturating_sub(1),
+        KeyCode::Enter | KeyCode::Tab => {
+            if let Some(query) = app.history_matches().get(app.history_match) {
+                app.input_buffer = query.to_string();
+            }
+            app.input_mode = InputMode::Search;
+            if key.code == KeyCode::Enter {
+                handle_search_key(app, key, db);
+            }
+        }
+        KeyCode::Esc => app.input_mode = InputMode::Search,
+        KeyCode::Backspace => {
+            app.history_pattern.pop();
+            app.history_match = 0;
+        }
+        KeyCode::Char(c) => {
+            app.history_pattern.push(c);
+            app.history_match = 0;
+        }
+        _ => {}
+    }
+}
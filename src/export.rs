@@ -0,0 +1,76 @@
+use crate::model::{Article, Highlight};
+use std::collections::HashMap;
+
+fn escape_xml(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Render articles as an RSS 2.0 feed. Saved highlights become the
+/// item description.
+pub fn render_rss(
+    title: &str,
+    articles: &[Article],
+    highlights: &HashMap<i64, Vec<Highlight>>,
+) -> String {
+    let mut out = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<rss version=\"2.0\">\n<channel>\n",
+    );
+    out.push_str(&format!("  <title>{}</title>\n", escape_xml(title)));
+    out.push_str("  <link>https://github.com/darkdenlion/stocknewstui</link>\n");
+    out.push_str(&format!(
+        "  <description>{} exported from StockNewsTUI</description>\n",
+        escape_xml(title)
+    ));
+
+    for article in articles {
+        let pub_date = chrono::DateTime::from_timestamp(article.published_at, 0)
+            .map(|dt| dt.to_rfc2822())
+            .unwrap_or_default();
+        out.push_str("  <item>\n");
+        out.push_str(&format!(
+            "    <title>{}</title>\n",
+            escape_xml(&article.title)
+        ));
+        out.push_str(&format!("    <link>{}</link>\n", escape_xml(&article.url)));
+        out.push_str(&format!(
+            "    <guid isPermaLink=\"true\">{}</guid>\n",
+            escape_xml(&article.url)
+        ));
+        out.push_str(&format!("    <pubDate>{}</pubDate>\n", pub_date));
+        out.push_str(&format!(
+            "    <source url=\"{}\">{}</source>\n",
+            escape_xml(&article.url),
+            escape_xml(&article.source)
+        ));
+        for ticker in &article.tickers {
+            out.push_str(&format!(
+                "    <category>{}</category>\n",
+                escape_xml(ticker)
+            ));
+        }
+        if let Some(saved) = highlights.get(&article.id).filter(|h| !h.is_empty()) {
+            let html: String = saved
+                .iter()
+                .map(|h| {
+                    let quote = format!("<blockquote>{}</blockquote>", escape_xml(&h.text));
+                    match &h.note {
+                        Some(note) => format!("{}<p>{}</p>", quote, escape_xml(note)),
+                        None => quote,
+                    }
+                })
+                .collect();
+            out.push_str(&format!(
+                "    <description>{}</description>\n",
+                escape_xml(&html)
+            ));
+        }
+        out.push_str("  </item>\n");
+    }
+
+    out.push_str("</channel>\n</rss>\n");
+    out
+}
@@ -1,28 +1,348 @@
-use crate::model::{analyze_sentiment, Article, FeedSource};
+use crate::companies::{Company, MARKED_NAMES};
+use crate::config::{BoilerplateConfig, NetworkConfig, TickerConfig};
+use crate::demo;
+use crate::ipo;
+use crate::model::{
+    classify_event, intern, sentiment_from_score, sentiment_score, starts_word, word_count,
+    Article, EventKind, FeedSource, Language, Link,
+};
 use regex::Regex;
-use scraper::{Html, Selector};
-use std::sync::LazyLock;
-use std::time::Duration;
+use scraper::{ElementRef, Html, Node, Selector};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::net::SocketAddr;
+use std::sync::{Arc, LazyLock, OnceLock, RwLock};
+use std::time::{Duration, Instant};
+use tokio::sync::Semaphore;
 
-static TICKER_RE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[A-Z]{4}\b").unwrap());
+/// Common words that fit the default 4-letter pattern but aren't tickers
+const EXCLUDED_WORDS: &[&str] = &[
+    "DARI", "YANG", "AKAN", "BISA", "JADI", "BARU", "HARI", "JUGA",
+    "OLEH", "PADA", "PARA", "LAGI", "BAIK", "BAGI", "KATA", "SAAT",
+    "TAPI", "MAKA", "DEMI", "AGAR", "JIKA", "SOAL", "THIS",
+    "THAT", "WITH", "FROM", "HAVE", "BEEN", "WILL", "THEY", "WHAT",
+    "WHEN", "INTO", "THAN", "THEM", "EACH", "JUST", "ONLY", "ALSO",
+    "VERY", "MORE", "SOME", "OVER", "SUCH", "BACK", "YEAR", "MOST",
+    "BUMN", "BUMD", "APBN", "APBD", "PPKM", "IHSG", "BKPM", "KPPU",
+    "PDRB", "OPEC", "NATO",
+];
+
+/// Ticker patterns (regex, known_only), the exclusion list, and codes that
+/// are always accepted, set from config at startup
+struct TickerRules {
+    patterns: Vec<(Regex, bool)>,
+    exclude: HashSet<String>,
+    allow: HashSet<String>,
+}
+
+static TICKER_RULES: LazyLock<RwLock<TickerRules>> = LazyLock::new(|| {
+    RwLock::new(TickerRules {
+        patterns: vec![(Regex::new(r"\b[A-Z]{4}\b").unwrap(), true)],
+        exclude: EXCLUDED_WORDS.iter().map(|w| w.to_string()).collect(),
+        allow: HashSet::new(),
+    })
+});
+
+/// Install the configured ticker patterns. Patterns that fail to compile
+/// are skipped; `doctor` reports them.
+pub fn set_ticker_rules(cfg: &TickerConfig) {
+    let patterns: Vec<(Regex, bool)> = cfg
+        .patterns
+        .iter()
+        .filter_map(|p| Regex::new(&p.regex).ok().map(|re| (re, p.known_only)))
+        .collect();
+    if let Ok(mut rules) = TICKER_RULES.write() {
+        rules.patterns = patterns;
+        rules.exclude = EXCLUDED_WORDS
+            .iter()
+            .map(|w| w.to_string())
+            .chain(cfg.exclude.iter().map(|w| w.to_uppercase()))
+            .collect();
+        rules.allow = cfg.allow.iter().map(|w| w.to_uppercase()).collect();
+    }
+}
+
+/// Whether a stored ticker survives the current exclusion list, so words
+/// excluded after an article was saved drop off it too
+pub fn keep_ticker(code: &str) -> bool {
+    TICKER_RULES
+        .read()
+        .map(|rules| rules.allow.contains(code) || !rules.exclude.contains(code))
+        .unwrap_or(true)
+}
+
+/// Line patterns and element selectors stripped from extracted articles
+struct BoilerplateRules {
+    patterns: Vec<Regex>,
+    selectors: Vec<Selector>,
+}
+
+impl BoilerplateRules {
+    /// Compile the configured rules, skipping any that don't parse;
+    /// `doctor` reports them.
+    fn compile(cfg: &BoilerplateConfig) -> BoilerplateRules {
+        BoilerplateRules {
+            patterns: cfg
+                .patterns
+                .iter()
+                .filter_map(|p| Regex::new(p).ok())
+                .collect(),
+            selectors: cfg
+                .selectors
+                .iter()
+                .filter_map(|s| Selector::parse(s).ok())
+                .collect(),
+        }
+    }
+}
+
+static BOILERPLATE: LazyLock<RwLock<BoilerplateRules>> =
+    LazyLock::new(|| RwLock::new(BoilerplateRules::compile(&BoilerplateConfig::default())));
+
+pub fn set_boilerplate(cfg: &BoilerplateConfig) {
+    if let Ok(mut rules) = BOILERPLATE.write() {
+        *rules = BoilerplateRules::compile(cfg);
+    }
+}
+
+fn is_boilerplate_line(line: &str) -> bool {
+    BOILERPLATE
+        .read()
+        .map(|rules| rules.patterns.iter().any(|re| re.is_match(line)))
+        .unwrap_or(false)
+}
+
+fn is_boilerplate_element(el: &ElementRef) -> bool {
+    BOILERPLATE
+        .read()
+        .map(|rules| rules.selectors.iter().any(|s| s.matches(el)))
+        .unwrap_or(false)
+}
+
+/// Listed tickers from the company table. When non-empty, extracted
+/// candidates must appear here, which drops acronyms like OJK or APBN.
+static KNOWN_TICKERS: LazyLock<RwLock<HashSet<String>>> =
+    LazyLock::new(|| RwLock::new(HashSet::new()));
+
+/// Company names and aliases paired with their ticker, longest first so
+/// "Astra Agro Lestari" wins over "Astra".
+static COMPANY_NAMES: LazyLock<RwLock<Vec<(String, String)>>> =
+    LazyLock::new(|| RwLock::new(Vec::new()));
+
+type TopicMap = BTreeMap<String, Vec<String>>;
+
+/// Commodity/FX topic tags and their lowercase trigger keywords
+static TOPICS: LazyLock<RwLock<TopicMap>> = LazyLock::new(|| RwLock::new(BTreeMap::new()));
+
+pub fn set_topics(topics: &TopicMap) {
+    if let Ok(mut current) = TOPICS.write() {
+        *current = topics.clone();
+    }
+}
+
+/// Default tags per source name, from `tags` in each source block
+static SOURCE_TAGS: LazyLock<RwLock<HashMap<String, Vec<String>>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+pub fn set_source_tags(sources: &[FeedSource]) {
+    if let Ok(mut current) = SOURCE_TAGS.write() {
+        *current = sources
+            .iter()
+            .filter(|s| !s.tags.is_empty())
+            .map(|s| (s.name.clone(), s.tags.clone()))
+            .collect();
+    }
+}
+
+/// Topic tags for an article: keyword matches in the title, then the
+/// source's default tags
+pub fn article_topics(title: &str, source: &str) -> Vec<String> {
+    let mut topics = extract_topics(title);
+    if let Some(tags) = SOURCE_TAGS.read().ok().and_then(|t| t.get(source).cloned()) {
+        for tag in tags {
+            if !topics.contains(&tag) {
+                topics.push(tag);
+            }
+        }
+    }
+    topics
+}
+
+/// Topic tags whose keywords appear in `text`, in dictionary order
+pub fn extract_topics(text: &str) -> Vec<String> {
+    let lower = text.to_lowercase();
+    let Ok(topics) = TOPICS.read() else {
+        return Vec::new();
+    };
+    topics
+        .iter()
+        .filter(|(_, keywords)| keywords.iter().any(|k| starts_word(&lower, k)))
+        .map(|(tag, _)| tag.clone())
+        .collect()
+}
+
+pub fn set_companies(companies: &[Company]) {
+    if let Ok(mut known) = KNOWN_TICKERS.write() {
+        *known = companies.iter().map(|c| c.ticker.clone()).collect();
+    }
+    if let Ok(mut names) = COMPANY_NAMES.write() {
+        *names = companies
+            .iter()
+            .flat_map(|c| {
+                std::iter::once(&c.name)
+                    .chain(c.aliases.iter())
+                    .map(|n| (n.clone(), c.ticker.clone()))
+            })
+            .filter(|(n, _)| !n.is_empty())
+            .collect();
+        names.sort_by_key(|(n, _)| std::cmp::Reverse(n.len()));
+    }
+}
+
+static PROXY: OnceLock<String> = OnceLock::new();
+
+/// Route every client built afterwards through `url`
+pub fn set_proxy(url: &str) {
+    let _ = PROXY.set(url.to_string());
+}
+
+static NETWORK: OnceLock<NetworkConfig> = OnceLock::new();
+
+/// Timeouts, redirects, User-Agent, and fetch concurrency for the rest of
+/// the process
+pub fn set_network(cfg: &NetworkConfig) {
+    let _ = NETWORK.set(cfg.clone());
+}
+
+fn network() -> NetworkConfig {
+    NETWORK.get().cloned().unwrap_or_default()
+}
+
+/// How long a looked-up host's addresses are reused
+const DNS_TTL: Duration = Duration::from_secs(300);
+
+/// Resolved addresses per host, with when they were looked up
+type DnsCache = HashMap<String, (Instant, Vec<SocketAddr>)>;
+
+/// Resolver that remembers addresses for DNS_TTL, since every refresh
+/// looks up the same few feed hosts again
+#[derive(Default)]
+struct CachingResolver {
+    cache: Arc<RwLock<DnsCache>>,
+}
+
+impl reqwest::dns::Resolve for CachingResolver {
+    fn resolve(&self, name: reqwest::dns::Name) -> reqwest::dns::Resolving {
+        let cache = self.cache.clone();
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let cached = cache.read().ok().and_then(|c| c.get(&host).cloned());
+            if let Some((_, addrs)) = cached.filter(|(at, _)| at.elapsed() < DNS_TTL) {
+                return Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs);
+            }
+            let addrs: Vec<SocketAddr> =
+                tokio::net::lookup_host((host.as_str(), 0)).await?.collect();
+            if let Ok(mut cache) = cache.write() {
+                cache.insert(host, (Instant::now(), addrs.clone()));
+            }
+            Ok(Box::new(addrs.into_iter()) as reqwest::dns::Addrs)
+        })
+    }
+}
+
+/// Build the shared HTTP client used for feeds and article pages. It is
+/// built once per run so refreshes reuse its pooled connections.
+pub fn build_client() -> reqwest::Result<reqwest::Client> {
+    let net = network();
+    let user_agent = net
+        .user_agent
+        .as_deref()
+        .unwrap_or("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/537.36");
+    let mut builder = reqwest::Client::builder()
+        .timeout(Duration::from_secs(net.timeout_secs))
+        .connect_timeout(Duration::from_secs(net.connect_timeout_secs))
+        .redirect(reqwest::redirect::Policy::limited(net.max_redirects))
+        .user_agent(user_agent)
+        // Keep connections to the feed hosts open between refreshes,
+        // pinging HTTP/2 ones so servers don't drop them while idle
+        .pool_idle_timeout(Duration::from_secs(net.idle_connection_secs))
+        .pool_max_idle_per_host(net.max_concurrent_fetches)
+        .tcp_keepalive(Duration::from_secs(60))
+        .http2_keep_alive_interval(Duration::from_secs(30))
+        .http2_keep_alive_while_idle(true)
+        .dns_resolver(Arc::new(CachingResolver::default()));
+    if let Some(url) = PROXY.get() {
+        builder = builder.proxy(reqwest::Proxy::all(url)?);
+    }
+    builder.build()
+}
+
+/// Why a response body wasn't read in full
+enum BodyError {
+    /// Passed the limit, in KB
+    TooLarge(u64),
+    Read(reqwest::Error),
+}
+
+impl BodyError {
+    fn message(&self) -> String {
+        match self {
+            BodyError::TooLarge(kb) => format!("Response over the {} KB size limit", kb),
+            BodyError::Read(e) => format!("Read error: {}", e),
+        }
+    }
+}
+
+/// Read a response body chunk by chunk, abandoning it as soon as it passes
+/// `limit_kb` (0 = no limit). A declared Content-Length over the limit is
+/// refused before anything is downloaded.
+async fn read_body(mut resp: reqwest::Response, limit_kb: u64) -> Result<Vec<u8>, BodyError> {
+    let limit = limit_kb.saturating_mul(1024);
+    let over = |len: u64| limit > 0 && len > limit;
+    if resp.content_length().is_some_and(over) {
+        return Err(BodyError::TooLarge(limit_kb));
+    }
+    let mut body = Vec::new();
+    while let Some(chunk) = resp.chunk().await.map_err(BodyError::Read)? {
+        body.extend_from_slice(&chunk);
+        if over(body.len() as u64) {
+            return Err(BodyError::TooLarge(limit_kb));
+        }
+    }
+    Ok(body)
+}
 
 /// Fetch and parse a single RSS feed source
 pub async fn fetch_feed(
     client: &reqwest::Client,
     source: &FeedSource,
 ) -> Result<Vec<Article>, String> {
-    let resp = client
-        .get(&source.url)
+    if let Some(xml) = demo::feed(&source.url) {
+        return parse_feed(xml.as_bytes(), source).map(demo::rebase);
+    }
+    let mut req = client.get(&source.url);
+    if let Some(ref ua) = source.user_agent {
+        req = req.header("User-Agent", ua);
+    }
+    let resp = req
         .send()
         .await
         .map_err(|e| format!("Network error for {}: {}", source.name, e))?;
 
-    let bytes = resp
-        .bytes()
+    let bytes = read_body(resp, network().max_feed_kb)
         .await
-        .map_err(|e| format!("Read error for {}: {}", source.name, e))?;
+        .map_err(|e| match e {
+            BodyError::TooLarge(kb) => {
+                format!("Size limit for {}: feed over {} KB", source.name, kb)
+            }
+            BodyError::Read(e) => format!("Read error for {}: {}", source.name, e),
+        })?;
+
+    parse_feed(&bytes, source)
+}
 
-    let feed = feed_rs::parser::parse(&bytes[..])
+/// Turn a source's RSS/Atom document into articles
+pub fn parse_feed(bytes: &[u8], source: &FeedSource) -> Result<Vec<Article>, String> {
+    let feed = feed_rs::parser::parse(bytes)
         .map_err(|e| format!("Parse error for {}: {}", source.name, e))?;
 
     let now = chrono::Utc::now().timestamp();
@@ -59,20 +379,32 @@ pub async fn fetch_feed(
                 .map(|dt| dt.timestamp())
                 .unwrap_or(now);
 
-            let tickers = extract_tickers(&title);
-            let sentiment = analyze_sentiment(&title);
+            let tickers = title_tickers(&title);
+            let sentiment_score = sentiment_score(&title);
+            let sentiment = sentiment_from_score(sentiment_score);
+            let event = classify_event(&title);
+            let topics = article_topics(&title, &source.name);
+            // Headlines alone rarely carry enough function words
+            let summary = entry.summary.map(|t| t.content).unwrap_or_default();
+            let language = Language::detect(&format!("{} {}", title, summary));
 
             Some(Article {
                 id: 0, // assigned by DB
-                title,
-                source: source.name.clone(),
-                url,
+                title: title.into(),
+                source: intern(&source.name),
+                url: url.into(),
                 tickers,
                 published_at,
                 fetched_at: now,
                 read: false,
                 bookmarked: false,
                 sentiment,
+                sentiment_score,
+                event,
+                topics,
+                word_count: None,
+                language,
+                keywords: Vec::new(),
             })
         })
         .collect();
@@ -80,24 +412,158 @@ pub async fn fetch_feed(
     Ok(articles)
 }
 
-/// Extract potential IDX ticker symbols from text
-/// Indonesian tickers are 4 uppercase letters (BBCA, TLKM, BBRI, etc.)
+/// Fetch a source and report its HTTP status and entry count, for diagnostics
+pub async fn check_source(
+    client: &reqwest::Client,
+    source: &FeedSource,
+) -> Result<(u16, usize), String> {
+    if let Some(xml) = demo::feed(&source.url) {
+        return Ok((200, parse_feed(xml.as_bytes(), source)?.len()));
+    }
+    let resp = client
+        .get(&source.url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    let status = resp.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status));
+    }
+
+    let bytes = read_body(resp, network().max_feed_kb)
+        .await
+        .map_err(|e| e.message())?;
+    let feed = feed_rs::parser::parse(&bytes[..]).map_err(|e| format!("Parse error: {}", e))?;
+    Ok((status.as_u16(), feed.entries.len()))
+}
+
+/// Extract potential ticker symbols from text using the configured
+/// patterns. By default these are IDX codes, 4 uppercase letters (BBCA,
+/// TLKM, BBRI, etc.); known company names ("Bank Mandiri" → BMRI) are
+/// matched as well
 fn extract_tickers(text: &str) -> Vec<String> {
-    // Common words to exclude (not tickers)
-    let exclude = [
-        "DARI", "YANG", "AKAN", "BISA", "JADI", "BARU", "HARI", "JUGA",
-        "OLEH", "PADA", "PARA", "LAGI", "BAIK", "BAGI", "KATA", "SAAT",
-        "TAPI", "MAKA", "DEMI", "AGAR", "BISA", "JIKA", "SOAL", "THIS",
-        "THAT", "WITH", "FROM", "HAVE", "BEEN", "WILL", "THEY", "WHAT",
-        "WHEN", "INTO", "THAN", "THEM", "EACH", "JUST", "ONLY", "ALSO",
-        "VERY", "MORE", "SOME", "OVER", "SUCH", "BACK", "YEAR", "MOST",
-    ];
+    let known = KNOWN_TICKERS.read().ok();
+    let mut tickers: Vec<String> = Vec::new();
+    if let Ok(rules) = TICKER_RULES.read() {
+        for (re, known_only) in &rules.patterns {
+            for caps in re.captures_iter(text) {
+                let Some(found) = caps.get(1).or(caps.get(0)) else {
+                    continue;
+                };
+                let candidate = found.as_str();
+                let allowed = rules.allow.contains(candidate);
+                if (!allowed && rules.exclude.contains(candidate))
+                    || tickers.iter().any(|t| t == candidate)
+                {
+                    continue;
+                }
+                if let Some(ref known) = known {
+                    // An unlisted code still counts where the text marks it
+                    // as one, so listings missing from the database aren't lost
+                    if *known_only
+                        && !allowed
+                        && !known.is_empty()
+                        && !known.contains(candidate)
+                        && !marked_as_code(text, found.start(), found.end())
+                    {
+                        continue;
+                    }
+                }
+                tickers.push(candidate.to_string());
+            }
+        }
+    }
 
-    TICKER_RE
-        .find_iter(text)
-        .map(|m| m.as_str().to_string())
-        .filter(|t| !exclude.contains(&t.as_str()))
-        .collect()
+    // Headlines often name the company without printing its code
+    if let Ok(names) = COMPANY_NAMES.read() {
+        let mut remaining = text.to_string();
+        for (name, ticker) in names.iter() {
+            let marked = MARKED_NAMES.contains(&name.as_str());
+            let mut found = false;
+            while let Some(pos) = find_word(&remaining, name) {
+                // Blank out the match so shorter aliases can't re-match it
+                remaining.replace_range(pos..pos + name.len(), &" ".repeat(name.len()));
+                found |= !marked || has_company_marker(text, pos, name.len());
+            }
+            if found && !tickers.contains(ticker) {
+                tickers.push(ticker.clone());
+            }
+        }
+    }
+    tickers
+}
+
+/// Tickers for a headline as a fetch would tag it, including the code of
+/// a company that is only just listing
+pub fn title_tickers(title: &str) -> Vec<String> {
+    let mut tickers = extract_tickers(title);
+    if classify_event(title) == Some(EventKind::Ipo) {
+        add_ipo_ticker(&mut tickers, title);
+    }
+    tickers
+}
+
+/// Digest of everything ticker extraction and sentiment scoring depend on,
+/// so stored articles can be re-analyzed when any of it changes
+pub fn analysis_fingerprint(tickers: &TickerConfig, companies: &[Company]) -> String {
+    use std::hash::{Hash, Hasher};
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    env!("CARGO_PKG_VERSION").hash(&mut hasher);
+    format!("{:?}", tickers).hash(&mut hasher);
+    format!("{:?}", companies).hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// IPO coverage names tickers that aren't listed yet, so pick those up
+/// separately from the known-ticker check
+fn add_ipo_ticker(tickers: &mut Vec<String>, text: &str) {
+    if let Some(ticker) = ipo::extract_ipo_ticker(text) {
+        if !tickers.contains(&ticker) {
+            tickers.push(ticker);
+        }
+    }
+}
+
+/// Words that introduce a ticker code, as in "saham DEWA" or "emiten DEWA"
+const CODE_CUES: &[&str] = &["saham", "emiten", "kode", "ticker", "stock", "shares"];
+
+/// Whether the code at `start..end` is written as one: "(DEWA)",
+/// "DEWA.JK", "IDX: DEWA", or after a cue word like "saham"
+fn marked_as_code(text: &str, start: usize, end: usize) -> bool {
+    let before = text[..start].trim_end();
+    let after = &text[end..];
+    if (before.ends_with('(') && after.starts_with(')')) || after.starts_with(".JK") {
+        return true;
+    }
+    if let Some(rest) = before.strip_suffix(':') {
+        return rest.trim_end().ends_with("IDX");
+    }
+    before
+        .rsplit(|c: char| !c.is_alphanumeric())
+        .next()
+        .is_some_and(|word| CODE_CUES.iter().any(|cue| word.eq_ignore_ascii_case(cue)))
+}
+
+/// Whether the name at `pos..pos + len` reads "PT <name>" or "<name> Tbk"
+fn has_company_marker(text: &str, pos: usize, len: usize) -> bool {
+    let before = text[..pos].trim_end();
+    let after = text[pos + len..].trim_start();
+    let pt = before
+        .strip_suffix("PT")
+        .is_some_and(|rest| !rest.ends_with(char::is_alphanumeric));
+    let tbk = after
+        .strip_prefix("Tbk")
+        .is_some_and(|rest| !rest.starts_with(char::is_alphanumeric));
+    pt || tbk
+}
+
+/// Find `needle` in `text` only where it stands as a whole word
+fn find_word(text: &str, needle: &str) -> Option<usize> {
+    text.match_indices(needle).map(|(pos, _)| pos).find(|&pos| {
+        let before = text[..pos].chars().next_back();
+        let after = text[pos + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
 }
 
 const USER_AGENTS: &[&str] = &[
@@ -106,27 +572,86 @@ const USER_AGENTS: &[&str] = &[
     "Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)",
 ];
 
-/// Fetch article body content from URL with retry and multiple User-Agents
-pub async fn fetch_article_content(
+/// What the reader keeps from an article's web page
+pub struct ArticlePage {
+    pub title: Option<String>,
+    pub content: String,
+    /// Lead image URL (og:image), if the page has one
+    pub image: Option<String>,
+    /// Links inside the article body
+    pub links: Vec<Link>,
+}
+
+/// Fetch an article page from URL with retry and multiple User-Agents:
+/// its text plus title, lead image, and body links. The article's source,
+/// when known, adds its own User-Agent and body selectors. All attempts
+/// together give up after `page_deadline_secs`.
+pub async fn fetch_article_page(
+    client: &reqwest::Client,
+    url: &str,
+    source: Option<&FeedSource>,
+) -> Result<ArticlePage, String> {
+    if let Some(page) = demo::article_page(url) {
+        return Ok(page);
+    }
+    let deadline = network().page_deadline_secs;
+    tokio::time::timeout(
+        Duration::from_secs(deadline),
+        try_article_page(client, url, source),
+    )
+    .await
+    .unwrap_or_else(|_| Err(format!("No response within {}s", deadline)))
+}
+
+async fn try_article_page(
     client: &reqwest::Client,
     url: &str,
-) -> Result<String, String> {
+    source: Option<&FeedSource>,
+) -> Result<ArticlePage, String> {
     let mut last_err = String::new();
+    let selectors = source
+        .map(|s| s.content_selectors.as_slice())
+        .unwrap_or(&[]);
+    let configured = network().user_agent;
+    let agents: Vec<&str> = source
+        .and_then(|s| s.user_agent.as_deref())
+        .into_iter()
+        .chain(configured.as_deref())
+        .chain(USER_AGENTS.iter().copied())
+        .collect();
 
-    for (attempt, ua) in USER_AGENTS.iter().enumerate() {
+    for (attempt, ua) in agents.iter().enumerate() {
         let result = client.get(url).header("User-Agent", *ua).send().await;
 
         match result {
             Ok(resp) => {
-                if let Ok(html_str) = resp.text().await {
-                    let content = extract_article_text(&html_str);
+                let body = match read_body(resp, network().max_page_kb).await {
+                    Ok(body) => Some(body),
+                    // Another User-Agent gets the same oversized page
+                    Err(e @ BodyError::TooLarge(_)) => return Err(e.message()),
+                    Err(_) => None,
+                };
+                if let Some(html_str) = body.map(|b| String::from_utf8_lossy(&b).into_owned()) {
+                    let image = extract_lead_image(&html_str, url);
+                    let title = extract_page_title(&Html::parse_document(&html_str));
+                    let (content, links) = extract_article(&html_str, url, selectors);
                     if !content.starts_with("Could not extract") {
-                        return Ok(content);
+                        return Ok(ArticlePage {
+                            title,
+                            content,
+                            image,
+                            links,
+                        });
                     }
                     // Try meta description fallback
                     if let Some(desc) = extract_meta_description(&html_str) {
                         if desc.len() > 50 {
-                            return Ok(desc);
+                            return Ok(ArticlePage {
+                                title,
+                                content: desc,
+                                image,
+                                links: Vec::new(),
+                            });
                         }
                     }
                     last_err = "Content extraction failed".to_string();
@@ -137,7 +662,7 @@ pub async fn fetch_article_content(
             }
         }
 
-        if attempt < USER_AGENTS.len() - 1 {
+        if attempt < agents.len() - 1 {
             tokio::time::sleep(Duration::from_millis(500)).await;
         }
     }
@@ -147,10 +672,17 @@ pub async fn fetch_article_content(
 
 /// Extract readable text from HTML using common article selectors
 fn extract_article_text(html: &str) -> String {
+    extract_article(html, "", &[]).0
+}
+
+/// Readable text plus the links inside it. Link URLs are resolved against
+/// `page_url`; links back to the page itself are dropped. `extra` selectors
+/// are tried before the built-in ones.
+fn extract_article(html: &str, page_url: &str, extra: &[String]) -> (String, Vec<Link>) {
     let document = Html::parse_document(html);
 
     // Try common article content selectors (most specific first)
-    let selectors = [
+    let builtin = [
         // Indonesian news sites
         ".detail__body-text",
         ".read__content",
@@ -178,22 +710,20 @@ fn extract_article_text(html: &str) -> String {
         "main .content",
         "main",
     ];
+    let selectors = extra.iter().map(String::as_str).chain(builtin);
 
-    for sel_str in &selectors {
+    for sel_str in selectors {
         if let Ok(selector) = Selector::parse(sel_str) {
-            let texts: Vec<String> = document
-                .select(&selector)
-                .flat_map(|el| {
-                    el.text()
-                        .map(|t| t.trim().to_string())
-                        .filter(|t| !t.is_empty())
-                })
-                .collect();
+            let mut texts: Vec<String> = Vec::new();
+            for el in document.select(&selector) {
+                element_lines(el, &mut texts);
+            }
 
             let combined = texts.join("\n");
             // Only use if we got meaningful content (more than just a title)
             if combined.len() > 100 {
-                return clean_article_text(&combined);
+                let links = collect_links(document.select(&selector), page_url);
+                return (clean_article_text(&combined), links);
             }
         }
     }
@@ -213,11 +743,207 @@ fn extract_article_text(html: &str) -> String {
             .collect();
 
         if !paragraphs.is_empty() {
-            return clean_article_text(&paragraphs.join("\n\n"));
+            let links = collect_links(document.select(&p_selector), page_url);
+            return (clean_article_text(&paragraphs.join("\n\n")), links);
+        }
+    }
+
+    (
+        "Could not extract article content. Press [o] to open in browser.".to_string(),
+        Vec::new(),
+    )
+}
+
+/// Most links kept per article
+const MAX_LINKS: usize = 40;
+
+/// Distinct http(s) links with text inside `elements`, in document order
+fn collect_links<'a>(elements: impl Iterator<Item = ElementRef<'a>>, page_url: &str) -> Vec<Link> {
+    let Ok(anchor) = Selector::parse("a[href]") else {
+        return Vec::new();
+    };
+    let base = reqwest::Url::parse(page_url).ok();
+    let mut links: Vec<Link> = Vec::new();
+    for el in elements {
+        for a in el.select(&anchor) {
+            let Some(href) = a.value().attr("href") else {
+                continue;
+            };
+            let url = match base {
+                Some(ref base) => base.join(href.trim()),
+                None => reqwest::Url::parse(href.trim()),
+            };
+            let Ok(mut url) = url else {
+                continue;
+            };
+            url.set_fragment(None);
+            let url = url.to_string();
+            let text = a.text().map(str::trim).collect::<Vec<_>>().join(" ");
+            let text = text.trim();
+            if text.is_empty()
+                || !url.starts_with("http")
+                || url == page_url
+                || links.iter().any(|l| l.url == url)
+            {
+                continue;
+            }
+            links.push(Link {
+                text: text.to_string(),
+                url,
+            });
+            if links.len() >= MAX_LINKS {
+                return links;
+            }
+        }
+    }
+    links
+}
+
+/// Text of an element in document order, one line per paragraph. Images
+/// are kept as "[Image: alt]" lines and figures as "[Image: caption]" so
+/// charts the text refers to don't leave silent gaps. Headings become
+/// "## " lines, blockquotes "> " lines, and bold/italic runs are wrapped in
+/// `**`/`*` for the reader to style.
+fn element_lines(el: ElementRef, out: &mut Vec<String>) {
+    let mut line = String::new();
+    walk_element(el, out, &mut line);
+    flush_line(out, &mut line);
+}
+
+fn walk_element(el: ElementRef, out: &mut Vec<String>, line: &mut String) {
+    for child in el.children() {
+        match child.value() {
+            Node::Text(text) => push_inline(line, text),
+            Node::Element(element) => {
+                let Some(child_el) = ElementRef::wrap(child) else {
+                    continue;
+                };
+                if is_boilerplate_element(&child_el) {
+                    continue;
+                }
+                match element.name() {
+                    "img" => {
+                        flush_line(out, line);
+                        out.push(image_placeholder(
+                            element.attr("alt").or_else(|| element.attr("title")),
+                        ));
+                    }
+                    "figure" if has_image(child_el) => {
+                        flush_line(out, line);
+                        let caption = first_text(child_el, "figcaption");
+                        let alt = child_el
+                            .descendants()
+                            .filter_map(ElementRef::wrap)
+                            .find(|e| e.value().name() == "img")
+                            .and_then(|img| img.value().attr("alt"));
+                        out.push(image_placeholder(caption.as_deref().or(alt)));
+                    }
+                    "script" | "style" | "noscript" => {}
+                    "br" => flush_line(out, line),
+                    "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                        flush_line(out, line);
+                        let text = collapse_whitespace(&child_el.text().collect::<String>());
+                        if !text.trim().is_empty() {
+                            out.push(format!("## {}", text.trim()));
+                        }
+                    }
+                    "blockquote" => {
+                        flush_line(out, line);
+                        let mut quoted = Vec::new();
+                        element_lines(child_el, &mut quoted);
+                        out.extend(quoted.into_iter().map(|l| format!("> {}", l)));
+                    }
+                    "strong" | "b" => push_emphasis(line, child_el, "**"),
+                    "em" | "i" => push_emphasis(line, child_el, "*"),
+                    "p" | "div" | "section" | "article" | "header" | "footer" | "aside"
+                    | "main" | "ul" | "ol" | "li" | "table" | "tr" | "pre" | "dl" | "dt"
+                    | "dd" | "figure" | "figcaption" | "hr" => {
+                        flush_line(out, line);
+                        walk_element(child_el, out, line);
+                        flush_line(out, line);
+                    }
+                    _ => walk_element(child_el, out, line),
+                }
+            }
+            _ => {}
         }
     }
+}
+
+/// Runs of whitespace as single spaces, keeping one at either end so
+/// inline pieces still join with the right spacing
+fn collapse_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut in_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !in_space {
+                out.push(' ');
+            }
+            in_space = true;
+        } else {
+            out.push(c);
+            in_space = false;
+        }
+    }
+    out
+}
+
+fn push_inline(line: &mut String, text: &str) {
+    let text = collapse_whitespace(text);
+    if line.ends_with(' ') {
+        line.push_str(text.trim_start());
+    } else {
+        line.push_str(&text);
+    }
+}
+
+/// Wrap the element's text in `marker`, keeping surrounding spaces outside
+fn push_emphasis(line: &mut String, el: ElementRef, marker: &str) {
+    let text = collapse_whitespace(&el.text().collect::<String>());
+    let inner = text.trim();
+    if inner.is_empty() {
+        push_inline(line, &text);
+        return;
+    }
+    if text.starts_with(' ') {
+        push_inline(line, " ");
+    }
+    line.push_str(&format!("{}{}{}", marker, inner, marker));
+    if text.ends_with(' ') {
+        line.push(' ');
+    }
+}
+
+fn flush_line(out: &mut Vec<String>, line: &mut String) {
+    let text = line.trim();
+    if !text.is_empty() {
+        out.push(text.to_string());
+    }
+    line.clear();
+}
+
+fn has_image(el: ElementRef) -> bool {
+    el.descendants()
+        .filter_map(ElementRef::wrap)
+        .any(|e| matches!(e.value().name(), "img" | "picture"))
+}
+
+/// Joined text of the first `tag` descendant, if it has any
+fn first_text(el: ElementRef, tag: &str) -> Option<String> {
+    el.descendants()
+        .filter_map(ElementRef::wrap)
+        .find(|e| e.value().name() == tag)
+        .map(|e| e.text().map(str::trim).collect::<Vec<_>>().join(" "))
+        .map(|t| t.trim().to_string())
+        .filter(|t| !t.is_empty())
+}
 
-    "Could not extract article content. Press [o] to open in browser.".to_string()
+fn image_placeholder(caption: Option<&str>) -> String {
+    match caption.map(str::trim).filter(|c| !c.is_empty()) {
+        Some(caption) => format!("[Image: {}]", caption),
+        None => "[Image]".to_string(),
+    }
 }
 
 /// Clean up extracted text
@@ -227,6 +953,9 @@ fn clean_article_text(text: &str) -> String {
 
     for line in text.lines() {
         let trimmed = line.trim();
+        if is_boilerplate_line(trimmed) {
+            continue;
+        }
         if trimmed.is_empty() {
             if !prev_empty {
                 lines.push(String::new());
@@ -246,6 +975,67 @@ fn clean_article_text(text: &str) -> String {
     lines.join("\n")
 }
 
+/// Absolute URL of the page's og:image / twitter:image
+fn extract_lead_image(html: &str, page_url: &str) -> Option<String> {
+    let document = Html::parse_document(html);
+    for selector_str in &[
+        "meta[property=\"og:image\"]",
+        "meta[name=\"twitter:image\"]",
+    ] {
+        let Ok(selector) = Selector::parse(selector_str) else {
+            continue;
+        };
+        let Some(src) = document
+            .select(&selector)
+            .next()
+            .and_then(|el| el.value().attr("content"))
+        else {
+            continue;
+        };
+        let base = reqwest::Url::parse(page_url).ok()?;
+        if let Ok(url) = base.join(src.trim()) {
+            return Some(url.to_string());
+        }
+    }
+    None
+}
+
+/// Download an image into `dir` as `<stem>.<ext>`, picking the extension
+/// from the response's content type
+pub async fn download_image(
+    client: &reqwest::Client,
+    url: &str,
+    dir: &std::path::Path,
+    stem: &str,
+) -> Result<std::path::PathBuf, String> {
+    let resp = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+    let ext = match resp
+        .headers()
+        .get(reqwest::header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+    {
+        t if t.contains("png") => "png",
+        t if t.contains("webp") => "webp",
+        t if t.contains("gif") => "gif",
+        _ => "jpg",
+    };
+    let bytes = read_body(resp, network().max_page_kb)
+        .await
+        .map_err(|e| e.message())?;
+    std::fs::create_dir_all(dir).map_err(|e| e.to_string())?;
+    let path = dir.join(format!("{}.{}", stem, ext));
+    std::fs::write(&path, &bytes).map_err(|e| e.to_string())?;
+    Ok(path)
+}
+
 /// Extract meta description as fallback content
 fn extract_meta_description(html: &str) -> Option<String> {
     let document = Html::parse_document(html);
@@ -267,17 +1057,127 @@ fn extract_meta_description(html: &str) -> Option<String> {
     None
 }
 
-/// Fetch all enabled feeds concurrently
+/// Extract the page title, preferring og:title over <title>
+fn extract_page_title(document: &Html) -> Option<String> {
+    if let Ok(selector) = Selector::parse("meta[property=\"og:title\"]") {
+        if let Some(content) = document
+            .select(&selector)
+            .next()
+            .and_then(|el| el.value().attr("content"))
+        {
+            let trimmed = content.trim();
+            if !trimmed.is_empty() {
+                return Some(trimmed.to_string());
+            }
+        }
+    }
+    let selector = Selector::parse("title").ok()?;
+    let title = document.select(&selector).next()?.text().collect::<String>();
+    let trimmed = title.trim();
+    (!trimmed.is_empty()).then(|| trimmed.to_string())
+}
+
+/// Extract the publish time from article:published_time, if present
+fn extract_published_time(document: &Html) -> Option<i64> {
+    let selector = Selector::parse("meta[property=\"article:published_time\"]").ok()?;
+    let content = document.select(&selector).next()?.value().attr("content")?;
+    chrono::DateTime::parse_from_rfc3339(content.trim())
+        .ok()
+        .map(|dt| dt.timestamp())
+}
+
+/// Fetch a single article page by URL and build an Article from its metadata,
+/// returning the extracted body alongside it. Used for manually added links.
+pub async fn fetch_single_article(
+    client: &reqwest::Client,
+    url: &str,
+) -> Result<(Article, String), String> {
+    let resp = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    // An error page would otherwise be stored as an article titled "404 Not Found"
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+    let html_str = read_body(resp, network().max_page_kb)
+        .await
+        .map(|b| String::from_utf8_lossy(&b).into_owned())
+        .map_err(|e| e.message())?;
+
+    let (title, published_at) = {
+        let document = Html::parse_document(&html_str);
+        (
+            extract_page_title(&document).ok_or("No title found on page")?,
+            extract_published_time(&document),
+        )
+    };
+
+    let mut content = extract_article_text(&html_str);
+    if content.starts_with("Could not extract") {
+        if let Some(desc) = extract_meta_description(&html_str) {
+            content = desc;
+        }
+    }
+    let now = chrono::Utc::now().timestamp();
+    let source = reqwest::Url::parse(url)
+        .ok()
+        .and_then(|u| u.host_str().map(|h| h.trim_start_matches("www.").to_string()))
+        .unwrap_or_else(|| "Manual".to_string());
+
+    let mut tickers = extract_tickers(&title);
+    for ticker in extract_tickers(&content) {
+        if !tickers.contains(&ticker) {
+            tickers.push(ticker);
+        }
+    }
+    let sentiment_score = sentiment_score(&title);
+    let sentiment = sentiment_from_score(sentiment_score);
+    let event = classify_event(&title);
+    if event == Some(EventKind::Ipo) {
+        add_ipo_ticker(&mut tickers, &title);
+        add_ipo_ticker(&mut tickers, &content);
+    }
+    let topics = article_topics(&title, &source);
+    let language = Language::detect(&content).or_else(|| Language::detect(&title));
+
+    let article = Article {
+        id: 0,
+        title: title.into(),
+        source: intern(&source),
+        url: url.into(),
+        tickers,
+        published_at: published_at.unwrap_or(now),
+        fetched_at: now,
+        read: false,
+        bookmarked: false,
+        sentiment,
+        sentiment_score,
+        event,
+        topics,
+        word_count: Some(word_count(&content)),
+        language,
+        keywords: Vec::new(),
+    };
+    Ok((article, content))
+}
+
+/// Fetch all enabled feeds concurrently, at most `max_concurrent_fetches`
+/// at a time
 pub async fn fetch_all_feeds(
     client: &reqwest::Client,
     sources: &[FeedSource],
 ) -> Vec<(String, Result<Vec<Article>, String>)> {
     let mut handles = Vec::new();
+    let permits = Arc::new(Semaphore::new(network().max_concurrent_fetches));
 
     for source in sources.iter().filter(|s| s.enabled) {
         let client = client.clone();
         let source = source.clone();
+        let permits = permits.clone();
         handles.push(tokio::spawn(async move {
+            let _permit = permits.acquire().await;
             let name = source.name.clone();
             let result = fetch_feed(&client, &source).await;
             (name, result)
@@ -293,3 +1193,53 @@ pub async fn fetch_all_feeds(
 
     results
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::companies;
+
+    fn tickers(title: &str) -> Vec<String> {
+        set_companies(&companies::bundled());
+        extract_tickers(title)
+    }
+
+    #[test]
+    fn unlisted_codes_need_to_be_written_as_codes() {
+        assert!(tickers("OJK dan BPJS Terbitkan Aturan Baru").is_empty());
+        assert_eq!(tickers("BBCA Cetak Laba Rp 40 Triliun"), ["BBCA"]);
+        assert_eq!(tickers("Saham DEWA Melonjak 20%"), ["DEWA"]);
+        assert_eq!(tickers("Darma Henwa (DEWA) Raih Kontrak Baru"), ["DEWA"]);
+        assert_eq!(tickers("Kontrak Baru Angkat DEWA.JK"), ["DEWA"]);
+    }
+
+    #[test]
+    fn marked_names_need_a_company_marker() {
+        assert!(tickers("Harga Timah Dunia Melonjak ke Level Tertinggi").is_empty());
+        assert!(tickers("Ekspor Timah Batangan Turun 10%").is_empty());
+        assert_eq!(tickers("PT Timah Catat Laba Kuartal III"), ["TINS"]);
+        assert_eq!(tickers("Timah Tbk Bagikan Dividen Rp 50 per Saham"), ["TINS"]);
+    }
+
+    #[test]
+    fn ambiguous_aliases_are_not_tickers() {
+        assert!(tickers("Surge in Coal Prices Lifts Miners").is_empty());
+        assert!(tickers("Penjualan Kaos Ukuran XL Melonjak").is_empty());
+        assert!(tickers("MNC Group Gelar Konser Akhir Tahun").is_empty());
+        assert_eq!(tickers("XL Axiata Luncurkan Paket Data Baru"), ["EXCL"]);
+        assert_eq!(tickers("PT Surge Raih Kontrak Baru"), ["WIFI"]);
+    }
+
+    #[test]
+    fn everyday_words_are_not_companies() {
+        assert!(tickers("Koperasi Usaha Mandiri Bantu Petani").is_empty());
+        assert!(tickers("Jago Masak Rumahan Raup Omzet Besar").is_empty());
+        assert!(tickers("Vale Brasil Pangkas Produksi Bijih Besi").is_empty());
+        assert!(tickers("Mega Proyek Ibu Kota Nusantara Dilanjutkan").is_empty());
+        assert!(tickers("Permintaan Semen Indonesia Turun 5%").is_empty());
+        assert!(tickers("Pembangunan Perumahan Subsidi Dipercepat").is_empty());
+        assert_eq!(tickers("Bank Mandiri Salurkan Kredit Rp 1.600 Triliun"), ["BMRI"]);
+        assert_eq!(tickers("PT Semen Indonesia Naikkan Harga"), ["SMGR"]);
+        assert_eq!(tickers("Pembangunan Perumahan Tbk Raih Kontrak Tol"), ["PTPP"]);
+    }
+}
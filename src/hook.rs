@@ -0,0 +1,143 @@
+use crate::model::{classify_alert, Article};
+use std::io::Write;
+use std::process::{Child, Command, Stdio};
+use std::thread::JoinHandle;
+
+/// Run the user's `on_new_article` command for a freshly inserted article.
+/// Article fields are exposed as STOCKNEWS_* env vars (STOCKNEWS_ALERT is
+/// "suspension", "uma", "special_monitoring", or "margin" for exchange
+/// notices) and the full article is written as JSON to the command's
+/// stdin. The child is reaped on a background thread so the UI never
+/// waits on it; headless callers can join the returned handle before
+/// exiting.
+pub fn run_new_article_hook(cmd: &str, article: &Article) -> Option<JoinHandle<()>> {
+    let json = serde_json::to_string(article).unwrap_or_default();
+
+    let child = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .env("STOCKNEWS_TITLE", &*article.title)
+        .env("STOCKNEWS_SOURCE", &*article.source)
+        .env("STOCKNEWS_URL", &*article.url)
+        .env("STOCKNEWS_TICKERS", article.tickers.join(","))
+        .env("STOCKNEWS_PUBLISHED_AT", article.published_at.to_string())
+        .env("STOCKNEWS_SENTIMENT", article.sentiment.as_str())
+        .env(
+            "STOCKNEWS_ALERT",
+            classify_alert(&article.title).map_or("", |a| a.as_str()),
+        )
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn();
+
+    let mut child = child.ok()?;
+    Some(std::thread::spawn(move || {
+        if let Some(mut stdin) = child.stdin.take() {
+            let _ = stdin.write_all(json.as_bytes());
+        }
+        let _ = child.wait();
+    }))
+}
+
+/// Start the user's `tts_command` reading `text` from stdin. The command
+/// runs in its own process group so stopping it also stops anything it
+/// piped into (e.g. `piper ... | aplay`).
+pub fn start_speech(cmd: &str, text: &str) -> Option<Child> {
+    let mut command = Command::new("sh");
+    command
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null());
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.process_group(0);
+    }
+
+    let mut child = command.spawn().ok()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        let text = text.to_string();
+        std::thread::spawn(move || {
+            let _ = stdin.write_all(text.as_bytes());
+        });
+    }
+    Some(child)
+}
+
+/// Stop a playback started by `start_speech` and reap it
+pub fn stop_speech(mut child: Child) {
+    #[cfg(unix)]
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGTERM);
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Copy `text` to the clipboard with `cmd` (e.g. "wl-copy", "xclip -sel c",
+/// "pbcopy") reading stdin, or with an OSC 52 escape when no command is
+/// set, which most terminals honor even over SSH
+pub fn copy_text(cmd: Option<&str>, text: &str) -> Result<(), String> {
+    let Some(cmd) = cmd else {
+        let mut stdout = std::io::stdout();
+        write!(stdout, "\x1b]52;c;{}\x07", base64(text.as_bytes()))
+            .and_then(|_| stdout.flush())
+            .map_err(|e| e.to_string())?;
+        return Ok(());
+    };
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| e.to_string())?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(text.as_bytes())
+            .map_err(|e| e.to_string())?;
+    }
+    let status = child.wait().map_err(|e| e.to_string())?;
+    if !status.success() {
+        return Err(format!("{} exited with {}", cmd, status));
+    }
+    Ok(())
+}
+
+/// Run `cmd` in the foreground with `text` on stdin and the terminal as
+/// its stdout, for pagers and renderers like `glow -`
+pub fn pipe_text(cmd: &str, text: &str) -> std::io::Result<std::process::ExitStatus> {
+    let mut child = Command::new("sh")
+        .arg("-c")
+        .arg(cmd)
+        .stdin(Stdio::piped())
+        .spawn()?;
+    if let Some(mut stdin) = child.stdin.take() {
+        // A command that exits without reading everything closes the pipe
+        let _ = stdin.write_all(text.as_bytes());
+    }
+    child.wait()
+}
+
+fn base64(data: &[u8]) -> String {
+    const ALPHABET: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, b)| n | (*b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,66 @@
+use crate::db::Db;
+use crate::hook;
+use crate::model::{alert_for, holding_weight, watchlist_hit, AlertKind, Article, Holding};
+use crate::webhook;
+use std::thread::JoinHandle;
+
+/// Who hears about a newly stored article, from the TUI or a headless fetch
+pub struct Notify<'a> {
+    pub watchlist: &'a [String],
+    pub holdings: &'a [Holding],
+    pub on_new_article: Option<&'a str>,
+    pub on_holding_article: Option<&'a str>,
+    /// Build a webhook payload for watchlist and alert articles
+    pub webhooks: bool,
+}
+
+/// What storing a new article set off
+pub struct Stored {
+    pub id: Option<i64>,
+    pub alert: Option<AlertKind>,
+    pub holding: bool,
+    pub payload: Option<Vec<u8>>,
+    /// Hook commands still running
+    pub hooks: Vec<JoinHandle<()>>,
+}
+
+/// Insert an article and, when it is new, record its alert and run the
+/// hooks. Returns None for an article already stored.
+pub fn store(db: &Db, article: &Article, notify: &Notify) -> Option<Stored> {
+    if !matches!(db.insert_article(article), Ok(true)) {
+        return None;
+    }
+    let id = db.article_id_by_url(&article.url).ok().flatten();
+    let hit = watchlist_hit(article, notify.watchlist);
+    let holding = holding_weight(article, notify.holdings).is_some();
+    let alert = alert_for(article, notify.watchlist, notify.holdings);
+
+    // Kept for the TUI's alert banner and Alerts view
+    if let (Some(kind), Some(id)) = (alert, id) {
+        let _ = db.record_alert(kind.as_str(), id);
+    }
+
+    let mut hooks = Vec::new();
+    let mut payload = None;
+    if hit || alert.is_some() {
+        if let Some(cmd) = notify.on_new_article {
+            hooks.extend(hook::run_new_article_hook(cmd, article));
+        }
+        if notify.webhooks {
+            payload = Some(webhook::payload(article, alert));
+        }
+    }
+    if holding {
+        if let Some(cmd) = notify.on_holding_article {
+            hooks.extend(hook::run_new_article_hook(cmd, article));
+        }
+    }
+
+    Some(Stored {
+        id,
+        alert,
+        holding,
+        payload,
+        hooks,
+    })
+}
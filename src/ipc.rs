@@ -0,0 +1,139 @@
+use serde_json::{json, Value};
+use std::path::{Path, PathBuf};
+use tokio::sync::{mpsc, oneshot};
+
+/// JSON-RPC error codes
+pub const PARSE_ERROR: i64 = -32700;
+pub const INVALID_REQUEST: i64 = -32600;
+pub const METHOD_NOT_FOUND: i64 = -32601;
+pub const INVALID_PARAMS: i64 = -32602;
+/// The request was understood but couldn't be carried out
+pub const FAILED: i64 = -32000;
+
+/// A method result, or an error code and message
+pub type Reply = Result<Value, (i64, String)>;
+
+/// A call from a client, handled on the UI loop
+pub struct Request {
+    pub method: String,
+    pub params: Value,
+    pub reply: oneshot::Sender<Reply>,
+}
+
+/// The listening socket; its file is removed when this is dropped
+pub struct Server {
+    path: PathBuf,
+}
+
+impl Drop for Server {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+/// Listen on `path`, passing each request to `tx`. Clients write one
+/// JSON-RPC 2.0 request per line and get one response line back. A socket
+/// left behind by a crashed instance is replaced; one still answering is not.
+#[cfg(unix)]
+pub fn start(
+    rt: &tokio::runtime::Runtime,
+    path: &Path,
+    tx: mpsc::Sender<Request>,
+) -> Result<Server, String> {
+    use std::os::unix::fs::PermissionsExt;
+
+    if path.exists() {
+        if std::os::unix::net::UnixStream::connect(path).is_ok() {
+            return Err(format!("{} is in use by another instance", path.display()));
+        }
+        let _ = std::fs::remove_file(path);
+    }
+    // Anyone who can connect can drive the app, so only this user may. The
+    // umask keeps the socket private from the moment it exists, rather than
+    // open to others until the permissions are tightened.
+    let listener = {
+        let _guard = rt.enter();
+        let old_mask = unsafe { libc::umask(0o177) };
+        let bound = tokio::net::UnixListener::bind(path);
+        unsafe { libc::umask(old_mask) };
+        bound.map_err(|e| format!("{}: {}", path.display(), e))?
+    };
+    if let Err(e) = std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)) {
+        let _ = std::fs::remove_file(path);
+        return Err(format!("{}: {}", path.display(), e));
+    }
+
+    rt.spawn(async move {
+        while let Ok((stream, _)) = listener.accept().await {
+            tokio::spawn(serve(stream, tx.clone()));
+        }
+    });
+    Ok(Server {
+        path: path.to_path_buf(),
+    })
+}
+
+#[cfg(not(unix))]
+pub fn start(
+    _rt: &tokio::runtime::Runtime,
+    _path: &Path,
+    _tx: mpsc::Sender<Request>,
+) -> Result<Server, String> {
+    Err("the control socket needs Unix domain sockets".to_string())
+}
+
+#[cfg(unix)]
+async fn serve(stream: tokio::net::UnixStream, tx: mpsc::Sender<Request>) {
+    use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+
+    let (read, mut write) = stream.into_split();
+    let mut lines = BufReader::new(read).lines();
+    while let Ok(Some(line)) = lines.next_line().await {
+        if line.trim().is_empty() {
+            continue;
+        }
+        let response = respond(&line, &tx).await;
+        let mut out = response.to_string();
+        out.push('\n');
+        if write.write_all(out.as_bytes()).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Hand one request line to the UI loop and build the response object
+#[cfg(unix)]
+async fn respond(line: &str, tx: &mpsc::Sender<Request>) -> Value {
+    let request: Value = match serde_json::from_str(line) {
+        Ok(request) => request,
+        Err(e) => return error_response(Value::Null, PARSE_ERROR, e.to_string()),
+    };
+    let id = request.get("id").cloned().unwrap_or(Value::Null);
+    let Some(method) = request.get("method").and_then(Value::as_str) else {
+        return error_response(id, INVALID_REQUEST, "missing method".to_string());
+    };
+
+    let (reply, rx) = oneshot::channel();
+    let call = Request {
+        method: method.to_string(),
+        params: request.get("params").cloned().unwrap_or(Value::Null),
+        reply,
+    };
+    if tx.send(call).await.is_err() {
+        return error_response(id, FAILED, "shutting down".to_string());
+    }
+    match rx.await {
+        Ok(Ok(result)) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+        Ok(Err((code, message))) => error_response(id, code, message),
+        Err(_) => error_response(id, FAILED, "no reply".to_string()),
+    }
+}
+
+#[cfg(unix)]
+fn error_response(id: Value, code: i64, message: String) -> Value {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message },
+    })
+}
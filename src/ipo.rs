@@ -0,0 +1,142 @@
+use crate::model::Article;
+use regex::Regex;
+use std::collections::HashMap;
+use std::sync::LazyLock;
+
+/// How far along a listing is, judged from its newest coverage
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum IpoStage {
+    Planned,
+    Bookbuilding,
+    Offering,
+    Listed,
+}
+
+impl IpoStage {
+    pub fn label(&self) -> &str {
+        match self {
+            IpoStage::Planned => "Planned",
+            IpoStage::Bookbuilding => "Bookbuilding",
+            IpoStage::Offering => "Offering",
+            IpoStage::Listed => "Listed",
+        }
+    }
+
+    fn from_text(text: &str) -> IpoStage {
+        let lower = text.to_lowercase();
+        let any = |words: &[&str]| words.iter().any(|w| lower.contains(w));
+        if any(&[
+            "melantai",
+            "resmi listing",
+            "pencatatan perdana",
+            "listing perdana",
+            "debut",
+        ]) {
+            IpoStage::Listed
+        } else if any(&[
+            "masa penawaran",
+            "e-ipo",
+            "penjatahan",
+            "oversubscribed",
+            "offering period",
+        ]) {
+            IpoStage::Offering
+        } else if any(&["book building", "bookbuilding", "harga penawaran"]) {
+            IpoStage::Bookbuilding
+        } else {
+            IpoStage::Planned
+        }
+    }
+}
+
+/// One company in the IPO pipeline with its newest article
+pub struct Listing {
+    pub ticker: Option<String>,
+    pub company: Option<String>,
+    pub stage: IpoStage,
+    pub articles: usize,
+    pub latest: Article,
+}
+
+impl Listing {
+    pub fn name(&self) -> String {
+        match (&self.company, &self.ticker) {
+            (Some(c), Some(t)) => format!("{} ({})", c, t),
+            (Some(c), None) => c.clone(),
+            (None, Some(t)) => t.clone(),
+            (None, None) => "-".to_string(),
+        }
+    }
+}
+
+static CODE_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i:kode\s*(?:saham\s*|emiten\s*|ticker\s*)?:?\s*|ticker\s+)([A-Z]{4})\b").unwrap()
+});
+static COMPANY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\bPT\s+((?:[A-Z][\w&.-]*\s+){0,5}?[A-Z][\w&.-]*)\s+(?:\(Persero\)\s+)?Tbk\b")
+        .unwrap()
+});
+
+/// Prospective ticker named in IPO coverage ("kode saham ABCD"). These
+/// aren't in the company table yet, so normal extraction drops them.
+pub fn extract_ipo_ticker(text: &str) -> Option<String> {
+    CODE_RE.captures(text).map(|c| c[1].to_string())
+}
+
+/// Company name from a "PT ... Tbk" mention
+pub fn extract_company(text: &str) -> Option<String> {
+    COMPANY_RE.captures(text).map(|c| c[1].trim().to_string())
+}
+
+/// Group IPO articles (newest first) into one entry per company. Listings
+/// still in progress come first, then completed ones, newest coverage first.
+pub fn listings(articles: Vec<Article>) -> Vec<Listing> {
+    let mut by_key: HashMap<String, Listing> = HashMap::new();
+    let mut order: Vec<String> = Vec::new();
+
+    for article in articles {
+        let ticker = article
+            .tickers
+            .first()
+            .cloned()
+            .or_else(|| extract_ipo_ticker(&article.title));
+        let company = extract_company(&article.title);
+        let key = ticker
+            .clone()
+            .or_else(|| company.as_ref().map(|c| c.to_lowercase()))
+            .unwrap_or_else(|| article.url.to_string());
+
+        match by_key.get_mut(&key) {
+            Some(listing) => {
+                listing.articles += 1;
+                if listing.company.is_none() {
+                    listing.company = company;
+                }
+            }
+            None => {
+                order.push(key.clone());
+                by_key.insert(
+                    key,
+                    Listing {
+                        ticker,
+                        company,
+                        stage: IpoStage::from_text(&article.title),
+                        articles: 1,
+                        latest: article,
+                    },
+                );
+            }
+        }
+    }
+
+    let mut result: Vec<Listing> = order
+        .into_iter()
+        .filter_map(|k| by_key.remove(&k))
+        .collect();
+    result.sort_by(|a, b| {
+        (a.stage == IpoStage::Listed)
+            .cmp(&(b.stage == IpoStage::Listed))
+            .then_with(|| b.latest.published_at.cmp(&a.latest.published_at))
+    });
+    result
+}
@@ -0,0 +1,132 @@
+use crate::config::KeysConfig;
+use crate::model::ViewMode;
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+/// How long a half-typed sequence waits for its next key before the keys
+/// are handled on their own
+pub const SEQUENCE_TIMEOUT: Duration = Duration::from_millis(1000);
+
+/// A key with the modifiers that matter for matching
+type Key = (KeyCode, KeyModifiers);
+
+/// A sequence and the keys it replays
+type Binding = (Vec<Key>, Vec<Key>);
+
+/// Outcome of feeding the keys typed so far to the keymap
+pub enum Step {
+    /// A longer binding starts with these keys; wait for more
+    Pending,
+    /// Handle these keys as if they were typed
+    Replay(Vec<KeyEvent>),
+}
+
+/// Key sequences from `[keys]`, global and per view
+#[derive(Debug, Default)]
+pub struct Keymap {
+    global: Vec<Binding>,
+    views: HashMap<String, Vec<Binding>>,
+}
+
+impl Keymap {
+    /// Build the keymap, skipping bindings that don't parse. Problems are
+    /// returned as messages for `doctor`.
+    pub fn from_config(cfg: &KeysConfig) -> (Keymap, Vec<String>) {
+        let mut errors = Vec::new();
+        let mut parse_all = |section: &str, bindings: &BTreeMap<String, String>| {
+            let mut parsed = Vec::new();
+            for (from, to) in bindings {
+                match (parse_keys(from), parse_keys(to)) {
+                    (Some(from), Some(to)) => parsed.push((from, to)),
+                    _ => errors.push(format!("[keys.{}] \"{}\" = \"{}\"", section, from, to)),
+                }
+            }
+            parsed
+        };
+        let global = parse_all("global", &cfg.global);
+        let views = cfg
+            .views
+            .iter()
+            .map(|(view, bindings)| (view.to_lowercase(), parse_all(view, bindings)))
+            .collect();
+        (Keymap { global, views }, errors)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.global.is_empty() && self.views.values().all(|b| b.is_empty())
+    }
+
+    /// Match the keys typed so far. View bindings win over global ones,
+    /// and an unmatched sequence replays its own keys.
+    pub fn step(&self, view: ViewMode, typed: &[KeyEvent]) -> Step {
+        let typed: Vec<Key> = typed.iter().map(key_of).collect();
+        let view_bindings = self.views.get(&view.label().to_lowercase());
+        let bindings = view_bindings
+            .into_iter()
+            .flatten()
+            .chain(self.global.iter());
+
+        let mut longer = false;
+        for (from, to) in bindings {
+            if *from == typed {
+                return Step::Replay(to.iter().map(|&(c, m)| KeyEvent::new(c, m)).collect());
+            }
+            longer |= from.len() > typed.len() && from.starts_with(&typed);
+        }
+        if longer {
+            Step::Pending
+        } else {
+            Step::Replay(typed.iter().map(|&(c, m)| KeyEvent::new(c, m)).collect())
+        }
+    }
+}
+
+/// Shift is part of the character itself ('G' vs 'g'), so it's dropped
+fn key_of(event: &KeyEvent) -> Key {
+    let mut modifiers = event.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT);
+    if !matches!(event.code, KeyCode::Char(_)) {
+        modifiers |= event.modifiers & KeyModifiers::SHIFT;
+    }
+    (event.code, modifiers)
+}
+
+/// Parse a space-separated key list such as "g b", "C-d", or "Enter"
+fn parse_keys(spec: &str) -> Option<Vec<Key>> {
+    let keys: Option<Vec<Key>> = spec.split_whitespace().map(parse_key).collect();
+    keys.filter(|k| !k.is_empty())
+}
+
+fn parse_key(token: &str) -> Option<Key> {
+    let (modifiers, name) = if let Some(rest) = token.strip_prefix("C-") {
+        (KeyModifiers::CONTROL, rest)
+    } else if let Some(rest) = token.strip_prefix("A-") {
+        (KeyModifiers::ALT, rest)
+    } else {
+        (KeyModifiers::NONE, token)
+    };
+    let code = match name {
+        "Enter" => KeyCode::Enter,
+        "Esc" => KeyCode::Esc,
+        "Tab" => KeyCode::Tab,
+        "Space" => KeyCode::Char(' '),
+        "Backspace" => KeyCode::Backspace,
+        "Up" => KeyCode::Up,
+        "Down" => KeyCode::Down,
+        "Left" => KeyCode::Left,
+        "Right" => KeyCode::Right,
+        "PageUp" => KeyCode::PageUp,
+        "PageDown" => KeyCode::PageDown,
+        "Home" => KeyCode::Home,
+        "End" => KeyCode::End,
+        _ => {
+            let mut chars = name.chars();
+            let c = chars.next()?;
+            if chars.next().is_some() {
+                return None;
+            }
+            KeyCode::Char(c)
+        }
+    };
+    Some((code, modifiers))
+}
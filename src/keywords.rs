@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet};
+
+/// Keywords kept per article
+pub const TOP_KEYWORDS: usize = 5;
+
+/// Function words in Indonesian and English that carry no topic. Terms
+/// common to all market news (saham, rupiah) are left to the IDF weight.
+const STOPWORDS: &[&str] = &[
+    // Indonesian
+    "yang",
+    "dengan",
+    "untuk",
+    "pada",
+    "dalam",
+    "juga",
+    "akan",
+    "tidak",
+    "oleh",
+    "atau",
+    "adalah",
+    "sebagai",
+    "karena",
+    "bahwa",
+    "tersebut",
+    "sudah",
+    "telah",
+    "masih",
+    "lebih",
+    "menjadi",
+    "kata",
+    "saat",
+    "hingga",
+    "sebesar",
+    "tahun",
+    "bulan",
+    "hari",
+    "para",
+    "bisa",
+    "dapat",
+    "kami",
+    "mereka",
+    "namun",
+    "serta",
+    "secara",
+    "antara",
+    "setelah",
+    "sebelum",
+    "ketika",
+    "seperti",
+    "hanya",
+    "jika",
+    "maka",
+    "agar",
+    "bagi",
+    "baru",
+    "lalu",
+    "sejak",
+    "terhadap",
+    "kepada",
+    "yakni",
+    "yaitu",
+    "selain",
+    "sementara",
+    "sehingga",
+    "tetapi",
+    "belum",
+    "harus",
+    "ujar",
+    "ungkap",
+    "jelas",
+    "menurut",
+    "kembali",
+    "sekitar",
+    "sendiri",
+    "lain",
+    "banyak",
+    "beberapa",
+    "semua",
+    "setiap",
+    "senin",
+    "selasa",
+    "rabu",
+    "kamis",
+    "jumat",
+    "sabtu",
+    "minggu",
+    // English
+    "that",
+    "with",
+    "from",
+    "this",
+    "have",
+    "been",
+    "will",
+    "they",
+    "said",
+    "were",
+    "which",
+    "their",
+    "would",
+    "there",
+    "about",
+    "also",
+    "more",
+    "than",
+    "into",
+    "after",
+    "over",
+    "what",
+    "when",
+    "while",
+    "other",
+    "some",
+    "such",
+    "only",
+    "year",
+    "says",
+    "could",
+];
+
+/// Lowercase words worth counting: four letters or more, not numbers,
+/// not stopwords
+fn tokens(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| w.chars().count() >= 4)
+        .filter(|w| w.chars().next().is_some_and(|c| c.is_alphabetic()))
+        .map(|w| w.to_lowercase())
+        .filter(|w| !STOPWORDS.contains(&w.as_str()))
+}
+
+/// Distinct candidate terms in `text`, for document frequency counts
+pub fn terms(text: &str) -> HashSet<String> {
+    tokens(text).collect()
+}
+
+/// Top terms of `text` by TF-IDF. `df` returns how many stored bodies
+/// contain a term and `docs` is the number of stored bodies. Terms seen
+/// once in the text are skipped as noise.
+pub fn extract(text: &str, mut df: impl FnMut(&str) -> usize, docs: usize) -> Vec<String> {
+    let mut counts: HashMap<String, usize> = HashMap::new();
+    let mut total = 0usize;
+    for token in tokens(text) {
+        *counts.entry(token).or_insert(0) += 1;
+        total += 1;
+    }
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(String, f64)> = counts
+        .into_iter()
+        .filter(|(_, n)| *n >= 2)
+        .map(|(term, n)| {
+            let tf = n as f64 / total as f64;
+            // Smoothed so a tiny corpus still ranks by frequency
+            let idf = ((1 + docs) as f64 / (1 + df(&term)) as f64).ln() + 1.0;
+            (term, tf * idf)
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    scored
+        .into_iter()
+        .take(TOP_KEYWORDS)
+        .map(|(term, _)| term)
+        .collect()
+}
@@ -0,0 +1,129 @@
+use crate::config::LlmConfig;
+use std::time::Duration;
+
+/// Longest article body sent to the model, in characters
+const MAX_INPUT_CHARS: usize = 12_000;
+
+const SUMMARY_PROMPT: &str = "Summarize this Indonesian stock market news article in exactly 3 short bullet points for an investor. Reply with the 3 bullets only, one per line, each starting with \"- \".";
+
+/// Chat API the summarizer talks to
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Provider {
+    /// `/v1/chat/completions`, as served by OpenAI and most proxies
+    OpenAi,
+    /// Ollama's local `/api/chat`
+    Ollama,
+}
+
+/// A configured language model backend
+#[derive(Debug, Clone)]
+pub struct Llm {
+    pub provider: Provider,
+    pub url: String,
+    pub model: String,
+    pub token: Option<String>,
+    /// Replaces the shared client's timeout, which is too short for a model
+    pub timeout: Duration,
+}
+
+impl Llm {
+    /// Build the configured backend, or None when no provider is set
+    pub fn from_config(cfg: &LlmConfig) -> Option<Llm> {
+        let provider = match cfg.provider.as_deref()? {
+            "openai" => Provider::OpenAi,
+            "ollama" => Provider::Ollama,
+            _ => return None,
+        };
+        let (url, model) = match provider {
+            Provider::OpenAi => ("https://api.openai.com/v1/chat/completions", "gpt-4o-mini"),
+            Provider::Ollama => ("http://localhost:11434/api/chat", "llama3.1"),
+        };
+        Some(Llm {
+            provider,
+            url: cfg.url.clone().unwrap_or_else(|| url.to_string()),
+            model: cfg.model.clone().unwrap_or_else(|| model.to_string()),
+            token: cfg
+                .token_env
+                .as_deref()
+                .and_then(|var| std::env::var(var).ok()),
+            timeout: Duration::from_secs(cfg.timeout_secs.max(1)),
+        })
+    }
+
+    /// Send one system + user message pair and return the reply text
+    pub async fn complete(
+        &self,
+        client: &reqwest::Client,
+        system: &str,
+        user: &str,
+    ) -> Result<String, String> {
+        let messages = serde_json::json!([
+            { "role": "system", "content": system },
+            { "role": "user", "content": user },
+        ]);
+        let body = match self.provider {
+            Provider::OpenAi => serde_json::json!({
+                "model": self.model,
+                "messages": messages,
+                "temperature": 0.2,
+            }),
+            Provider::Ollama => serde_json::json!({
+                "model": self.model,
+                "messages": messages,
+                "stream": false,
+            }),
+        };
+        let mut req = client.post(&self.url).json(&body).timeout(self.timeout);
+        if let Some(ref token) = self.token {
+            req = req.bearer_auth(token);
+        }
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("HTTP {}", resp.status()));
+        }
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Parse error: {}", e))?;
+        let reply = match self.provider {
+            Provider::OpenAi => json["choices"][0]["message"]["content"].as_str(),
+            Provider::Ollama => json["message"]["content"].as_str(),
+        };
+        reply
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| "Response has no message content".to_string())
+    }
+
+    /// Three-bullet summary of an article, one bullet per line
+    pub async fn summarize(
+        &self,
+        client: &reqwest::Client,
+        title: &str,
+        content: &str,
+    ) -> Result<String, String> {
+        let body: String = content.chars().take(MAX_INPUT_CHARS).collect();
+        let reply = self
+            .complete(client, SUMMARY_PROMPT, &format!("{}\n\n{}", title, body))
+            .await?;
+        let bullets: Vec<String> = reply
+            .lines()
+            .map(|l| {
+                l.trim()
+                    .trim_start_matches(['-', '*', '\u{2022}'])
+                    .trim()
+                    .to_string()
+            })
+            .filter(|l| !l.is_empty())
+            .take(3)
+            .map(|l| format!("\u{2022} {}", l))
+            .collect();
+        if bullets.is_empty() {
+            return Err("Model returned an empty summary".to_string());
+        }
+        Ok(bullets.join("\n"))
+    }
+}
@@ -1,23 +1,51 @@
 mod app;
+mod cli;
+mod community;
+mod companies;
 mod config;
 mod db;
+mod demo;
+mod digest;
+mod earnings;
 mod event;
+mod export;
 mod feed;
+mod hook;
+mod ingest;
+mod ipc;
+mod ipo;
+mod keymap;
+mod keywords;
+mod llm;
 mod model;
+mod quotes;
+mod ratings;
+mod readlater;
+mod rups;
+mod script;
+mod sentiment;
+mod serve;
 mod state;
+mod statusline;
+mod termbg;
+mod translate;
 mod ui;
+mod watchlist;
+mod webhook;
 
-use app::App;
+use app::{App, ContentCache};
 use clap::Parser;
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
-use model::{FeedSource, Theme};
+use model::{Theme, TimeDisplay};
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use std::io::{self};
+use std::path::PathBuf;
+use std::sync::Arc;
 use std::time::Duration;
 
 fn main() -> io::Result<()> {
@@ -33,31 +61,122 @@ fn main() -> io::Result<()> {
     let args = config::CliArgs::parse();
 
     // Load config file
-    let cfg = config::load_config(args.config.as_ref());
+    if let Some(ref path) = args.config {
+        config::set_config_file(path.clone());
+    }
+    // A file that doesn't parse stops startup rather than silently falling
+    // back to defaults; `config check` still runs to report it
+    let checking = matches!(args.command, Some(config::Command::Config { .. }));
+    let (mut cfg, unknown_keys) = match config::check_config(&config::config_file_path()) {
+        Ok(Some(parsed)) => parsed,
+        Ok(None) => (config::load_config(None), Vec::new()),
+        Err(_) if checking => (config::load_config(None), Vec::new()),
+        Err(e) => {
+            let err = cli::CliError::Config(format!(
+                "{}: {}",
+                config::config_file_path().display(),
+                e
+            ));
+            cli::report_error(&err, args.errors_json);
+            std::process::exit(err.exit_code());
+        }
+    };
+
+    // Switcher list comes from the file as written; --preset then overlays
+    // its section for startup
+    let presets = config::resolve_presets(&cfg);
+    if let Some(ref name) = args.preset {
+        if let Err(e) = config::apply_preset(&mut cfg, name) {
+            let err = cli::CliError::Config(e);
+            cli::report_error(&err, args.errors_json);
+            std::process::exit(err.exit_code());
+        }
+    }
 
     // Resolve settings
-    let resolved = config::resolve(&args, &cfg);
+    let mut resolved = config::resolve(&args, &cfg);
 
     // Build feed sources from config or defaults
-    let sources = if !cfg.sources.is_empty() {
-        cfg.sources
-            .iter()
-            .map(|s| FeedSource {
-                name: s.name.clone(),
-                url: s.url.clone(),
-                enabled: s.enabled,
-            })
-            .collect()
+    let sources = if args.demo {
+        demo::isolate(&mut resolved);
+        demo::sources()
     } else {
-        FeedSource::defaults()
+        config::sources_from_config(&cfg)
     };
 
+    // IANA zone names go through TZ so the local zone follows them; this
+    // runs before any threads are started
+    if let Some(zone) = resolved.timezone.as_deref().filter(|z| z.contains('/')) {
+        std::env::set_var("TZ", zone);
+    }
+
+    feed::set_topics(&resolved.topics);
+    feed::set_ticker_rules(&resolved.tickers);
+    feed::set_boilerplate(&resolved.boilerplate);
+    feed::set_source_tags(&sources);
+    feed::set_network(&resolved.network);
+    if let Some(ref proxy) = resolved.proxy {
+        feed::set_proxy(proxy);
+    }
+
     // Open database
-    let db_path = config::db_path();
-    let db = db::Db::open(&db_path).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+    if args.demo {
+        // Images and view state go to a scratch directory instead
+        let dir = std::env::temp_dir().join("stocknewstui-demo");
+        config::set_data_dir(dir.clone());
+        config::set_state_dir(dir);
+    }
+    if let Some(ref dir) = args.data_dir {
+        config::set_data_dir(dir.clone());
+    }
+    if let Some(ref dir) = args.state_dir {
+        config::set_state_dir(dir.clone());
+    }
+    let db_path = if args.demo {
+        PathBuf::from(":memory:")
+    } else {
+        args.db.clone().unwrap_or_else(config::db_path)
+    };
+    let db = db::Db::open(&db_path);
+    if let Ok(ref db) = db {
+        if args.demo {
+            demo::seed(db);
+        }
+        feed::set_companies(&db.get_companies().unwrap_or_default());
+    }
+
+    // Headless subcommands run without touching the terminal
+    let once = args.once.then(config::Command::default_digest);
+    if let Some(command) = args.command.as_ref().or(once.as_ref()) {
+        if !checking && !args.quiet {
+            for key in &unknown_keys {
+                eprintln!("Warning: unknown config key '{}' is ignored", key);
+            }
+        }
+        let result = db.map_err(cli::CliError::from).and_then(|db| {
+            let ctx = cli::Context {
+                resolved: &resolved,
+                sources: &sources,
+                db: &db,
+                db_path: &db_path,
+                config_path: config::config_file_path(),
+                json: args.json,
+                quiet: args.quiet,
+            };
+            cli::run(command, &ctx)
+        });
+        if let Err(err) = result {
+            cli::report_error(&err, args.errors_json);
+            std::process::exit(err.exit_code());
+        }
+        return Ok(());
+    }
+
+    let db = db.map_err(io::Error::other)?;
 
     // Terminal setup
     enable_raw_mode()?;
+    let theme = resolved.theme.unwrap_or_else(termbg::detect_theme);
     let mut stdout = io::stdout();
     execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
     let backend = CrosstermBackend::new(stdout);
@@ -65,16 +184,93 @@ fn main() -> io::Result<()> {
 
     // Build app
     let mut app = App::new(resolved.watchlist, sources);
+    app.terminal_size = crossterm::terminal::size().unwrap_or(app.terminal_size);
+    app.watchlist_overridden = resolved.watchlist_overridden;
+    app.demo = args.demo;
     app.refresh_interval = Duration::from_secs(resolved.refresh_interval);
     app.min_fetch_interval = Duration::from_secs(resolved.min_fetch_interval);
+    app.article_limit = resolved.article_limit;
+    app.page_size = resolved.page_size;
+    app.on_new_article = resolved.on_new_article;
+    app.holdings = resolved.holdings;
+    app.on_holding_article = resolved.on_holding_article;
+    app.webhooks = webhook::Webhook::from_config(&resolved.webhooks);
+    app.ipc_socket = resolved.ipc.socket_path();
+    app.status_file = resolved.status_file;
+    let script_error = match resolved.script.as_deref().map(script::Script::load) {
+        Some(Ok(script)) => {
+            app.script = Some(Arc::new(script));
+            None
+        }
+        Some(Err(e)) => Some(e),
+        None => None,
+    };
+    app.confirm_quit = resolved.confirm_quit;
+    app.tts_command = resolved.tts_command;
+    app.clipboard_command = resolved.clipboard_command;
+    app.pipe_command = resolved.pipe_command;
+    app.reading_time_column = resolved.reading_time_column;
+    app.content_cache = ContentCache::new(
+        resolved.cache.max_entries,
+        Duration::from_secs(resolved.cache.max_age_minutes * 60),
+    );
+    app.topic_names = resolved.topics.keys().cloned().collect();
+    for tag in app.sources.iter().flat_map(|s| s.tags.clone()).collect::<Vec<_>>() {
+        if !app.topic_names.contains(&tag) {
+            app.topic_names.push(tag);
+        }
+    }
+    app.show_market_strip = resolved.quotes.enabled && resolved.quotes.market_strip;
+    app.quotes_config = resolved.quotes;
+    app.reader_style = resolved.reader;
+    app.dedup = resolved.dedup;
+    app.preset_index = args
+        .preset
+        .as_ref()
+        .and_then(|name| presets.iter().position(|p| &p.name == name))
+        .unwrap_or(0);
+    // Switching presets would bring back the configured, networked sources
+    app.presets = if args.demo { Vec::new() } else { presets };
+    app.time_display = TimeDisplay::new(resolved.timezone.as_deref(), &resolved.date_format);
+    app.keymap = keymap::Keymap::from_config(&resolved.keys).0;
+    app.community_config = resolved.community;
+    app.sentiment_backend = sentiment::Backend::from_config(&resolved.sentiment_backend);
+    app.analysis_fingerprint =
+        feed::analysis_fingerprint(&resolved.tickers, &db.get_companies().unwrap_or_default());
+    app.llm = llm::Llm::from_config(&resolved.llm);
+    app.translator = translate::Translator::from_config(&resolved.translate, app.llm.as_ref());
+    app.read_later = readlater::Service::from_config(&resolved.read_later);
+    if !resolved.sentiment_windows.is_empty() {
+        app.sentiment_windows = resolved.sentiment_windows;
+    }
+    if resolved.idle_pause_minutes > 0 {
+        app.idle_pause = Some(Duration::from_secs(resolved.idle_pause_minutes * 60));
+    }
 
     // Restore saved view state (before CLI overrides)
     let saved_state = state::load_state();
     app.restore_view_state(&saved_state);
 
     // CLI overrides take precedence
-    app.theme_name = resolved.theme;
-    app.theme = Theme::from_name(resolved.theme);
+    app.theme_name = theme;
+    app.theme = Theme::from_name(theme);
+    app.view_mode = resolved.start_view;
+    if let Some(sort_mode) = resolved.sort_mode {
+        app.sort_mode = sort_mode;
+    }
+    if let Some(filter_mode) = resolved.filter_mode {
+        app.filter_mode = filter_mode;
+    }
+
+    if let Some(e) = script_error {
+        app.set_status(format!("Script not loaded: {}", e));
+    } else if let Some(key) = unknown_keys.first() {
+        app.set_status(format!(
+            "Unknown config key '{}' ignored ({} total; see `stocknewstui config check`)",
+            key,
+            unknown_keys.len()
+        ));
+    }
 
     // Run the app
     let result = event::run_loop(&mut terminal, app, db);
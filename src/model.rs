@@ -3,6 +3,7 @@
 use ratatui::style::Color;
 use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
+use std::sync::{Arc, LazyLock, Mutex};
 
 // ============================================================
 // Article
@@ -11,15 +12,58 @@ use std::collections::HashSet;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Article {
     pub id: i64,
-    pub title: String,
-    pub source: String,
-    pub url: String,
+    pub title: Arc<str>,
+    pub source: Arc<str>,
+    pub url: Arc<str>,
     pub tickers: Vec<String>,
     pub published_at: i64, // unix timestamp
     pub fetched_at: i64,
     pub read: bool,
     pub bookmarked: bool,
     pub sentiment: Sentiment,
+    /// Continuous score behind `sentiment`, -1.0 to 1.0
+    #[serde(default)]
+    pub sentiment_score: f64,
+    pub event: Option<EventKind>,
+    /// Commodity/FX topic tags, derived from the title at load time
+    #[serde(default)]
+    pub topics: Vec<String>,
+    /// Words in the stored body; None until content has been fetched
+    #[serde(default)]
+    pub word_count: Option<usize>,
+    /// Detected from the body when fetched, else from the title
+    #[serde(default)]
+    pub language: Option<Language>,
+    /// Top TF-IDF terms of the stored body
+    #[serde(default)]
+    pub keywords: Vec<String>,
+}
+
+/// Shared copy of a source name; thousands of stored articles repeat the
+/// same handful of names
+pub fn intern(name: &str) -> Arc<str> {
+    static NAMES: LazyLock<Mutex<HashSet<Arc<str>>>> = LazyLock::new(Default::default);
+    let mut names = NAMES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(name) = names.get(name) {
+        return name.clone();
+    }
+    let name: Arc<str> = name.into();
+    names.insert(name.clone());
+    name
+}
+
+/// Words per minute behind reading time estimates
+const READING_WPM: usize = 200;
+
+pub fn word_count(text: &str) -> usize {
+    text.split_whitespace()
+        .filter(|w| w.chars().any(char::is_alphanumeric))
+        .count()
+}
+
+/// Whole minutes to read `words` words, at least one
+pub fn reading_minutes(words: usize) -> usize {
+    words.div_ceil(READING_WPM).max(1)
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
@@ -38,6 +82,14 @@ impl Sentiment {
         }
     }
 
+    pub fn as_str(&self) -> &str {
+        match self {
+            Sentiment::Positive => "positive",
+            Sentiment::Negative => "negative",
+            Sentiment::Neutral => "neutral",
+        }
+    }
+
     pub fn color(&self, theme: &Theme) -> Color {
         match self {
             Sentiment::Positive => theme.positive,
@@ -47,6 +99,609 @@ impl Sentiment {
     }
 }
 
+/// Aggregate sentiment for one ticker over one time window
+#[derive(Debug, Clone)]
+pub struct SentimentMeter {
+    pub window: String,
+    /// Mean article sentiment, -1.0 (all negative) to 1.0 (all positive)
+    pub score: f64,
+    pub count: i64,
+}
+
+impl SentimentMeter {
+    /// Up to three arrows for bullish/bearish strength, "=" when mixed
+    pub fn glyph(&self) -> String {
+        if self.count == 0 {
+            return "\u{00b7}".to_string();
+        }
+        let strength = ((self.score.abs() * 3.0).round() as usize).clamp(1, 3);
+        if self.score >= 0.1 {
+            "\u{25b2}".repeat(strength)
+        } else if self.score <= -0.1 {
+            "\u{25bc}".repeat(strength)
+        } else {
+            "=".to_string()
+        }
+    }
+
+    pub fn color(&self, theme: &Theme) -> Color {
+        if self.count == 0 || self.score.abs() < 0.1 {
+            theme.muted
+        } else if self.score > 0.0 {
+            theme.positive
+        } else {
+            theme.negative
+        }
+    }
+}
+
+/// Parse a window like "24h", "7d", or "2w" into seconds
+pub fn parse_window(s: &str) -> Option<i64> {
+    let s = s.trim();
+    let (num, unit) = s.split_at(s.len().checked_sub(1)?);
+    let n: i64 = num.parse().ok()?;
+    let secs = match unit {
+        "h" => 3600,
+        "d" => 86400,
+        "w" => 7 * 86400,
+        _ => return None,
+    };
+    (n > 0).then_some(n * secs)
+}
+
+// ============================================================
+// Time Display
+// ============================================================
+
+/// Time zone and date format for absolute timestamps in the UI
+#[derive(Debug, Clone)]
+pub struct TimeDisplay {
+    /// None follows the system zone (or TZ, which IANA names set)
+    offset: Option<chrono::FixedOffset>,
+    /// Zone name shown after full timestamps, e.g. "WIB"
+    label: String,
+    pub date_format: String,
+}
+
+impl Default for TimeDisplay {
+    fn default() -> Self {
+        TimeDisplay::new(None, "%Y-%m-%d")
+    }
+}
+
+impl TimeDisplay {
+    /// `zone` is WIB, WITA, WIT, UTC, or an offset like "+07:00"; anything
+    /// else uses the local zone
+    pub fn new(zone: Option<&str>, date_format: &str) -> TimeDisplay {
+        let (offset, label) = match zone.map(str::trim).and_then(parse_zone) {
+            Some((offset, label)) => (Some(offset), label),
+            None => (None, String::new()),
+        };
+        TimeDisplay {
+            offset,
+            label,
+            date_format: date_format.to_string(),
+        }
+    }
+
+    /// Unix timestamp formatted with a strftime string in the display zone
+    pub fn format(&self, ts: i64, fmt: &str) -> String {
+        let Some(dt) = chrono::DateTime::from_timestamp(ts, 0) else {
+            return String::new();
+        };
+        match self.offset {
+            Some(offset) => dt.with_timezone(&offset).format(fmt).to_string(),
+            None => dt.with_timezone(&chrono::Local).format(fmt).to_string(),
+        }
+    }
+
+    /// Unix timestamp as a date and time in the display zone
+    pub fn at(&self, ts: i64) -> Option<chrono::DateTime<chrono::FixedOffset>> {
+        let dt = chrono::DateTime::from_timestamp(ts, 0)?;
+        Some(match self.offset {
+            Some(offset) => dt.with_timezone(&offset),
+            None => dt.with_timezone(&chrono::Local).fixed_offset(),
+        })
+    }
+
+    pub fn date(&self, ts: i64) -> String {
+        self.format(ts, &self.date_format)
+    }
+
+    /// Date, time, and zone, e.g. "2024-05-02 14:30 WIB"
+    pub fn full(&self, ts: i64) -> String {
+        let label = if self.label.is_empty() {
+            "%Z"
+        } else {
+            &self.label
+        };
+        self.format(ts, &format!("{} %H:%M {}", self.date_format, label))
+    }
+}
+
+/// Indonesian zone names, UTC, or a fixed "+HH:MM" offset
+fn parse_zone(zone: &str) -> Option<(chrono::FixedOffset, String)> {
+    let hours = match zone.to_uppercase().as_str() {
+        "WIB" => Some(7),
+        "WITA" => Some(8),
+        "WIT" => Some(9),
+        "UTC" | "GMT" => Some(0),
+        _ => None,
+    };
+    if let Some(hours) = hours {
+        let offset = chrono::FixedOffset::east_opt(hours * 3600)?;
+        return Some((offset, zone.to_uppercase()));
+    }
+
+    let (sign, rest) = match zone.chars().next()? {
+        '+' => (1, &zone[1..]),
+        '-' => (-1, &zone[1..]),
+        _ => return None,
+    };
+    let (h, m) = rest.split_once(':').unwrap_or((rest, "0"));
+    let secs = h.parse::<i32>().ok()? * 3600 + m.parse::<i32>().ok()? * 60;
+    let offset = chrono::FixedOffset::east_opt(sign * secs)?;
+    Some((offset, zone.to_string()))
+}
+
+// ============================================================
+// Language
+// ============================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Language {
+    Indonesian,
+    English,
+}
+
+/// Common Indonesian function words; English text almost never has them
+const ID_WORDS: &[&str] = &[
+    "yang",
+    "dan",
+    "di",
+    "ke",
+    "dari",
+    "ini",
+    "itu",
+    "dengan",
+    "untuk",
+    "pada",
+    "tidak",
+    "akan",
+    "dalam",
+    "juga",
+    "oleh",
+    "sebesar",
+    "tahun",
+    "saham",
+    "perseroan",
+];
+const EN_WORDS: &[&str] = &[
+    "the", "and", "of", "to", "in", "is", "that", "for", "with", "on", "by", "from", "shares",
+];
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::Indonesian, Language::English];
+
+    /// ISO 639-1 code, as stored and passed to translators
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Language::Indonesian => "id",
+            Language::English => "en",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<Language> {
+        Language::ALL.into_iter().find(|l| l.as_str() == s)
+    }
+
+    /// Short badge shown in the feed
+    pub fn badge(&self) -> &str {
+        match self {
+            Language::Indonesian => "ID",
+            Language::English => "EN",
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            Language::Indonesian => "Indonesian",
+            Language::English => "English",
+        }
+    }
+
+    /// Guess the language from function word counts; None when the text
+    /// has no telling words at all (short headlines often don't)
+    pub fn detect(text: &str) -> Option<Language> {
+        let mut id = 0;
+        let mut en = 0;
+        for word in text.split(|c: char| !c.is_alphabetic()).take(2000) {
+            let word = word.to_lowercase();
+            if ID_WORDS.contains(&word.as_str()) {
+                id += 1;
+            } else if EN_WORDS.contains(&word.as_str()) {
+                en += 1;
+            }
+        }
+        if id == 0 && en == 0 {
+            None
+        } else if id > en {
+            Some(Language::Indonesian)
+        } else {
+            Some(Language::English)
+        }
+    }
+
+    /// Cycle None -> each language -> None, for the feed's language filter
+    pub fn cycle(current: Option<Language>) -> Option<Language> {
+        match current {
+            None => Some(Language::ALL[0]),
+            Some(l) => {
+                let idx = Language::ALL.iter().position(|k| *k == l).unwrap_or(0);
+                Language::ALL.get(idx + 1).copied()
+            }
+        }
+    }
+}
+
+// ============================================================
+// Corporate Actions
+// ============================================================
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    Dividend,
+    StockSplit,
+    RightsIssue,
+    Buyback,
+    PrivatePlacement,
+    Acquisition,
+    Earnings,
+    Ipo,
+}
+
+impl EventKind {
+    pub const ALL: [EventKind; 8] = [
+        EventKind::Dividend,
+        EventKind::StockSplit,
+        EventKind::RightsIssue,
+        EventKind::Buyback,
+        EventKind::PrivatePlacement,
+        EventKind::Acquisition,
+        EventKind::Earnings,
+        EventKind::Ipo,
+    ];
+
+    /// Short badge shown in the feed
+    pub fn badge(&self) -> &str {
+        match self {
+            EventKind::Dividend => "DIV",
+            EventKind::StockSplit => "SPLIT",
+            EventKind::RightsIssue => "RIGHTS",
+            EventKind::Buyback => "BUYBACK",
+            EventKind::PrivatePlacement => "PP",
+            EventKind::Acquisition => "M&A",
+            EventKind::Earnings => "EARN",
+            EventKind::Ipo => "IPO",
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            EventKind::Dividend => "Dividend",
+            EventKind::StockSplit => "Stock split",
+            EventKind::RightsIssue => "Rights issue",
+            EventKind::Buyback => "Buyback",
+            EventKind::PrivatePlacement => "Private placement",
+            EventKind::Acquisition => "Acquisition",
+            EventKind::Earnings => "Earnings",
+            EventKind::Ipo => "IPO",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Dividend => "dividend",
+            EventKind::StockSplit => "stock_split",
+            EventKind::RightsIssue => "rights_issue",
+            EventKind::Buyback => "buyback",
+            EventKind::PrivatePlacement => "private_placement",
+            EventKind::Acquisition => "acquisition",
+            EventKind::Earnings => "earnings",
+            EventKind::Ipo => "ipo",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<EventKind> {
+        EventKind::ALL.into_iter().find(|e| e.as_str() == s)
+    }
+
+    /// Cycle None -> each kind -> None, for the feed's event filter key
+    pub fn cycle(current: Option<EventKind>) -> Option<EventKind> {
+        match current {
+            None => Some(EventKind::ALL[0]),
+            Some(e) => {
+                let idx = EventKind::ALL.iter().position(|k| *k == e).unwrap_or(0);
+                EventKind::ALL.get(idx + 1).copied()
+            }
+        }
+    }
+}
+
+/// Keyword rules per event, checked in order. Private placement comes
+/// before rights issue because "tanpa HMETD" contains "HMETD".
+const EVENT_RULES: &[(EventKind, &[&str])] = &[
+    (
+        EventKind::PrivatePlacement,
+        &["private placement", "pmthmetd", "tanpa hmetd"],
+    ),
+    (
+        EventKind::RightsIssue,
+        &["rights issue", "right issue", "hmetd", "penawaran umum terbatas"],
+    ),
+    (
+        EventKind::StockSplit,
+        &["stock split", "pemecahan saham", "pemecahan nilai nominal"],
+    ),
+    (
+        EventKind::Buyback,
+        &["buyback", "buy back", "pembelian kembali saham"],
+    ),
+    (
+        EventKind::Dividend,
+        &["dividen", "dividend", "cum date", "cum dividen"],
+    ),
+    (
+        EventKind::Ipo,
+        &[
+            "ipo",
+            "initial public offering",
+            "penawaran umum perdana",
+            "book building",
+            "bookbuilding",
+            "e-ipo",
+            "pencatatan perdana",
+            "listing perdana",
+            "calon emiten",
+        ],
+    ),
+    (
+        EventKind::Earnings,
+        &[
+            "laporan keuangan",
+            "laba bersih",
+            "rugi bersih",
+            "kinerja keuangan",
+            "net profit",
+            "net income",
+            "earnings",
+            "laba kuartal",
+            "laba semester",
+            "laba periode",
+        ],
+    ),
+    (
+        EventKind::Acquisition,
+        &[
+            "akuisisi",
+            "acquisition",
+            "acquire",
+            "merger",
+            "caplok",
+            "ambil alih",
+            "pengambilalihan",
+        ],
+    ),
+];
+
+/// Classify a headline as a corporate action, if it reads like one
+pub fn classify_event(text: &str) -> Option<EventKind> {
+    let lower = text.to_lowercase();
+    EVENT_RULES
+        .iter()
+        .find(|(_, keywords)| keywords.iter().any(|k| starts_word(&lower, k)))
+        .map(|(kind, _)| *kind)
+}
+
+/// Index levels, index rebalancing and macro data: news about the market
+/// as a whole rather than any one issuer
+const MARKET_KEYWORDS: &[&str] = &[
+    "ihsg",
+    "indeks harga saham gabungan",
+    "composite index",
+    "jci",
+    "lq45",
+    "lq 45",
+    "idx30",
+    "idx80",
+    "kompas100",
+    "rebalancing indeks",
+    "index rebalancing",
+    "net sell asing",
+    "net buy asing",
+    "foreign net",
+    "bi rate",
+    "bi-rate",
+    "suku bunga acuan",
+    "inflasi",
+    "inflation",
+    "neraca perdagangan",
+    "trade balance",
+    "cadangan devisa",
+    "pertumbuhan ekonomi",
+    "pdb",
+    "gdp",
+    "nilai tukar rupiah",
+    "kurs rupiah",
+];
+
+/// Whether a headline is index-level or macro news
+pub fn is_market_news(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    MARKET_KEYWORDS.iter().any(|k| starts_word(&lower, k))
+}
+
+/// Regulators and market authorities whose announcements tend to move the
+/// whole market
+const REGULATOR_KEYWORDS: &[&str] = &[
+    "ojk",
+    "otoritas jasa keuangan",
+    "bei",
+    "bursa efek indonesia",
+    "idx",
+    "indonesia stock exchange",
+    "bank indonesia",
+    "kemenkeu",
+    "kementerian keuangan",
+    "menkeu",
+    "menteri keuangan",
+    "ministry of finance",
+];
+
+/// Whether a headline mentions a regulator. Matched as whole words so
+/// "BEI" doesn't fire on "Beijing".
+pub fn is_regulatory(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    REGULATOR_KEYWORDS.iter().any(|k| contains_word(&lower, k))
+}
+
+/// Whether `keyword` occurs in `text` as a whole word or phrase
+pub fn contains_word(text: &str, keyword: &str) -> bool {
+    text.match_indices(keyword).any(|(pos, _)| {
+        let before = text[..pos].chars().next_back();
+        let after = text[pos + keyword.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Exchange notices worth an alert: trading suspensions and Unusual Market
+/// Activity (UMA) announcements, which are never missed, plus special
+/// monitoring board / full call auction placements and margin list
+/// changes, which warn when they touch a holding or watched ticker
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AlertKind {
+    Suspension,
+    Uma,
+    SpecialMonitoring,
+    Margin,
+}
+
+impl AlertKind {
+    pub fn badge(&self) -> &str {
+        match self {
+            AlertKind::Suspension => "SUSPEND",
+            AlertKind::Uma => "UMA",
+            AlertKind::SpecialMonitoring => "FCA",
+            AlertKind::Margin => "MARGIN",
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            AlertKind::Suspension => "Trading suspension",
+            AlertKind::Uma => "Unusual Market Activity",
+            AlertKind::SpecialMonitoring => "Special monitoring / full call auction",
+            AlertKind::Margin => "Margin list change",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertKind::Suspension => "suspension",
+            AlertKind::Uma => "uma",
+            AlertKind::SpecialMonitoring => "special_monitoring",
+            AlertKind::Margin => "margin",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<AlertKind> {
+        match s {
+            "suspension" => Some(AlertKind::Suspension),
+            "uma" => Some(AlertKind::Uma),
+            "special_monitoring" => Some(AlertKind::SpecialMonitoring),
+            "margin" => Some(AlertKind::Margin),
+            _ => None,
+        }
+    }
+
+    /// Alerts raised for every ticker, not just holdings and the watchlist
+    pub fn forced(&self) -> bool {
+        matches!(self, AlertKind::Suspension | AlertKind::Uma)
+    }
+}
+
+/// Keywords per alert, matched as whole words ("UMA" must not fire on
+/// "umat"). Suspension is checked first: a suspension often follows a UMA
+/// and mentions both.
+const ALERT_RULES: &[(AlertKind, &[&str])] = &[
+    (
+        AlertKind::Suspension,
+        &[
+            "suspensi",
+            "suspend",
+            "disuspensi",
+            "disuspend",
+            "suspended",
+            "suspension",
+            "trading halt",
+            "penghentian sementara perdagangan",
+            "dihentikan sementara",
+            "digembok",
+        ],
+    ),
+    (
+        AlertKind::Uma,
+        &["uma", "unusual market activity", "aktivitas pasar yang tidak biasa"],
+    ),
+    (
+        AlertKind::SpecialMonitoring,
+        &[
+            "papan pemantauan khusus",
+            "pemantauan khusus",
+            "special monitoring",
+            "watchlist board",
+            "full call auction",
+            "fca",
+            "notasi khusus",
+        ],
+    ),
+    (
+        AlertKind::Margin,
+        &[
+            "efek marjin",
+            "daftar efek marjin",
+            "transaksi marjin",
+            "margin list",
+            "marginable",
+            "short sell",
+            "short selling",
+            "transaksi short",
+        ],
+    ),
+];
+
+/// Classify a headline as an exchange notice worth an alert
+pub fn classify_alert(text: &str) -> Option<AlertKind> {
+    let lower = text.to_lowercase();
+    ALERT_RULES
+        .iter()
+        .find(|(_, keywords)| keywords.iter().any(|k| contains_word(&lower, k)))
+        .map(|(kind, _)| *kind)
+}
+
+/// Whether `keyword` occurs at the start of a word, so "ipo" matches
+/// "IPO-nya" but not "Diponegoro"
+pub fn starts_word(text: &str, keyword: &str) -> bool {
+    text.match_indices(keyword).any(|(pos, _)| {
+        !text[..pos]
+            .chars()
+            .next_back()
+            .is_some_and(char::is_alphanumeric)
+    })
+}
+
 // ============================================================
 // Feed Source
 // ============================================================
@@ -56,30 +711,163 @@ pub struct FeedSource {
     pub name: String,
     pub url: String,
     pub enabled: bool,
+    /// Tried before the built-in article body selectors
+    pub content_selectors: Vec<String>,
+    /// Seconds between fetches of this source, instead of the global refresh
+    pub refresh_interval: Option<u64>,
+    /// Sent instead of the default User-Agent for the feed and its pages
+    pub user_agent: Option<String>,
+    /// Tags added to every article from this source
+    pub tags: Vec<String>,
+    pub category: Option<String>,
+    /// Never fetch article pages from this source
+    pub mute_content: bool,
 }
 
 impl FeedSource {
+    pub fn new(name: &str, url: &str) -> FeedSource {
+        FeedSource {
+            name: name.to_string(),
+            url: url.to_string(),
+            enabled: true,
+            content_selectors: Vec::new(),
+            refresh_interval: None,
+            user_agent: None,
+            tags: Vec::new(),
+            category: None,
+            mute_content: false,
+        }
+    }
+
     pub fn defaults() -> Vec<FeedSource> {
         vec![
-            FeedSource {
-                name: "CNBC Indo".to_string(),
-                url: "https://www.cnbcindonesia.com/market/rss".to_string(),
-                enabled: true,
-            },
-            FeedSource {
-                name: "Tempo Bisnis".to_string(),
-                url: "https://rss.tempo.co/bisnis".to_string(),
-                enabled: true,
-            },
-            FeedSource {
-                name: "IDX Channel".to_string(),
-                url: "https://www.idxchannel.com/rss".to_string(),
-                enabled: true,
-            },
+            FeedSource::new("CNBC Indo", "https://www.cnbcindonesia.com/market/rss"),
+            FeedSource::new("Tempo Bisnis", "https://rss.tempo.co/bisnis"),
+            FeedSource::new("IDX Channel", "https://www.idxchannel.com/rss"),
         ]
     }
 }
 
+/// Whether an article mentions a watchlist ticker (everything matches an empty watchlist)
+pub fn matches_watchlist(article: &Article, watchlist: &[String]) -> bool {
+    if watchlist.is_empty() {
+        return true;
+    }
+    let title_upper = article.title.to_uppercase();
+    article.tickers.iter().any(|t| watchlist.contains(t))
+        || watchlist.iter().any(|w| title_upper.contains(w))
+}
+
+/// Whether a new article should notify as a watchlist match. Unlike
+/// `matches_watchlist`, an empty watchlist matches nothing here.
+pub fn watchlist_hit(article: &Article, watchlist: &[String]) -> bool {
+    !watchlist.is_empty() && matches_watchlist(article, watchlist)
+}
+
+/// One row of the watchlist overview
+#[derive(Debug, Clone)]
+pub struct TickerSummary {
+    pub ticker: String,
+    pub unread: usize,
+    /// Articles published in the last 24 hours
+    pub recent: usize,
+    pub positive: usize,
+    pub negative: usize,
+    pub latest_title: Option<String>,
+}
+
+impl TickerSummary {
+    /// Summarize a ticker's articles (newest first); sentiment counts only
+    /// cover articles published at or after `since`
+    pub fn from_articles(ticker: &str, articles: &[Article], since: i64) -> Self {
+        let recent: Vec<&Article> = articles.iter().filter(|a| a.published_at >= since).collect();
+        Self {
+            ticker: ticker.to_string(),
+            unread: articles.iter().filter(|a| !a.read).count(),
+            recent: recent.len(),
+            positive: recent
+                .iter()
+                .filter(|a| a.sentiment == Sentiment::Positive)
+                .count(),
+            negative: recent
+                .iter()
+                .filter(|a| a.sentiment == Sentiment::Negative)
+                .count(),
+            latest_title: articles.first().map(|a| a.title.to_string()),
+        }
+    }
+}
+
+/// One classified event on a ticker's timeline
+#[derive(Debug, Clone)]
+pub struct TimelineEntry {
+    pub published_at: i64,
+    /// Short kind badge: an event badge or "RATING"
+    pub badge: String,
+    /// Condensed detail, e.g. "Q3 2024" or "Mandiri Sekuritas Buy TP 10500"
+    pub detail: String,
+    pub title: String,
+    pub article_id: i64,
+    pub url: String,
+    pub sentiment: Sentiment,
+}
+
+/// A link found in an article's body
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Link {
+    pub text: String,
+    pub url: String,
+}
+
+/// A passage saved from the reader, with the article it came from
+#[derive(Debug, Clone, Serialize)]
+pub struct Highlight {
+    pub id: i64,
+    pub article_id: i64,
+    pub title: String,
+    pub url: String,
+    pub text: String,
+    pub note: Option<String>,
+    pub created_at: i64,
+}
+
+/// An alert that fired, kept until reviewed in the Alerts view
+#[derive(Debug, Clone, Serialize)]
+pub struct AlertRecord {
+    pub id: i64,
+    /// Rule that fired, an `AlertKind::as_str` value
+    pub rule: String,
+    pub article_id: i64,
+    pub title: String,
+    pub url: String,
+    pub created_at: i64,
+    pub acknowledged: bool,
+}
+
+/// Choices made for a duplicate cluster, remembered across restarts
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ClusterPref {
+    /// Show every copy as its own row
+    pub expanded: bool,
+    /// Hide the whole cluster
+    pub dismissed: bool,
+    /// Source whose copy represents the cluster
+    pub preferred_source: Option<String>,
+}
+
+/// A linked page opened from the reader. It isn't stored as an article;
+/// pages stack up as links are followed and pop off on back.
+#[derive(Debug, Clone)]
+pub struct LinkedPage {
+    pub title: String,
+    pub url: String,
+    /// None until the fetch finishes
+    pub content: Option<String>,
+    pub links: Vec<Link>,
+    /// Scroll position of the page underneath, restored on back
+    pub return_scroll: u16,
+}
+
 // ============================================================
 // View / Filter
 // ============================================================
@@ -90,6 +878,14 @@ pub enum ViewMode {
     Reader,
     Bookmarks,
     Sources,
+    Watchlist,
+    Earnings,
+    Ipo,
+    Ratings,
+    Timeline,
+    Meetings,
+    Highlights,
+    Alerts,
 }
 
 impl ViewMode {
@@ -99,6 +895,14 @@ impl ViewMode {
             ViewMode::Reader => "Reader",
             ViewMode::Bookmarks => "Bookmarks",
             ViewMode::Sources => "Sources",
+            ViewMode::Watchlist => "Watchlist",
+            ViewMode::Earnings => "Earnings",
+            ViewMode::Ipo => "IPO",
+            ViewMode::Ratings => "Ratings",
+            ViewMode::Timeline => "Timeline",
+            ViewMode::Meetings => "RUPS",
+            ViewMode::Highlights => "Highlights",
+            ViewMode::Alerts => "Alerts",
         }
     }
 }
@@ -109,6 +913,8 @@ pub enum FilterMode {
     Watchlist,
     Source,
     Unread,
+    /// Fetched since the previous session ended
+    New,
 }
 
 impl FilterMode {
@@ -118,6 +924,7 @@ impl FilterMode {
             FilterMode::Watchlist => "Watchlist",
             FilterMode::Source => "Source",
             FilterMode::Unread => "Unread",
+            FilterMode::New => "New",
         }
     }
 
@@ -126,7 +933,8 @@ impl FilterMode {
             FilterMode::All => FilterMode::Watchlist,
             FilterMode::Watchlist => FilterMode::Unread,
             FilterMode::Unread => FilterMode::Source,
-            FilterMode::Source => FilterMode::All,
+            FilterMode::Source => FilterMode::New,
+            FilterMode::New => FilterMode::All,
         }
     }
 
@@ -135,6 +943,7 @@ impl FilterMode {
             "watchlist" => FilterMode::Watchlist,
             "unread" => FilterMode::Unread,
             "source" => FilterMode::Source,
+            "new" => FilterMode::New,
             _ => FilterMode::All,
         }
     }
@@ -145,10 +954,120 @@ impl FilterMode {
             FilterMode::Watchlist => "watchlist",
             FilterMode::Unread => "unread",
             FilterMode::Source => "source",
+            FilterMode::New => "new",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SortMode {
+    Time,
+    Portfolio,
+}
+
+impl SortMode {
+    pub fn label(&self) -> &str {
+        match self {
+            SortMode::Time => "Time",
+            SortMode::Portfolio => "Portfolio",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            SortMode::Time => SortMode::Portfolio,
+            SortMode::Portfolio => SortMode::Time,
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "portfolio" => SortMode::Portfolio,
+            _ => SortMode::Time,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            SortMode::Time => "time",
+            SortMode::Portfolio => "portfolio",
         }
     }
 }
 
+/// How market-wide articles are treated in the feed. `Mixed` keeps them
+/// in the general feed but drops them from per-ticker views, where an
+/// IHSG wrap listing twenty movers would otherwise match every ticker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MarketFilter {
+    Mixed,
+    Only,
+    Hidden,
+}
+
+impl MarketFilter {
+    pub fn label(&self) -> &str {
+        match self {
+            MarketFilter::Mixed => "Mixed",
+            MarketFilter::Only => "Market only",
+            MarketFilter::Hidden => "Hidden",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            MarketFilter::Mixed => MarketFilter::Only,
+            MarketFilter::Only => MarketFilter::Hidden,
+            MarketFilter::Hidden => MarketFilter::Mixed,
+        }
+    }
+
+    pub fn from_str(s: &str) -> Self {
+        match s {
+            "only" => MarketFilter::Only,
+            "hidden" => MarketFilter::Hidden,
+            _ => MarketFilter::Mixed,
+        }
+    }
+
+    pub fn as_str(&self) -> &str {
+        match self {
+            MarketFilter::Mixed => "mixed",
+            MarketFilter::Only => "only",
+            MarketFilter::Hidden => "hidden",
+        }
+    }
+}
+
+/// A portfolio position declared in config
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Holding {
+    pub ticker: String,
+    /// Position size (shares or lots); larger positions sort first
+    #[serde(default)]
+    pub size: Option<f64>,
+}
+
+/// The exchange notice a new article raises: suspension and UMA notices
+/// for any ticker, board and margin notices only for watchlist or held ones
+pub fn alert_for(
+    article: &Article,
+    watchlist: &[String],
+    holdings: &[Holding],
+) -> Option<AlertKind> {
+    let watched = watchlist_hit(article, watchlist) || holding_weight(article, holdings).is_some();
+    classify_alert(&article.title).filter(|a| a.forced() || watched)
+}
+
+/// Position size of the largest holding an article mentions, if any
+pub fn holding_weight(article: &Article, holdings: &[Holding]) -> Option<f64> {
+    holdings
+        .iter()
+        .filter(|h| article.tickers.iter().any(|t| t.eq_ignore_ascii_case(&h.ticker)))
+        .map(|h| h.size.unwrap_or(0.0))
+        .reduce(f64::max)
+}
+
 // ============================================================
 // Theme (matching stocktui)
 // ============================================================
@@ -267,28 +1186,22 @@ impl Theme {
 // Title Similarity (for deduplication)
 // ============================================================
 
-pub fn normalize_title(title: &str) -> String {
+pub fn normalize_title(title: &str, stop_words: &[String]) -> String {
     let lower = title.to_lowercase();
     let cleaned: String = lower
         .chars()
         .map(|c| if c.is_alphanumeric() || c == ' ' { c } else { ' ' })
         .collect();
-    let stop_words: HashSet<&str> = [
-        "dan", "di", "ke", "dari", "yang", "untuk", "dengan", "ini", "itu",
-        "the", "a", "an", "in", "on", "of", "to", "and", "for", "is", "at",
-    ]
-    .into_iter()
-    .collect();
     cleaned
         .split_whitespace()
-        .filter(|w| !stop_words.contains(w) && w.len() > 1)
+        .filter(|w| !stop_words.iter().any(|s| s == w) && w.len() > 1)
         .collect::<Vec<_>>()
         .join(" ")
 }
 
-pub fn title_similarity(a: &str, b: &str) -> f64 {
-    let norm_a = normalize_title(a);
-    let norm_b = normalize_title(b);
+pub fn title_similarity(a: &str, b: &str, stop_words: &[String]) -> f64 {
+    let norm_a = normalize_title(a, stop_words);
+    let norm_b = normalize_title(b, stop_words);
     let words_a: HashSet<&str> = norm_a.split_whitespace().collect();
     let words_b: HashSet<&str> = norm_b.split_whitespace().collect();
     if words_a.is_empty() || words_b.is_empty() {
@@ -299,33 +1212,331 @@ pub fn title_similarity(a: &str, b: &str) -> f64 {
     intersection / union
 }
 
-pub fn analyze_sentiment(title: &str) -> Sentiment {
+/// Sentiment lexicon: (word or phrase, weight). Phrases are matched before
+/// their component words, so "gagal bayar" never reads as "gagal" + "bayar".
+const SENTIMENT_LEXICON: &[(&str, f64)] = &[
+    // Positive
+    ("naik", 1.0),
+    ("menguat", 1.0),
+    ("penguatan", 1.0),
+    ("tumbuh", 1.0),
+    ("bertumbuh", 1.0),
+    ("positif", 1.0),
+    ("optimis", 1.0),
+    ("optimistis", 1.0),
+    ("surplus", 1.0),
+    ("cuan", 1.0),
+    ("untung", 1.0),
+    ("laba bersih", 0.5),
+    ("cetak laba", 1.5),
+    ("rekor", 1.5),
+    ("melonjak", 1.5),
+    ("melesat", 1.5),
+    ("melejit", 1.5),
+    ("meroket", 1.5),
+    ("bullish", 1.5),
+    ("rally", 1.0),
+    ("reli", 1.0),
+    ("hijau", 0.5),
+    ("zona hijau", 1.0),
+    ("pulih", 1.0),
+    ("rebound", 1.0),
+    ("all time high", 1.5),
+    ("record", 1.5),
+    ("beats", 1.0),
+    ("upgrade", 1.0),
+    ("growth", 1.0),
+    ("raises", 1.0),
+    ("outperform", 1.0),
+    ("buy", 1.0),
+    ("overweight", 1.0),
+    // Negative
+    ("turun", -1.0),
+    ("melemah", -1.0),
+    ("pelemahan", -1.0),
+    ("jatuh", -1.0),
+    ("rugi", -1.0),
+    ("merugi", -1.0),
+    ("defisit", -1.0),
+    ("resesi", -1.5),
+    ("pesimis", -1.0),
+    ("koreksi", -1.0),
+    ("terkoreksi", -1.0),
+    ("tekanan", -1.0),
+    ("tertekan", -1.0),
+    ("merah", -0.5),
+    ("zona merah", -1.0),
+    ("merosot", -1.5),
+    ("anjlok", -1.5),
+    ("ambles", -1.5),
+    ("ambruk", -1.5),
+    ("longsor", -1.5),
+    ("terjun bebas", -2.0),
+    ("buntung", -1.0),
+    ("gagal bayar", -2.0),
+    ("bangkrut", -2.0),
+    ("pailit", -2.0),
+    ("phk", -1.0),
+    ("bearish", -1.5),
+    ("misses", -1.0),
+    ("downgrade", -1.0),
+    ("layoffs", -1.0),
+    ("lambat", -1.0),
+    ("melambat", -1.0),
+    ("slows", -1.0),
+    ("cuts", -1.0),
+    ("underperform", -1.0),
+    ("sell", -1.0),
+    ("underweight", -1.0),
+];
+
+/// Words that flip the next sentiment term ("tidak naik", "gagal menguat")
+const NEGATORS: &[&str] = &[
+    "tidak", "tak", "bukan", "belum", "gagal", "batal", "not", "no", "never", "fails",
+];
+
+/// Words that strengthen an adjacent sentiment term ("turun tajam")
+const INTENSIFIERS: &[&str] = &[
+    "sangat", "makin", "semakin", "tajam", "signifikan", "drastis", "terus",
+    "sharply", "strongly", "surges",
+];
+
+/// Words that soften an adjacent sentiment term ("naik tipis")
+const DIMINISHERS: &[&str] = &["tipis", "sedikit", "terbatas", "slightly", "marginally"];
+
+/// Indonesian prefixes stripped from a word with no lexicon entry of its
+/// own, each with the letter a nasal prefix swallows before a vowel
+/// ("penurunan" is pen- + "turun" + -an)
+const PREFIXES: &[(&str, &str)] = &[
+    ("meng", "k"),
+    ("peng", "k"),
+    ("meny", "s"),
+    ("peny", "s"),
+    ("men", "t"),
+    ("pen", "t"),
+    ("mem", "p"),
+    ("pem", "p"),
+    ("meng", ""),
+    ("peng", ""),
+    ("men", ""),
+    ("pen", ""),
+    ("mem", ""),
+    ("pem", ""),
+    ("me", ""),
+    ("pe", ""),
+    ("ber", ""),
+    ("per", ""),
+    ("ter", ""),
+    ("ke", ""),
+    ("di", ""),
+];
+
+/// Suffixes stripped before the prefixes ("kenaikannya" reads as "naik")
+const SUFFIXES: &[&str] = &["kan", "an"];
+
+/// The forms a token can match the lexicon as: itself when the lexicon
+/// has it, else the token with Indonesian affixes stripped
+fn lexicon_forms(token: &str) -> Vec<String> {
+    let listed = SENTIMENT_LEXICON
+        .iter()
+        .any(|(phrase, _)| phrase.split(' ').any(|w| w == token));
+    if listed {
+        return vec![token.to_string()];
+    }
+
+    let base = token.strip_suffix("nya").unwrap_or(token);
+    let mut bases = vec![token, base];
+    bases.extend(SUFFIXES.iter().filter_map(|suf| base.strip_suffix(suf)));
+
+    let mut forms: Vec<String> = bases.iter().map(|b| b.to_string()).collect();
+    for base in &bases {
+        for (prefix, restore) in PREFIXES {
+            let Some(rest) = base.strip_prefix(prefix) else {
+                continue;
+            };
+            let before_vowel = rest.starts_with(['a', 'e', 'i', 'o', 'u']);
+            if restore.is_empty() || before_vowel {
+                forms.push(format!("{}{}", restore, rest));
+            }
+        }
+    }
+    forms.retain(|f| f.chars().count() >= 3);
+    forms
+}
+
+/// How many tokens back a negator still applies
+const NEGATION_WINDOW: usize = 2;
+
+/// Continuous sentiment score in (-1.0, 1.0). Handles multi-word phrases,
+/// negation, and intensity modifiers around each lexicon hit.
+pub fn sentiment_score(title: &str) -> f64 {
     let lower = title.to_lowercase();
+    let cleaned: String = lower
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+    let tokens: Vec<&str> = cleaned.split_whitespace().collect();
+    let forms: Vec<Vec<String>> = tokens.iter().map(|t| lexicon_forms(t)).collect();
 
-    let positive_words = [
-        "naik", "melonjak", "menguat", "rally", "cetak laba", "rekor",
-        "surplus", "tumbuh", "positif", "optimis", "bullish",
-        "melesat", "melejit", "cuan", "untung", "laba bersih",
-        "beats", "record", "upgrade", "growth", "raises",
-        "outperform", "buy", "overweight",
-    ];
+    let mut total = 0.0;
+    let mut i = 0;
+    while i < tokens.len() {
+        // Longest lexicon entry starting at this token
+        let hit = SENTIMENT_LEXICON
+            .iter()
+            .filter_map(|(phrase, weight)| {
+                let words: Vec<&str> = phrase.split(' ').collect();
+                let end = i + words.len();
+                let matches = end <= tokens.len()
+                    && words
+                        .iter()
+                        .zip(&forms[i..end])
+                        .all(|(word, candidates)| candidates.iter().any(|f| f == word));
+                matches.then_some((words.len(), *weight))
+            })
+            .max_by_key(|(len, _)| *len);
 
-    let negative_words = [
-        "turun", "anjlok", "melemah", "jatuh", "rugi", "defisit",
-        "resesi", "pesimis", "bearish", "koreksi", "tekanan",
-        "merosot", "ambles", "buntung", "gagal bayar",
-        "misses", "downgrade", "layoffs", "slows", "cuts",
-        "underperform", "sell", "underweight",
-    ];
+        let Some((len, mut weight)) = hit else {
+            i += 1;
+            continue;
+        };
+
+        let before = &tokens[i.saturating_sub(NEGATION_WINDOW)..i];
+        if before.iter().any(|t| NEGATORS.contains(t)) {
+            weight = -weight;
+        }
+        let neighbours = [i.checked_sub(1), Some(i + len)];
+        for idx in neighbours.into_iter().flatten() {
+            if let Some(t) = tokens.get(idx) {
+                if INTENSIFIERS.contains(t) {
+                    weight *= 1.5;
+                } else if DIMINISHERS.contains(t) {
+                    weight *= 0.5;
+                }
+            }
+        }
 
-    let pos_count = positive_words.iter().filter(|w| lower.contains(*w)).count();
-    let neg_count = negative_words.iter().filter(|w| lower.contains(*w)).count();
+        total += weight;
+        i += len;
+    }
 
-    if pos_count > neg_count {
+    (total / 2.0).tanh()
+}
+
+/// Bucket a continuous score into a label
+pub fn sentiment_from_score(score: f64) -> Sentiment {
+    if score >= 0.15 {
         Sentiment::Positive
-    } else if neg_count > pos_count {
+    } else if score <= -0.15 {
         Sentiment::Negative
     } else {
         Sentiment::Neutral
     }
 }
+
+pub fn analyze_sentiment(title: &str) -> Sentiment {
+    sentiment_from_score(sentiment_score(title))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Headlines as they run on Indonesian market news sites
+    const HEADLINES: &[(&str, Sentiment)] = &[
+        (
+            "IHSG Ditutup Menguat 1,2% ke Level 7.300",
+            Sentiment::Positive,
+        ),
+        (
+            "Laba Bersih BBCA Naik 12% pada Kuartal III",
+            Sentiment::Positive,
+        ),
+        (
+            "Kenaikan Harga Batu Bara Dorong Saham ADRO",
+            Sentiment::Positive,
+        ),
+        (
+            "Menguatnya Rupiah Angkat IHSG ke Zona Hijau",
+            Sentiment::Positive,
+        ),
+        (
+            "Pemulihan Ekonomi Dorong Kinerja Emiten Ritel",
+            Sentiment::Positive,
+        ),
+        (
+            "Keuntungan TLKM Melonjak Berkat Bisnis Data Center",
+            Sentiment::Positive,
+        ),
+        (
+            "Saham BREN Meroket, Kapitalisasi Pasar Cetak Rekor",
+            Sentiment::Positive,
+        ),
+        (
+            "Penurunan Laba Bank Mandiri Bebani Saham BMRI",
+            Sentiment::Negative,
+        ),
+        ("Rupiah Melemah terhadap Dolar AS", Sentiment::Negative),
+        (
+            "Melemahnya Permintaan China Bebani Harga Nikel",
+            Sentiment::Negative,
+        ),
+        (
+            "Kejatuhan Harga Minyak Seret Saham MEDC",
+            Sentiment::Negative,
+        ),
+        (
+            "Kerugian GOTO Membengkak pada Semester I",
+            Sentiment::Negative,
+        ),
+        (
+            "Saham UNVR Anjlok 5% Usai Rilis Laporan Keuangan",
+            Sentiment::Negative,
+        ),
+        (
+            "Sritex Resmi Dinyatakan Pailit oleh Pengadilan",
+            Sentiment::Negative,
+        ),
+        (
+            "IHSG Tidak Naik Meski Asing Borong Saham",
+            Sentiment::Negative,
+        ),
+        (
+            "Ekonomi China Melambat, Harga Komoditas Tertekan",
+            Sentiment::Negative,
+        ),
+        ("BI Tahan Suku Bunga Acuan di Level 6%", Sentiment::Neutral),
+        (
+            "Pengumuman Jadwal Pembagian Dividen TLKM",
+            Sentiment::Neutral,
+        ),
+        (
+            "Penawaran Umum Perdana Saham Dibuka Pekan Depan",
+            Sentiment::Neutral,
+        ),
+        ("Perusahaan Tambang Gelar RUPS Tahunan", Sentiment::Neutral),
+    ];
+
+    #[test]
+    fn headline_corpus() {
+        for (title, expected) in HEADLINES {
+            assert_eq!(analyze_sentiment(title), *expected, "{}", title);
+        }
+    }
+
+    #[test]
+    fn affixed_forms_score_like_their_roots() {
+        assert!(sentiment_score("kenaikan") > 0.0);
+        assert!(sentiment_score("penurunan") < 0.0);
+        assert!(sentiment_score("menguatnya") > 0.0);
+        assert!(sentiment_score("melemahnya") < 0.0);
+        assert!(sentiment_score("kenaikannya") > 0.0);
+    }
+
+    #[test]
+    fn modifiers_scale_the_score() {
+        let plain = sentiment_score("IHSG naik");
+        assert!(sentiment_score("IHSG naik tipis") < plain);
+        assert!(sentiment_score("IHSG naik tajam") > plain);
+    }
+}
@@ -0,0 +1,223 @@
+use crate::config::QuotesConfig;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch};
+
+/// Delayed price for a ticker as reported by the quote provider
+#[derive(Debug, Clone)]
+pub struct Quote {
+    pub ticker: String,
+    pub price: f64,
+    pub change_pct: f64,
+    pub fetched_at: i64,
+    /// Recent trading days whose close-to-close change crossed the
+    /// configured move threshold, oldest first
+    pub moves: Vec<PriceMove>,
+}
+
+/// One trading day with an unusually large price change
+#[derive(Debug, Clone, Copy)]
+pub struct PriceMove {
+    /// Start of the trading day (unix timestamp from the provider)
+    pub day: i64,
+    pub change_pct: f64,
+}
+
+impl PriceMove {
+    /// Whether `timestamp` falls on the move day or within `window`
+    /// seconds either side of it
+    pub fn covers(&self, timestamp: i64, window: i64) -> bool {
+        timestamp >= self.day - window && timestamp < self.day + 86400 + window
+    }
+}
+
+impl Quote {
+    /// Compact "+1.25%" style change label
+    pub fn change_label(&self) -> String {
+        format!("{:+.2}%", self.change_pct)
+    }
+}
+
+/// Gap between consecutive provider requests so a long watchlist doesn't
+/// burst the endpoint.
+const REQUEST_SPACING: Duration = Duration::from_millis(500);
+
+/// Daily bars only change once a day; refetch them at most this often
+const HISTORY_REFRESH: Duration = Duration::from_secs(3600);
+
+/// Fetch one provider symbol (`BBCA.JK`, `^JKSE`, `IDR=X`) and key the
+/// quote by `label`. The provider must answer with Yahoo's v8 chart JSON
+/// (`chart.result[0].meta.regularMarketPrice` / `chartPreviousClose`).
+async fn fetch_symbol(
+    client: &reqwest::Client,
+    cfg: &QuotesConfig,
+    symbol: &str,
+    label: &str,
+) -> Result<Quote, String> {
+    let url = cfg.url.replace("{symbol}", symbol);
+    let resp = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    let meta = &json["chart"]["result"][0]["meta"];
+    let price = meta["regularMarketPrice"]
+        .as_f64()
+        .ok_or_else(|| format!("No price for {}", symbol))?;
+    let prev = meta["chartPreviousClose"]
+        .as_f64()
+        .or_else(|| meta["previousClose"].as_f64())
+        .unwrap_or(price);
+    let change_pct = if prev > 0.0 {
+        (price - prev) / prev * 100.0
+    } else {
+        0.0
+    };
+
+    Ok(Quote {
+        ticker: label.to_string(),
+        price,
+        change_pct,
+        fetched_at: chrono::Utc::now().timestamp(),
+        moves: Vec::new(),
+    })
+}
+
+/// Fetch daily bars for `symbol` and keep the days whose change from the
+/// previous close is at least `threshold` percent either way
+async fn fetch_moves(
+    client: &reqwest::Client,
+    cfg: &QuotesConfig,
+    symbol: &str,
+) -> Result<Vec<PriceMove>, String> {
+    let url = cfg.history_url.replace("{symbol}", symbol);
+    let resp = client
+        .get(&url)
+        .send()
+        .await
+        .map_err(|e| format!("Network error: {}", e))?;
+    if !resp.status().is_success() {
+        return Err(format!("HTTP {}", resp.status()));
+    }
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Parse error: {}", e))?;
+
+    let result = &json["chart"]["result"][0];
+    let days: Vec<i64> = result["timestamp"]
+        .as_array()
+        .map(|a| a.iter().filter_map(|v| v.as_i64()).collect())
+        .unwrap_or_default();
+    let closes: Vec<Option<f64>> = result["indicators"]["quote"][0]["close"]
+        .as_array()
+        .map(|a| a.iter().map(|v| v.as_f64()).collect())
+        .unwrap_or_default();
+
+    let mut moves = Vec::new();
+    let mut prev: Option<f64> = None;
+    for (day, close) in days.iter().zip(closes) {
+        let Some(close) = close else {
+            continue;
+        };
+        if let Some(prev) = prev.filter(|p| *p > 0.0) {
+            let change_pct = (close - prev) / prev * 100.0;
+            if change_pct.abs() >= cfg.move_threshold {
+                moves.push(PriceMove {
+                    day: *day,
+                    change_pct,
+                });
+            }
+        }
+        prev = Some(close);
+    }
+    Ok(moves)
+}
+
+/// Long-running quote poller. Watches the set of tickers the UI cares
+/// about, refreshes any quote older than the configured interval, and sends
+/// each fresh batch back over `tx`. Quotes are cached here so switching
+/// ticker filters back and forth doesn't refetch. Market strip indicators
+/// ride along on the same schedule, keyed by their label. Tickers also
+/// carry their recent large moves when a move threshold is set.
+pub fn spawn_quote_task(
+    rt: &tokio::runtime::Runtime,
+    client: &reqwest::Client,
+    cfg: QuotesConfig,
+    mut tickers_rx: watch::Receiver<Vec<String>>,
+    tx: mpsc::Sender<Vec<Quote>>,
+) {
+    let client = client.clone();
+    rt.spawn(async move {
+        let interval = Duration::from_secs(cfg.interval.max(30));
+        let mut cache: HashMap<String, (Quote, Instant)> = HashMap::new();
+        let mut history: HashMap<String, (Vec<PriceMove>, Instant)> = HashMap::new();
+        loop {
+            let tickers = tickers_rx.borrow_and_update().clone();
+            let symbols = cfg
+                .indicators
+                .iter()
+                .map(|i| (i.label.clone(), i.symbol.clone()))
+                .chain(
+                    tickers
+                        .iter()
+                        .map(|t| (t.clone(), format!("{}{}", t, cfg.suffix))),
+                );
+            let mut fresh = Vec::new();
+            for (label, symbol) in symbols {
+                let stale = cache
+                    .get(&label)
+                    .map(|(_, at)| at.elapsed() >= interval)
+                    .unwrap_or(true);
+                if !stale {
+                    continue;
+                }
+                let Ok(mut quote) = fetch_symbol(&client, &cfg, &symbol, &label).await else {
+                    tokio::time::sleep(REQUEST_SPACING).await;
+                    continue;
+                };
+                tokio::time::sleep(REQUEST_SPACING).await;
+
+                if cfg.move_threshold > 0.0 && tickers.contains(&label) {
+                    let history_stale = history
+                        .get(&label)
+                        .map(|(_, at)| at.elapsed() >= HISTORY_REFRESH)
+                        .unwrap_or(true);
+                    if history_stale {
+                        if let Ok(moves) = fetch_moves(&client, &cfg, &symbol).await {
+                            history.insert(label.clone(), (moves, Instant::now()));
+                        }
+                        tokio::time::sleep(REQUEST_SPACING).await;
+                    }
+                    if let Some((moves, _)) = history.get(&label) {
+                        quote.moves = moves.clone();
+                    }
+                }
+
+                cache.insert(label, (quote.clone(), Instant::now()));
+                fresh.push(quote);
+            }
+            if !fresh.is_empty() && tx.send(fresh).await.is_err() {
+                return;
+            }
+
+            // Wake on the next interval or as soon as the ticker set changes
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                changed = tickers_rx.changed() => {
+                    if changed.is_err() {
+                        return;
+                    }
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,225 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// What a broker did to its call
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RatingAction {
+    Upgrade,
+    Downgrade,
+    Initiate,
+    Maintain,
+}
+
+impl RatingAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RatingAction::Upgrade => "upgrade",
+            RatingAction::Downgrade => "downgrade",
+            RatingAction::Initiate => "initiate",
+            RatingAction::Maintain => "maintain",
+        }
+    }
+
+    pub fn from_str(s: &str) -> Option<RatingAction> {
+        match s {
+            "upgrade" => Some(RatingAction::Upgrade),
+            "downgrade" => Some(RatingAction::Downgrade),
+            "initiate" => Some(RatingAction::Initiate),
+            "maintain" => Some(RatingAction::Maintain),
+            _ => None,
+        }
+    }
+
+    pub fn label(&self) -> &str {
+        match self {
+            RatingAction::Upgrade => "Upgrade",
+            RatingAction::Downgrade => "Downgrade",
+            RatingAction::Initiate => "Initiate",
+            RatingAction::Maintain => "Maintain",
+        }
+    }
+}
+
+/// A broker rating action on one ticker
+#[derive(Debug, Clone)]
+pub struct Rating {
+    pub ticker: String,
+    pub broker: Option<String>,
+    pub action: Option<RatingAction>,
+    pub rating: Option<String>,
+    pub target_price: Option<f64>,
+}
+
+/// A stored rating together with the article it came from
+#[derive(Debug, Clone)]
+pub struct RatingEntry {
+    pub rating: Rating,
+    pub article_id: i64,
+    pub title: String,
+    pub url: String,
+    pub published_at: i64,
+}
+
+const ACTION_RULES: &[(RatingAction, &[&str])] = &[
+    (
+        RatingAction::Upgrade,
+        &[
+            "upgrade",
+            "menaikkan rekomendasi",
+            "naikkan rekomendasi",
+            "kerek rekomendasi",
+        ],
+    ),
+    (
+        RatingAction::Downgrade,
+        &[
+            "downgrade",
+            "menurunkan rekomendasi",
+            "turunkan rekomendasi",
+            "pangkas rekomendasi",
+        ],
+    ),
+    (
+        RatingAction::Initiate,
+        &[
+            "initiate coverage",
+            "initiates coverage",
+            "memulai cakupan",
+            "mulai meliput",
+        ],
+    ),
+    (
+        RatingAction::Maintain,
+        &[
+            "mempertahankan rekomendasi",
+            "pertahankan rekomendasi",
+            "maintain",
+            "reiterate",
+        ],
+    ),
+];
+
+/// Rating words and the label they normalize to
+const RATING_WORDS: &[(&str, &str)] = &[
+    ("strong buy", "Strong Buy"),
+    ("trading buy", "Trading Buy"),
+    ("outperform", "Outperform"),
+    ("underperform", "Underperform"),
+    ("overweight", "Overweight"),
+    ("underweight", "Underweight"),
+    ("accumulate", "Accumulate"),
+    ("akumulasi", "Accumulate"),
+    ("neutral", "Neutral"),
+    ("netral", "Neutral"),
+    ("buy", "Buy"),
+    ("beli", "Buy"),
+    ("hold", "Hold"),
+    ("tahan", "Hold"),
+    ("sell", "Sell"),
+    ("jual", "Sell"),
+    ("reduce", "Reduce"),
+];
+
+const BROKERS: &[&str] = &[
+    "Mirae Asset",
+    "BRI Danareksa",
+    "Mandiri Sekuritas",
+    "BCA Sekuritas",
+    "Samuel Sekuritas",
+    "Trimegah",
+    "CGS International",
+    "CGS-CIMB",
+    "Kiwoom",
+    "Indo Premier",
+    "MNC Sekuritas",
+    "Phintraco",
+    "Ciptadana",
+    "Sucor",
+    "Verdhana",
+    "Bahana",
+    "Panin Sekuritas",
+    "NH Korindo",
+    "Valbury",
+    "Henan Putihrai",
+    "Maybank",
+    "RHB",
+    "DBS",
+    "Macquarie",
+    "Morgan Stanley",
+    "JPMorgan",
+    "JP Morgan",
+    "Goldman Sachs",
+    "UBS",
+    "Citi",
+    "CLSA",
+    "HSBC",
+    "Jefferies",
+    "Nomura",
+    "Deutsche Bank",
+];
+
+static TARGET_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(?:target\s+harga|target\s+price|TP)\b[^0-9]{0,25}?(?:Rp\.?\s*)?(\d{1,3}(?:[.,]\d{3})+|\d+)",
+    )
+    .unwrap()
+});
+static SEKURITAS_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"\b([A-Z][A-Za-z]+(?:\s+[A-Z][A-Za-z]+)?\s+Sekuritas)\b").unwrap()
+});
+
+/// Extract a broker rating action for `ticker` from a headline and
+/// optional body. Returns None unless the text reads like a rating call.
+pub fn extract_rating(text: &str, ticker: &str) -> Option<Rating> {
+    let lower = text.to_lowercase();
+
+    let action = ACTION_RULES
+        .iter()
+        .find(|(_, words)| words.iter().any(|w| lower.contains(w)))
+        .map(|(action, _)| *action);
+
+    // Thousands separators are "." in Indonesian copy and "," in English
+    let target_price = TARGET_RE
+        .captures(text)
+        .and_then(|c| c[1].replace(['.', ','], "").parse::<f64>().ok())
+        .filter(|p| *p > 0.0);
+
+    let broker = BROKERS
+        .iter()
+        .find(|b| text.contains(*b))
+        .map(|b| b.to_string())
+        .or_else(|| SEKURITAS_RE.captures(text).map(|c| c[1].to_string()));
+
+    let rating_context = lower.contains("rekomendasi")
+        || lower.contains("rating")
+        || lower.contains("analis")
+        || broker.is_some();
+    let rating = rating_context
+        .then(|| {
+            RATING_WORDS
+                .iter()
+                .find(|(w, _)| contains_word(&lower, w))
+                .map(|(_, label)| label.to_string())
+        })
+        .flatten();
+
+    if action.is_none() && target_price.is_none() && rating.is_none() {
+        return None;
+    }
+
+    Some(Rating {
+        ticker: ticker.to_string(),
+        broker,
+        action,
+        rating,
+        target_price,
+    })
+}
+
+fn contains_word(text: &str, word: &str) -> bool {
+    text.match_indices(word).any(|(pos, _)| {
+        let before = text[..pos].chars().next_back();
+        let after = text[pos + word.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
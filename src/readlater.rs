@@ -0,0 +1,100 @@
+use crate::config::ReadLaterConfig;
+
+/// A read-later service articles can be sent to
+#[derive(Debug, Clone)]
+pub enum Service {
+    Pocket {
+        consumer_key: String,
+        access_token: String,
+    },
+    Instapaper {
+        username: String,
+        password: String,
+    },
+    /// Self-hosted or wallabag.it; `url` is the instance base URL
+    Wallabag {
+        url: String,
+        token: String,
+    },
+    Readwise {
+        token: String,
+    },
+}
+
+impl Service {
+    /// Build the configured service, or None when it's unset or a
+    /// credential is missing
+    pub fn from_config(cfg: &ReadLaterConfig) -> Option<Service> {
+        let token = cfg
+            .token_env
+            .as_deref()
+            .and_then(|var| std::env::var(var).ok());
+        match cfg.service.as_deref()? {
+            "pocket" => Some(Service::Pocket {
+                consumer_key: cfg.consumer_key.clone()?,
+                access_token: token?,
+            }),
+            "instapaper" => Some(Service::Instapaper {
+                username: cfg.username.clone()?,
+                password: token.unwrap_or_default(),
+            }),
+            "wallabag" => Some(Service::Wallabag {
+                url: cfg.url.clone()?.trim_end_matches('/').to_string(),
+                token: token?,
+            }),
+            "readwise" => Some(Service::Readwise { token: token? }),
+            _ => None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        match self {
+            Service::Pocket { .. } => "Pocket",
+            Service::Instapaper { .. } => "Instapaper",
+            Service::Wallabag { .. } => "Wallabag",
+            Service::Readwise { .. } => "Readwise",
+        }
+    }
+
+    /// Save `url` to the service
+    pub async fn send(
+        &self,
+        client: &reqwest::Client,
+        url: &str,
+        title: &str,
+    ) -> Result<(), String> {
+        let req = match self {
+            Service::Pocket {
+                consumer_key,
+                access_token,
+            } => client
+                .post("https://getpocket.com/v3/add")
+                .json(&serde_json::json!({
+                    "url": url,
+                    "title": title,
+                    "consumer_key": consumer_key,
+                    "access_token": access_token,
+                })),
+            Service::Instapaper { username, password } => client
+                .post("https://www.instapaper.com/api/add")
+                .basic_auth(username, Some(password))
+                .query(&[("url", url), ("title", title)]),
+            Service::Wallabag { url: base, token } => client
+                .post(format!("{}/api/entries.json", base))
+                .bearer_auth(token)
+                .json(&serde_json::json!({ "url": url, "title": title })),
+            Service::Readwise { token } => client
+                .post("https://readwise.io/api/v3/save/")
+                .header("Authorization", format!("Token {}", token))
+                .json(&serde_json::json!({ "url": url, "title": title })),
+        };
+        let resp = req
+            .send()
+            .await
+            .map_err(|e| format!("Network error: {}", e))?;
+        if !resp.status().is_success() {
+            return Err(format!("HTTP {}", resp.status()));
+        }
+        Ok(())
+    }
+}
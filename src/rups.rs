@@ -0,0 +1,217 @@
+use crate::model::Article;
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// Meetings this many days out (or closer) are flagged as reminders
+pub const REMIND_DAYS: i64 = 3;
+
+/// Annual (RUPST) vs extraordinary (RUPSLB) shareholder meeting
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeetingKind {
+    Annual,
+    Extraordinary,
+    Unspecified,
+}
+
+impl MeetingKind {
+    pub fn label(&self) -> &str {
+        match self {
+            MeetingKind::Annual => "RUPST",
+            MeetingKind::Extraordinary => "RUPSLB",
+            MeetingKind::Unspecified => "RUPS",
+        }
+    }
+}
+
+/// An announced shareholder meeting with a known date
+#[derive(Debug, Clone)]
+pub struct Meeting {
+    pub ticker: String,
+    pub kind: MeetingKind,
+    pub date: NaiveDate,
+    pub article_id: i64,
+    pub title: String,
+    pub url: String,
+}
+
+impl Meeting {
+    /// Days from `today` until the meeting; negative once it has passed
+    pub fn days_until(&self, today: NaiveDate) -> i64 {
+        (self.date - today).num_days()
+    }
+
+    /// "today", "tomorrow", or "in N days"
+    pub fn when_label(&self, today: NaiveDate) -> String {
+        match self.days_until(today) {
+            0 => "today".to_string(),
+            1 => "tomorrow".to_string(),
+            d => format!("in {} days", d),
+        }
+    }
+}
+
+static MEETING_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)\b(rupslb|rupst|rups|rapat\s+umum\s+pemegang\s+saham(?:\s+(?:tahunan|luar\s+biasa))?|(?:annual|extraordinary)\s+general\s+meeting|shareholders?'?\s+meeting|agm|egm)\b",
+    )
+    .unwrap()
+});
+
+const MONTHS: &str = "januari|februari|maret|april|mei|juni|juli|agustus|september|oktober|november|desember|january|february|march|may|june|july|august|october|december|jan|feb|mar|apr|jun|jul|agu|aug|sep|okt|oct|nov|des|dec";
+
+/// "27 Mei 2025", "3 Juni"
+static DAY_MONTH_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(\d{{1,2}})\s+({})\b\.?(?:\s+(20\d{{2}}))?",
+        MONTHS
+    ))
+    .unwrap()
+});
+/// "May 27, 2025"
+static MONTH_DAY_RE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b({})\.?\s+(\d{{1,2}})\b,?(?:\s+(20\d{{2}}))?",
+        MONTHS
+    ))
+    .unwrap()
+});
+
+/// How far past a meeting keyword to look for its date, in bytes
+const DATE_WINDOW: usize = 240;
+
+fn month_number(name: &str) -> Option<u32> {
+    let name = name.to_lowercase();
+    let month = match &name[..name.len().min(3)] {
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "mei" | "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "agu" | "aug" => 8,
+        "sep" => 9,
+        "okt" | "oct" => 10,
+        "nov" => 11,
+        "des" | "dec" => 12,
+        _ => return None,
+    };
+    Some(month)
+}
+
+/// First date in `text`. A missing year means the next such date on or
+/// after `published`, since announcements look ahead.
+fn find_date(text: &str, published: NaiveDate) -> Option<NaiveDate> {
+    let day_month = DAY_MONTH_RE
+        .captures(text)
+        .map(|c| (c.get(0).map(|m| m.start()), c.get(1), c.get(2), c.get(3)));
+    let month_day = MONTH_DAY_RE
+        .captures(text)
+        .map(|c| (c.get(0).map(|m| m.start()), c.get(2), c.get(1), c.get(3)));
+    let (_, day, month, year) = match (day_month, month_day) {
+        (Some(a), Some(b)) if b.0 < a.0 => b,
+        (Some(a), _) => a,
+        (None, Some(b)) => b,
+        (None, None) => return None,
+    };
+
+    let day: u32 = day?.as_str().parse().ok()?;
+    let month = month_number(month?.as_str())?;
+    match year.and_then(|y| y.as_str().parse::<i32>().ok()) {
+        Some(year) => NaiveDate::from_ymd_opt(year, month, day),
+        None => {
+            let date = NaiveDate::from_ymd_opt(published.year(), month, day)?;
+            if date < published {
+                NaiveDate::from_ymd_opt(published.year() + 1, month, day)
+            } else {
+                Some(date)
+            }
+        }
+    }
+}
+
+/// Detect a shareholder meeting announcement and, when the text names
+/// one, the meeting date. The date is taken from just after a meeting
+/// keyword so cum dates and record dates elsewhere don't win.
+pub fn extract_meeting(text: &str, published_at: i64) -> Option<(MeetingKind, Option<NaiveDate>)> {
+    let published = chrono::DateTime::from_timestamp(published_at, 0)?.date_naive();
+    let mut kind = None;
+    let mut date = None;
+
+    for m in MEETING_RE.find_iter(text) {
+        let keyword = m.as_str().to_lowercase();
+        let this_kind = if keyword.starts_with("rupslb")
+            || keyword.contains("luar biasa")
+            || keyword.starts_with("extraordinary")
+            || keyword == "egm"
+        {
+            MeetingKind::Extraordinary
+        } else if keyword.starts_with("rupst")
+            || keyword.contains("tahunan")
+            || keyword.starts_with("annual")
+            || keyword == "agm"
+        {
+            MeetingKind::Annual
+        } else {
+            MeetingKind::Unspecified
+        };
+        if kind.is_none() || kind == Some(MeetingKind::Unspecified) {
+            kind = Some(this_kind);
+        }
+
+        if date.is_none() {
+            let mut end = (m.end() + DATE_WINDOW).min(text.len());
+            while !text.is_char_boundary(end) {
+                end -= 1;
+            }
+            date = find_date(&text[m.end()..end], published);
+        }
+    }
+
+    kind.map(|k| (k, date))
+}
+
+/// Upcoming meetings for `tickers` (all tickers when empty), soonest
+/// first. `articles` pairs each candidate with its stored body, if any.
+/// A ticker's meeting announced by several articles is listed once.
+pub fn upcoming(
+    articles: &[(Article, Option<String>)],
+    tickers: &[String],
+    today: NaiveDate,
+) -> Vec<Meeting> {
+    let mut meetings: Vec<Meeting> = Vec::new();
+    for (article, content) in articles {
+        let Some(ticker) = article
+            .tickers
+            .iter()
+            .find(|t| tickers.is_empty() || tickers.contains(t))
+        else {
+            continue;
+        };
+        let text = match content {
+            Some(body) => format!("{}\n{}", article.title, body),
+            None => article.title.to_string(),
+        };
+        let Some((kind, Some(date))) = extract_meeting(&text, article.published_at) else {
+            continue;
+        };
+        if date < today
+            || meetings
+                .iter()
+                .any(|m| m.ticker == *ticker && m.date == date)
+        {
+            continue;
+        }
+        meetings.push(Meeting {
+            ticker: ticker.clone(),
+            kind,
+            date,
+            article_id: article.id,
+            title: article.title.to_string(),
+            url: article.url.to_string(),
+        });
+    }
+    meetings.sort_by(|a, b| a.date.cmp(&b.date).then_with(|| a.ticker.cmp(&b.ticker)));
+    meetings
+}
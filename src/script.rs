@@ -0,0 +1,143 @@
+use crate::model::{sentiment_from_score, Article};
+use rhai::{Array, CallFnOptions, Dynamic, Engine, Map, Scope, AST};
+use std::path::Path;
+
+/// Upper bound on work per hook call, so a runaway loop can't hang a refresh
+const MAX_OPERATIONS: u64 = 1_000_000;
+
+const ON_INSERT: &str = "on_insert";
+const ON_DISPLAY: &str = "on_display";
+const ON_KEY: &str = "on_key";
+
+/// A user's Rhai script from `script = "..."`. Any of its hooks may be
+/// left out:
+///
+/// - `fn on_insert()` runs on each fetched article before it is stored
+/// - `fn on_display()` runs on each article loaded into the feed
+/// - `fn on_key(key)` runs for a key the feed doesn't use; a string it
+///   returns is shown in the status bar
+///
+/// Each hook sees the article as `this`, a map with `title`, `source`,
+/// `url`, `tickers`, `event`, `read`, and `bookmarked`, plus `score`
+/// (-1.0 to 1.0), `tags`, and `mute`, which it may change. A muted article
+/// is not stored by `on_insert` and not shown by the other two.
+pub struct Script {
+    engine: Engine,
+    ast: AST,
+}
+
+impl Script {
+    pub fn load(path: &Path) -> Result<Script, String> {
+        let mut engine = Engine::new();
+        engine.set_max_operations(MAX_OPERATIONS);
+        // Output from print() or debug() would land on top of the TUI
+        engine.on_print(|_| {});
+        engine.on_debug(|_, _, _| {});
+        let ast = engine
+            .compile_file(path.to_path_buf())
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+        Ok(Script { engine, ast })
+    }
+
+    /// Returns false when the script mutes the article
+    pub fn on_insert(&self, article: &mut Article) -> Result<bool, String> {
+        self.run(ON_INSERT, article, Vec::new())
+            .map(|(keep, _)| keep)
+    }
+
+    /// Returns false when the script mutes the article
+    pub fn on_display(&self, article: &mut Article) -> Result<bool, String> {
+        self.run(ON_DISPLAY, article, Vec::new())
+            .map(|(keep, _)| keep)
+    }
+
+    /// Whether the script has an `on_key` hook to hand unused keys to
+    pub fn handles_keys(&self) -> bool {
+        self.has_hook(ON_KEY, 1)
+    }
+
+    /// Returns whether the article is still shown, and the status text
+    /// the hook returned, if any
+    pub fn on_key(
+        &self,
+        key: char,
+        article: &mut Article,
+    ) -> Result<(bool, Option<String>), String> {
+        self.run(ON_KEY, article, vec![Dynamic::from(key.to_string())])
+    }
+
+    fn has_hook(&self, name: &str, params: usize) -> bool {
+        self.ast
+            .iter_functions()
+            .any(|f| f.name == name && f.params.len() == params)
+    }
+
+    fn run(
+        &self,
+        hook: &str,
+        article: &mut Article,
+        args: Vec<Dynamic>,
+    ) -> Result<(bool, Option<String>), String> {
+        if !self.has_hook(hook, args.len()) {
+            return Ok((true, None));
+        }
+        let mut this = Dynamic::from_map(article_map(article));
+        // Only the hook runs; top-level statements are skipped
+        let options = CallFnOptions::new()
+            .eval_ast(false)
+            .bind_this_ptr(&mut this);
+        let result: Dynamic = self
+            .engine
+            .call_fn_with_options(options, &mut Scope::new(), &self.ast, hook, args)
+            .map_err(|e| format!("{}: {}", hook, e))?;
+        let keep = apply_map(article, this);
+        Ok((keep, result.into_string().ok().filter(|s| !s.is_empty())))
+    }
+}
+
+fn article_map(article: &Article) -> Map {
+    let strings =
+        |items: &[String]| -> Array { items.iter().cloned().map(Dynamic::from).collect() };
+    let mut map = Map::new();
+    map.insert("title".into(), article.title.to_string().into());
+    map.insert("source".into(), article.source.to_string().into());
+    map.insert("url".into(), article.url.to_string().into());
+    map.insert("tickers".into(), strings(&article.tickers).into());
+    map.insert(
+        "event".into(),
+        article.event.map(|e| e.as_str()).unwrap_or_default().into(),
+    );
+    map.insert("read".into(), article.read.into());
+    map.insert("bookmarked".into(), article.bookmarked.into());
+    map.insert("score".into(), article.sentiment_score.into());
+    map.insert("tags".into(), strings(&article.topics).into());
+    map.insert("mute".into(), false.into());
+    map
+}
+
+/// Copy the writable fields back from the script's map. Returns false
+/// when the script set `mute`.
+fn apply_map(article: &mut Article, this: Dynamic) -> bool {
+    let Some(map) = this.try_cast::<Map>() else {
+        return true;
+    };
+    let score = map.get("score").and_then(|v| {
+        v.as_float()
+            .ok()
+            .or_else(|| v.as_int().ok().map(|n| n as f64))
+    });
+    if let Some(score) = score {
+        article.sentiment_score = score.clamp(-1.0, 1.0);
+        article.sentiment = sentiment_from_score(article.sentiment_score);
+    }
+    if let Some(tags) = map.get("tags").and_then(|v| v.clone().try_cast::<Array>()) {
+        article.topics = tags
+            .into_iter()
+            .filter_map(|t| t.into_string().ok())
+            .map(|t| t.to_lowercase())
+            .collect();
+    }
+    !map.get("mute")
+        .and_then(|v| v.as_bool().ok())
+        .unwrap_or(false)
+}
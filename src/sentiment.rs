@@ -0,0 +1,115 @@
+use crate::config::SentimentBackendConfig;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+/// External sentiment scorer used in place of the keyword analyzer once an
+/// article is stored. Either an HTTP endpoint (e.g. an LLM or hosted
+/// model) or a local command (e.g. a script wrapping an ONNX model).
+#[derive(Debug, Clone)]
+pub enum Backend {
+    /// POSTs `{"text": ...}` and reads `score` or `label` from the JSON reply
+    Http { url: String, token: Option<String> },
+    /// Runs `sh -c command` with the text on stdin; stdout is a score or label
+    Command { command: String },
+}
+
+impl Backend {
+    /// Build the configured backend, or None to keep the keyword analyzer
+    pub fn from_config(cfg: &SentimentBackendConfig) -> Option<Backend> {
+        match cfg.kind.as_deref()? {
+            "http" => Some(Backend::Http {
+                url: cfg.url.clone()?,
+                token: cfg
+                    .token_env
+                    .as_deref()
+                    .and_then(|var| std::env::var(var).ok()),
+            }),
+            "command" => Some(Backend::Command {
+                command: cfg.command.clone()?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Score text from -1.0 (negative) to 1.0 (positive)
+    pub async fn score(&self, client: &reqwest::Client, text: &str) -> Result<f64, String> {
+        match self {
+            Backend::Http { url, token } => {
+                let mut req = client.post(url).json(&serde_json::json!({ "text": text }));
+                if let Some(token) = token {
+                    req = req.bearer_auth(token);
+                }
+                let resp = req
+                    .send()
+                    .await
+                    .map_err(|e| format!("Network error: {}", e))?;
+                if !resp.status().is_success() {
+                    return Err(format!("HTTP {}", resp.status()));
+                }
+                let json: serde_json::Value = resp
+                    .json()
+                    .await
+                    .map_err(|e| format!("Parse error: {}", e))?;
+                json["score"]
+                    .as_f64()
+                    .or_else(|| json["label"].as_str().and_then(parse_output))
+                    .map(|s| s.clamp(-1.0, 1.0))
+                    .ok_or_else(|| "Response has no score or label".to_string())
+            }
+            Backend::Command { command } => {
+                let mut child = tokio::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(command)
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .kill_on_drop(true)
+                    .spawn()
+                    .map_err(|e| format!("Spawn error: {}", e))?;
+                if let Some(mut stdin) = child.stdin.take() {
+                    let _ = stdin.write_all(text.as_bytes()).await;
+                }
+                let output = child
+                    .wait_with_output()
+                    .await
+                    .map_err(|e| format!("Command error: {}", e))?;
+                if !output.status.success() {
+                    return Err(format!("Command exited with {}", output.status));
+                }
+                parse_output(&String::from_utf8_lossy(&output.stdout))
+                    .map(|s| s.clamp(-1.0, 1.0))
+                    .ok_or_else(|| "Command printed no score or label".to_string())
+            }
+        }
+    }
+}
+
+/// Accept either a number or a positive/negative/neutral label
+fn parse_output(text: &str) -> Option<f64> {
+    let text = text.trim();
+    if let Ok(score) = text.parse::<f64>() {
+        return Some(score);
+    }
+    match text.to_lowercase().as_str() {
+        "positive" | "bullish" => Some(1.0),
+        "negative" | "bearish" => Some(-1.0),
+        "neutral" => Some(0.0),
+        _ => None,
+    }
+}
+
+/// Score each (article id, title) in turn. Articles the backend fails on
+/// are left out and keep their keyword score.
+pub async fn score_batch(
+    backend: &Backend,
+    client: &reqwest::Client,
+    items: Vec<(i64, String)>,
+) -> Vec<(i64, f64)> {
+    let mut scored = Vec::new();
+    for (id, title) in items {
+        if let Ok(score) = backend.score(client, &title).await {
+            scored.push((id, score));
+        }
+    }
+    scored
+}
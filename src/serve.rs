@@ -0,0 +1,223 @@
+use crate::digest::escape_html;
+use crate::model::{Article, Sentiment, TimeDisplay};
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::time::Duration;
+
+/// A client that stalls mid-request is dropped after this
+const READ_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest request head read before giving up on the client
+const MAX_HEAD_BYTES: usize = 8 * 1024;
+
+/// Answer requests one at a time until the listener fails. `page` renders
+/// the HTML for `/` fresh on every request, so the page always matches
+/// the database. Anything other than GET or HEAD is refused.
+pub fn run(listener: TcpListener, page: impl Fn() -> Result<String, String>) -> io::Result<()> {
+    for stream in listener.incoming() {
+        let mut stream = match stream {
+            Ok(stream) => stream,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        let _ = stream.set_read_timeout(Some(READ_TIMEOUT));
+        // A misbehaving client only loses its own response
+        let _ = handle(&mut stream, &page);
+    }
+    Ok(())
+}
+
+fn handle(stream: &mut TcpStream, page: &impl Fn() -> Result<String, String>) -> io::Result<()> {
+    let Some((method, path)) = read_request(stream)? else {
+        return respond(
+            stream,
+            "400 Bad Request",
+            "text/plain",
+            "Bad request\n",
+            true,
+        );
+    };
+    let head_only = method == "HEAD";
+    if method != "GET" && !head_only {
+        return respond(
+            stream,
+            "405 Method Not Allowed",
+            "text/plain",
+            "Read-only: only GET is supported\n",
+            true,
+        );
+    }
+    // Query strings are ignored; there is only the one page
+    match path.split('?').next().unwrap_or_default() {
+        "/" => match page() {
+            Ok(html) => respond(
+                stream,
+                "200 OK",
+                "text/html; charset=utf-8",
+                &html,
+                !head_only,
+            ),
+            Err(e) => respond(
+                stream,
+                "500 Internal Server Error",
+                "text/plain",
+                &format!("{}\n", e),
+                !head_only,
+            ),
+        },
+        _ => respond(
+            stream,
+            "404 Not Found",
+            "text/plain",
+            "Not found\n",
+            !head_only,
+        ),
+    }
+}
+
+/// Method and path from the request line; the headers are read and dropped
+fn read_request(stream: &TcpStream) -> io::Result<Option<(String, String)>> {
+    let mut reader = BufReader::new(stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    let mut read = request_line.len();
+    loop {
+        let mut header = String::new();
+        let n = reader.read_line(&mut header)?;
+        read += n;
+        if n == 0 || header.trim().is_empty() || read > MAX_HEAD_BYTES {
+            break;
+        }
+    }
+
+    let mut parts = request_line.split_whitespace();
+    match (parts.next(), parts.next()) {
+        (Some(method), Some(path)) => Ok(Some((method.to_string(), path.to_string()))),
+        _ => Ok(None),
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: &str,
+    content_type: &str,
+    body: &str,
+    with_body: bool,
+) -> io::Result<()> {
+    let mut out = format!(
+        "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nCache-Control: no-store\r\nConnection: close\r\n",
+        status,
+        content_type,
+        body.len()
+    );
+    if status.starts_with("405") {
+        out.push_str("Allow: GET, HEAD\r\n");
+    }
+    out.push_str("\r\n");
+    if with_body {
+        out.push_str(body);
+    }
+    stream.write_all(out.as_bytes())?;
+    stream.flush()
+}
+
+/// The whole page: bookmarks first, then one section per saved search
+pub fn render_page(
+    bookmarks: &[Article],
+    searches: &[(String, Vec<Article>)],
+    time: &TimeDisplay,
+) -> String {
+    let mut out = String::from(
+        "<!DOCTYPE html>\n<html><head><meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>StockNewsTUI</title>\n\
+         <style>\n\
+         body { font-family: sans-serif; margin: 0 auto; max-width: 48em; padding: 0 1em; line-height: 1.4 }\n\
+         nav a { margin-right: 1em }\n\
+         ul { list-style: none; padding: 0 }\n\
+         li { padding: 0.5em 0; border-bottom: 1px solid #ddd }\n\
+         a { text-decoration: none }\n\
+         small { color: #757575 }\n\
+         </style>\n</head><body>\n<h2>StockNewsTUI</h2>\n",
+    );
+
+    out.push_str("<nav><a href=\"#bookmarks\">Bookmarks</a>");
+    for (i, (query, _)) in searches.iter().enumerate() {
+        out.push_str(&format!(
+            "<a href=\"#search-{}\">{}</a>",
+            i,
+            escape_html(query)
+        ));
+    }
+    out.push_str("</nav>\n");
+
+    render_section(&mut out, "bookmarks", "Bookmarks", bookmarks, time);
+    for (i, (query, articles)) in searches.iter().enumerate() {
+        let heading = format!("Search: {}", query);
+        render_section(&mut out, &format!("search-{}", i), &heading, articles, time);
+    }
+
+    out.push_str(&format!(
+        "<p><small>Rendered {}</small></p>\n</body></html>\n",
+        time.format(chrono::Utc::now().timestamp(), "%Y-%m-%d %H:%M")
+    ));
+    out
+}
+
+fn render_section(
+    out: &mut String,
+    id: &str,
+    heading: &str,
+    articles: &[Article],
+    time: &TimeDisplay,
+) {
+    out.push_str(&format!(
+        "<h3 id=\"{}\">{} ({})</h3>\n",
+        id,
+        escape_html(heading),
+        articles.len()
+    ));
+    if articles.is_empty() {
+        out.push_str("<p><small>Nothing here yet</small></p>\n");
+        return;
+    }
+    out.push_str("<ul>\n");
+    for article in articles {
+        let color = match article.sentiment {
+            Sentiment::Positive => "#2e7d32",
+            Sentiment::Negative => "#c62828",
+            Sentiment::Neutral => "#757575",
+        };
+        // Stored URLs come from feeds, so only web links become clickable
+        let title = if is_web_url(&article.url) {
+            format!(
+                "<a href=\"{}\">{}</a>",
+                escape_html(&article.url),
+                escape_html(&article.title)
+            )
+        } else {
+            escape_html(&article.title)
+        };
+        out.push_str(&format!(
+            "<li><b style=\"color: {}\">{}</b> {}<br><small>{} &middot; {}{}</small></li>\n",
+            color,
+            article.sentiment.label(),
+            title,
+            escape_html(&article.source),
+            time.format(article.published_at, "%m-%d %H:%M"),
+            if article.tickers.is_empty() {
+                String::new()
+            } else {
+                format!(" &middot; {}", escape_html(&article.tickers.join(", ")))
+            }
+        ));
+    }
+    out.push_str("</ul>\n");
+}
+
+/// Whether a URL is plain http(s), rather than `javascript:`, `data:`, or
+/// another scheme a browser would act on when clicked
+fn is_web_url(url: &str) -> bool {
+    let lower = url.trim_start().to_ascii_lowercase();
+    lower.starts_with("http://") || lower.starts_with("https://")
+}
@@ -1,35 +1,105 @@
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use std::fs;
 use std::path::PathBuf;
 
-#[derive(Debug, Serialize, Deserialize, Default)]
+/// Layout version written to state.json. Bump it when a field changes
+/// meaning or shape, and teach `migrate` to convert the old form.
+pub const STATE_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize, Default, PartialEq)]
 pub struct ViewState {
+    /// 0 for files written before state was versioned
+    #[serde(default)]
+    pub version: u32,
     pub filter_mode: Option<String>,
     pub search_query: Option<String>,
     pub ticker_filter: Option<String>,
+    #[serde(default)]
+    pub event_filter: Option<String>,
+    #[serde(default)]
+    pub language_filter: Option<String>,
+    #[serde(default)]
+    pub sort_mode: Option<String>,
+    #[serde(default)]
+    pub market_filter: Option<String>,
+    #[serde(default)]
+    pub topic_filter: Option<String>,
+    #[serde(default)]
+    pub regulatory_only: bool,
     pub theme_name: Option<String>,
     pub selected_index: Option<usize>,
+    /// First visible feed row, so the list reopens where it was scrolled
+    #[serde(default)]
+    pub scroll_offset: usize,
+    #[serde(default)]
+    pub sources_selected: usize,
+    /// Past search queries, oldest first
+    #[serde(default)]
+    pub search_history: Vec<String>,
+    /// Ticker filters used lately, newest first
+    #[serde(default)]
+    pub recent_tickers: Vec<String>,
+    /// When the session that wrote this state was last saved
+    #[serde(default)]
+    pub session_end: Option<i64>,
 }
 
 fn state_path() -> PathBuf {
-    let dir = dirs::data_dir()
-        .unwrap_or_else(|| PathBuf::from("."))
-        .join("stocknewstui");
-    let _ = fs::create_dir_all(&dir);
-    dir.join("state.json")
+    crate::config::state_dir().join("state.json")
 }
 
 pub fn load_state() -> ViewState {
     let path = state_path();
-    fs::read_to_string(&path)
-        .ok()
-        .and_then(|s| serde_json::from_str(&s).ok())
-        .unwrap_or_default()
+    // A damaged file falls back to the backup from the save before it.
+    // Older versions kept state.json next to the database.
+    [
+        path.clone(),
+        crate::config::sibling(&path, ".bak"),
+        crate::config::data_dir().join("state.json"),
+    ]
+    .iter()
+    .find_map(|p| {
+        fs::read_to_string(p)
+            .ok()
+            .and_then(|s| serde_json::from_str::<Value>(&s).ok())
+            .and_then(parse_state)
+    })
+    .unwrap_or_default()
+}
+
+/// Bring an older layout up to date, then read it field by field so one
+/// field that no longer fits resets only itself, not the whole state
+fn parse_state(value: Value) -> Option<ViewState> {
+    let Value::Object(mut fields) = value else {
+        return None;
+    };
+    let version = fields.get("version").and_then(Value::as_u64).unwrap_or(0);
+    migrate(&mut fields, version as u32);
+
+    let mut merged = serde_json::Map::new();
+    for (key, field) in fields {
+        merged.insert(key.clone(), field);
+        if serde_json::from_value::<ViewState>(Value::Object(merged.clone())).is_err() {
+            merged.remove(&key);
+        }
+    }
+    let mut state: ViewState = serde_json::from_value(Value::Object(merged)).ok()?;
+    state.version = STATE_VERSION;
+    Some(state)
+}
+
+/// Step a state file from `version` to `STATE_VERSION`. Unversioned files
+/// differ from version 1 only by missing fields, which take their
+/// defaults, so there is nothing to convert yet; a layout change adds an
+/// `if version < N` step here.
+fn migrate(fields: &mut serde_json::Map<String, Value>, version: u32) {
+    let _ = (fields, version);
 }
 
 pub fn save_state(state: &ViewState) {
     let path = state_path();
     if let Ok(json) = serde_json::to_string_pretty(state) {
-        let _ = fs::write(path, json);
+        let _ = crate::config::write_atomic(&path, &json);
     }
 }
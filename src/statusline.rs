@@ -0,0 +1,127 @@
+use crate::config::default_status_format;
+use crate::db::{Db, LAST_REFRESH_KEY};
+use crate::model::{watchlist_hit, TimeDisplay};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Unread articles looked through when counting watchlist hits
+const UNREAD_SCAN: usize = 5000;
+
+/// How far back the trending ticker is counted
+const TRENDING_WINDOW_SECS: i64 = 24 * 3600;
+
+/// Counts for a status bar or prompt
+pub struct Status {
+    pub unread: i64,
+    /// Unread articles about watchlist tickers
+    pub watchlist: usize,
+    /// Alerts not yet acknowledged
+    pub alerts: i64,
+    /// Ticker in the most articles over the last day, with that count
+    pub trending: Option<(String, i64)>,
+    /// Unix time the last refresh finished
+    pub last_refresh: Option<i64>,
+}
+
+impl Status {
+    pub fn gather(db: &Db, watchlist: &[String]) -> Status {
+        // Nothing can hit an empty watchlist, so skip the scan
+        let hits = if watchlist.is_empty() {
+            0
+        } else {
+            db.get_unread_articles(UNREAD_SCAN)
+                .unwrap_or_default()
+                .iter()
+                .filter(|a| watchlist_hit(a, watchlist))
+                .count()
+        };
+        let since = chrono::Utc::now().timestamp() - TRENDING_WINDOW_SECS;
+        let trending = db
+            .ticker_sentiment_since(since)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(ticker, _, count)| (ticker, count))
+            .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)));
+        Status {
+            unread: db.unread_count().unwrap_or(0),
+            watchlist: hits,
+            alerts: db.unacknowledged_alert_count().unwrap_or(0),
+            trending,
+            last_refresh: db
+                .get_meta(LAST_REFRESH_KEY)
+                .ok()
+                .flatten()
+                .and_then(|ts| ts.parse().ok()),
+        }
+    }
+
+    /// Fill in the placeholders of `format`: {unread}, {watchlist},
+    /// {alerts}, {trending}, and {last_refresh}. "json" gives the waybar
+    /// object instead.
+    pub fn render(&self, format: &str, time: &TimeDisplay) -> String {
+        if format == "json" {
+            return self.waybar(time).to_string();
+        }
+        let last_refresh = self
+            .last_refresh
+            .map(|ts| time.format(ts, "%H:%M"))
+            .unwrap_or_else(|| "never".to_string());
+        let trending = self
+            .trending
+            .as_ref()
+            .map(|(ticker, _)| ticker.as_str())
+            .unwrap_or("-");
+        format
+            .replace("{unread}", &self.unread.to_string())
+            .replace("{watchlist}", &self.watchlist.to_string())
+            .replace("{alerts}", &self.alerts.to_string())
+            .replace("{trending}", trending)
+            .replace("{last_refresh}", &last_refresh)
+    }
+
+    /// Object for a waybar custom module: text, tooltip, and a class to
+    /// style by (alert, watchlist, unread, or idle)
+    pub fn waybar(&self, time: &TimeDisplay) -> serde_json::Value {
+        let class = if self.alerts > 0 {
+            "alert"
+        } else if self.watchlist > 0 {
+            "watchlist"
+        } else if self.unread > 0 {
+            "unread"
+        } else {
+            "idle"
+        };
+        let tooltip = "{unread} unread, {watchlist} about the watchlist, {alerts} alerts\n\
+                       Trending: {trending}\nLast refresh {last_refresh}";
+        serde_json::json!({
+            "text": self.render(&default_status_format(), time),
+            "tooltip": self.render(tooltip, time),
+            "class": class,
+        })
+    }
+
+    /// The raw counts
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::json!({
+            "unread": self.unread,
+            "watchlist": self.watchlist,
+            "alerts": self.alerts,
+            "trending": self.trending.as_ref().map(|(ticker, articles)| {
+                serde_json::json!({ "ticker": ticker, "articles": articles })
+            }),
+            "last_refresh": self.last_refresh,
+        })
+    }
+}
+
+/// Swap the new text in with a rename, so a status bar polling the file
+/// never reads it half-written
+pub fn write(path: &Path, text: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let tmp = path.with_extension("tmp");
+    fs::write(&tmp, format!("{}\n", text))?;
+    fs::rename(&tmp, path)
+}
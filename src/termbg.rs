@@ -0,0 +1,102 @@
+use crate::model::ThemeName;
+use std::io::Write;
+use std::time::Duration;
+
+/// How long to wait for the terminal to answer the background query
+const QUERY_TIMEOUT: Duration = Duration::from_millis(150);
+
+/// Theme matching the terminal background: COLORFGBG when the terminal
+/// sets it, otherwise an OSC 11 query. Must run in raw mode so the reply
+/// isn't echoed. Dark when neither answers.
+pub fn detect_theme() -> ThemeName {
+    let light = colorfgbg_is_light()
+        .or_else(|| query_background().map(|(r, g, b)| luminance(r, g, b) > 0.5))
+        .unwrap_or(false);
+    if light {
+        ThemeName::Light
+    } else {
+        ThemeName::Dark
+    }
+}
+
+/// COLORFGBG is "fg;bg" (sometimes "fg;default;bg") with ANSI color
+/// numbers; 7 and 9-15 are light backgrounds
+fn colorfgbg_is_light() -> Option<bool> {
+    let value = std::env::var("COLORFGBG").ok()?;
+    let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    Some(bg == 7 || (9..=15).contains(&bg))
+}
+
+/// Relative luminance of 16-bit channels, 0.0 to 1.0
+fn luminance(r: u16, g: u16, b: u16) -> f64 {
+    let max = u16::MAX as f64;
+    0.2126 * (r as f64 / max) + 0.7152 * (g as f64 / max) + 0.0722 * (b as f64 / max)
+}
+
+/// Ask the terminal for its background color with OSC 11 and parse the
+/// "rgb:RRRR/GGGG/BBBB" reply
+#[cfg(unix)]
+fn query_background() -> Option<(u16, u16, u16)> {
+    use std::io::IsTerminal;
+    use std::os::fd::AsRawFd;
+
+    let stdin = std::io::stdin();
+    if !stdin.is_terminal() || !std::io::stdout().is_terminal() {
+        return None;
+    }
+    let mut stdout = std::io::stdout();
+    stdout.write_all(b"\x1b]11;?\x07").ok()?;
+    stdout.flush().ok()?;
+
+    let fd = stdin.as_raw_fd();
+    let mut reply = Vec::new();
+    let mut buf = [0u8; 64];
+    let deadline = std::time::Instant::now() + QUERY_TIMEOUT;
+    loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let mut pollfd = libc::pollfd {
+            fd,
+            events: libc::POLLIN,
+            revents: 0,
+        };
+        let ready = unsafe { libc::poll(&mut pollfd, 1, remaining.as_millis() as libc::c_int) };
+        if ready <= 0 {
+            break;
+        }
+        let n = unsafe { libc::read(fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len()) };
+        if n <= 0 {
+            break;
+        }
+        reply.extend_from_slice(&buf[..n as usize]);
+        // Terminated by BEL or ST (ESC \)
+        if reply.ends_with(b"\x07") || reply.ends_with(b"\x1b\\") {
+            break;
+        }
+    }
+    parse_reply(&String::from_utf8_lossy(&reply))
+}
+
+#[cfg(not(unix))]
+fn query_background() -> Option<(u16, u16, u16)> {
+    None
+}
+
+fn parse_reply(reply: &str) -> Option<(u16, u16, u16)> {
+    let rgb = reply.split("rgb:").nth(1)?;
+    let rgb = rgb
+        .trim_end_matches(['\x07', '\\'])
+        .trim_end_matches('\x1b');
+    let mut channels = rgb.split('/').map(|c| {
+        // Channels are 1-4 hex digits; scale to 16 bits
+        if c.is_empty() || c.len() > 4 {
+            return None;
+        }
+        let value = u32::from_str_radix(c, 16).ok()?;
+        let max = (1u32 << (4 * c.len())) - 1;
+        Some((value * u16::MAX as u32 / max) as u16)
+    });
+    Some((channels.next()??, channels.next()??, channels.next()??))
+}
@@ -0,0 +1,131 @@
+use crate::config::TranslateConfig;
+use crate::llm::Llm;
+use crate::model::Language;
+use std::process::Stdio;
+use tokio::io::AsyncWriteExt;
+
+/// Rough guess at whether `text` is Indonesian, from function word counts
+pub fn is_indonesian(text: &str) -> bool {
+    Language::detect(text) == Some(Language::Indonesian)
+}
+
+/// Indonesian articles go to English and everything else to Indonesian
+pub fn target_for(text: &str) -> &'static str {
+    if is_indonesian(text) {
+        "en"
+    } else {
+        "id"
+    }
+}
+
+pub fn language_name(code: &str) -> &str {
+    match code {
+        "en" => "English",
+        "id" => "Indonesian",
+        other => other,
+    }
+}
+
+/// Backend that turns article text into the target language
+#[derive(Debug, Clone)]
+pub enum Translator {
+    /// The language model configured under [llm]
+    Llm(Llm),
+    /// A LibreTranslate-compatible `/translate` endpoint
+    LibreTranslate { url: String, key: Option<String> },
+    /// Runs `sh -c command` with `{target}` replaced by the language code,
+    /// the text on stdin and the translation on stdout
+    Command { command: String },
+}
+
+impl Translator {
+    /// Build the configured translator, or None when translation is off
+    pub fn from_config(cfg: &TranslateConfig, llm: Option<&Llm>) -> Option<Translator> {
+        match cfg.kind.as_deref()? {
+            "llm" => llm.cloned().map(Translator::Llm),
+            "libretranslate" => Some(Translator::LibreTranslate {
+                url: cfg.url.clone()?,
+                key: cfg
+                    .token_env
+                    .as_deref()
+                    .and_then(|var| std::env::var(var).ok()),
+            }),
+            "command" => Some(Translator::Command {
+                command: cfg.command.clone()?,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Translate `text` into `target` ("en" or "id")
+    pub async fn translate(
+        &self,
+        client: &reqwest::Client,
+        text: &str,
+        target: &str,
+    ) -> Result<String, String> {
+        match self {
+            Translator::Llm(llm) => {
+                let system = format!(
+                    "Translate the user's text into {}. Keep ticker codes, numbers, and paragraph breaks as they are. Reply with the translation only.",
+                    language_name(target)
+                );
+                llm.complete(client, &system, text).await
+            }
+            Translator::LibreTranslate { url, key } => {
+                let mut body = serde_json::json!({
+                    "q": text,
+                    "source": "auto",
+                    "target": target,
+                    "format": "text",
+                });
+                if let Some(key) = key {
+                    body["api_key"] = serde_json::json!(key);
+                }
+                let resp = client
+                    .post(url)
+                    .json(&body)
+                    .send()
+                    .await
+                    .map_err(|e| format!("Network error: {}", e))?;
+                if !resp.status().is_success() {
+                    return Err(format!("HTTP {}", resp.status()));
+                }
+                let json: serde_json::Value = resp
+                    .json()
+                    .await
+                    .map_err(|e| format!("Parse error: {}", e))?;
+                json["translatedText"]
+                    .as_str()
+                    .map(|s| s.to_string())
+                    .ok_or_else(|| "Response has no translatedText".to_string())
+            }
+            Translator::Command { command } => {
+                let mut child = tokio::process::Command::new("sh")
+                    .arg("-c")
+                    .arg(command.replace("{target}", target))
+                    .stdin(Stdio::piped())
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .kill_on_drop(true)
+                    .spawn()
+                    .map_err(|e| format!("Spawn error: {}", e))?;
+                if let Some(mut stdin) = child.stdin.take() {
+                    let _ = stdin.write_all(text.as_bytes()).await;
+                }
+                let output = child
+                    .wait_with_output()
+                    .await
+                    .map_err(|e| format!("Command error: {}", e))?;
+                if !output.status.success() {
+                    return Err(format!("Command exited with {}", output.status));
+                }
+                let text = String::from_utf8_lossy(&output.stdout).trim().to_string();
+                if text.is_empty() {
+                    return Err("Command printed no translation".to_string());
+                }
+                Ok(text)
+            }
+        }
+    }
+}
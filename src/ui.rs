@@ -1,5 +1,7 @@
 use crate::app::{App, InputMode};
+use crate::ratings::RatingAction;
 use crate::model::*;
+use crate::translate;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
@@ -7,26 +9,69 @@ use ratatui::{
     widgets::{Block, Borders, Clear, Paragraph, Row, Table, Wrap},
     Frame,
 };
+use std::borrow::Cow;
 
 pub fn draw(frame: &mut Frame, app: &App) {
+    let strip_height = if app.show_market_strip { 1 } else { 0 };
+    let alert_height = if app.trading_alerts.is_empty() { 0 } else { 1 };
     let outer = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
             Constraint::Length(1), // header
+            Constraint::Length(strip_height), // market strip
+            Constraint::Length(alert_height), // suspension/UMA banner
             Constraint::Min(0),   // body
             Constraint::Length(1), // footer
         ])
         .split(frame.area());
 
     draw_header(frame, outer[0], app);
+    if app.show_market_strip {
+        draw_market_strip(frame, outer[1], app);
+    }
+    if !app.trading_alerts.is_empty() {
+        draw_alert_banner(frame, outer[2], app);
+    }
 
     match app.view_mode {
-        ViewMode::Feed | ViewMode::Bookmarks => draw_feed(frame, outer[1], app),
-        ViewMode::Reader => draw_reader(frame, outer[1], app),
-        ViewMode::Sources => draw_sources(frame, outer[1], app),
+        ViewMode::Feed | ViewMode::Bookmarks if app.show_quotes => {
+            let body = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Min(40), Constraint::Length(30)])
+                .split(outer[3]);
+            draw_feed(frame, body[0], app);
+            draw_quotes_panel(frame, body[1], app);
+        }
+        ViewMode::Feed | ViewMode::Bookmarks => draw_feed(frame, outer[3], app),
+        ViewMode::Reader => match app.linked_pages.last() {
+            Some(page) => draw_linked_page(frame, outer[3], app, page),
+            None => draw_reader(frame, outer[3], app),
+        },
+        ViewMode::Sources => draw_sources(frame, outer[3], app),
+        ViewMode::Watchlist => draw_watchlist(frame, outer[3], app),
+        ViewMode::Earnings => draw_earnings(frame, outer[3], app),
+        ViewMode::Ipo => draw_ipo(frame, outer[3], app),
+        ViewMode::Ratings => draw_ratings(frame, outer[3], app),
+        ViewMode::Timeline => draw_timeline(frame, outer[3], app),
+        ViewMode::Meetings => draw_meetings(frame, outer[3], app),
+        ViewMode::Highlights => draw_highlights(frame, outer[3], app),
+        ViewMode::Alerts => draw_alerts(frame, outer[3], app),
+    }
+
+    let progress = if app.view_mode == ViewMode::Reader {
+        reader_progress(app, outer[3])
+    } else {
+        0
+    };
+    draw_footer(frame, outer[4], app, progress);
+
+    if app.view_mode == ViewMode::Reader && app.link_cursor.is_some() {
+        draw_link_picker(frame, app);
     }
 
-    draw_footer(frame, outer[2], app);
+    if app.recent_cursor.is_some() {
+        draw_recent_tickers(frame, app);
+    }
 
     if app.show_help {
         draw_help_overlay(frame, app);
@@ -42,23 +87,73 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
 
     let fetch_indicator = if app.is_fetching {
         format!(" {} Fetching...", app.spinner_char())
+    } else if let Some((done, total)) = app.offline_progress {
+        format!(" {} Offline pack {}/{}", app.spinner_char(), done, total)
+    } else if let Some((done, total)) = app.reanalysis_progress {
+        format!(" {} Re-analyzing {}/{}", app.spinner_char(), done, total)
+    } else if app.refresh_paused {
+        " Refresh: paused".to_string()
+    } else if app.is_idle() {
+        " Refresh: idle".to_string()
     } else {
         format!(" Refresh: {}s", app.refresh_seconds_remaining())
     };
 
-    let filter_text = format!(" Filter:{}", app.filter_mode.label());
+    let mut filter_text = format!(" Filter:{}", app.filter_mode.label());
+    if app.regulatory_only {
+        filter_text.push_str(" Regulatory");
+    }
+    if app.moves_only {
+        filter_text.push_str(" Moves");
+    }
+    if app.market_filter != MarketFilter::Mixed {
+        filter_text.push_str(&format!(" Market:{}", app.market_filter.label()));
+    }
+    if app.sort_mode != SortMode::Time {
+        filter_text.push_str(&format!(" Sort:{}", app.sort_mode.label()));
+    }
     let ticker_filter_text = if let Some(ref t) = app.ticker_filter {
-        format!(" [{}]", t)
+        match app.quotes.get(t) {
+            Some(q) => format!(" [{} {} {}]", t, format_price(q.price), q.change_label()),
+            None => format!(" [{}]", t),
+        }
     } else {
         String::new()
     };
+    let mut meter_spans = Vec::new();
+    if let Some(meters) = app
+        .ticker_filter
+        .as_ref()
+        .and_then(|t| app.sentiment_meters.get(t))
+    {
+        for meter in meters {
+            meter_spans.push(Span::styled(
+                format!(" {}", meter.window),
+                Style::default().fg(theme.muted),
+            ));
+            meter_spans.push(Span::styled(
+                meter.glyph(),
+                Style::default().fg(meter.color(theme)),
+            ));
+        }
+    }
+    let mut event_filter_text = match app.event_filter {
+        Some(kind) => format!(" Event:{}", kind.label()),
+        None => String::new(),
+    };
+    if let Some(ref topic) = app.topic_filter {
+        event_filter_text.push_str(&format!(" Topic:#{}", topic));
+    }
+    if let Some(lang) = app.language_filter {
+        event_filter_text.push_str(&format!(" Lang:{}", lang.badge()));
+    }
     let watchlist_text = if app.watchlist.is_empty() {
         String::new()
     } else {
         format!(" Tickers:{}", app.watchlist.join(","))
     };
 
-    let header = Paragraph::new(Line::from(vec![
+    let mut spans = vec![
         Span::styled(
             " StockNewsTUI ",
             Style::default()
@@ -79,13 +174,18 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
                 .fg(theme.positive)
                 .add_modifier(Modifier::BOLD),
         ),
+    ];
+    spans.extend(meter_spans);
+    spans.extend([
+        Span::styled(event_filter_text, Style::default().fg(theme.accent)),
         Span::styled(watchlist_text, Style::default().fg(theme.muted)),
         Span::styled(
             format!(" Theme:{}", app.theme_name.label()),
             Style::default().fg(theme.muted),
         ),
         Span::styled(fetch_indicator, Style::default().fg(theme.muted)),
-    ]));
+    ]);
+    let header = Paragraph::new(Line::from(spans));
     frame.render_widget(header, area);
 }
 
@@ -93,7 +193,8 @@ fn draw_header(frame: &mut Frame, area: Rect, app: &App) {
 // Footer
 // ============================================================
 
-fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
+/// `progress` is how far through the reader page the view is, in percent
+fn draw_footer(frame: &mut Frame, area: Rect, app: &App, progress: u16) {
     let theme = &app.theme;
 
     if let Some(status) = app.status_text() {
@@ -111,7 +212,68 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
             Span::raw(&app.input_buffer),
             Span::styled("_", Style::default().fg(theme.accent)),
             Span::styled(
-                "  [Enter]Search [Esc]Cancel",
+                "  [Enter]Search [Up/Down]History [C-r]Find [Esc]Cancel",
+                Style::default().fg(theme.muted),
+            ),
+        ])),
+        InputMode::SearchHistory => {
+            let matches = app.history_matches();
+            let shown = matches.get(app.history_match).copied().unwrap_or("");
+            Paragraph::new(Line::from(vec![
+                Span::styled(
+                    format!(" history `{}': ", app.history_pattern),
+                    Style::default().fg(theme.accent),
+                ),
+                Span::raw(shown),
+                Span::styled(
+                    format!(
+                        "  ({}/{}) [C-r]Older [Enter]Search [Tab]Edit [Esc]Back",
+                        (app.history_match + 1).min(matches.len()),
+                        matches.len()
+                    ),
+                    Style::default().fg(theme.muted),
+                ),
+            ]))
+        }
+        InputMode::AddArticle => Paragraph::new(Line::from(vec![
+            Span::styled(" URL: ", Style::default().fg(theme.accent)),
+            Span::raw(&app.input_buffer),
+            Span::styled("_", Style::default().fg(theme.accent)),
+            Span::styled(
+                "  [Enter]Add [Esc]Cancel",
+                Style::default().fg(theme.muted),
+            ),
+        ])),
+        InputMode::ReaderSearch => Paragraph::new(Line::from(vec![
+            Span::styled(" Find: ", Style::default().fg(theme.accent)),
+            Span::raw(&app.input_buffer),
+            Span::styled("_", Style::default().fg(theme.accent)),
+            Span::styled(
+                "  [Enter]Find [Esc]Cancel",
+                Style::default().fg(theme.muted),
+            ),
+        ])),
+        InputMode::HighlightNote => Paragraph::new(Line::from(vec![
+            Span::styled(" Note (optional): ", Style::default().fg(theme.accent)),
+            Span::raw(&app.input_buffer),
+            Span::styled("_", Style::default().fg(theme.accent)),
+            Span::styled(
+                "  [Enter]Save [Esc]Back to selection",
+                Style::default().fg(theme.muted),
+            ),
+        ])),
+        InputMode::AddTicker => Paragraph::new(Line::from(vec![
+            Span::styled(" Add tickers: ", Style::default().fg(theme.accent)),
+            Span::raw(&app.input_buffer),
+            Span::styled("_", Style::default().fg(theme.accent)),
+            Span::styled("  [Enter]Add [Esc]Cancel", Style::default().fg(theme.muted)),
+        ])),
+        InputMode::ImportWatchlist => Paragraph::new(Line::from(vec![
+            Span::styled(" CSV path: ", Style::default().fg(theme.accent)),
+            Span::raw(&app.input_buffer),
+            Span::styled("_", Style::default().fg(theme.accent)),
+            Span::styled(
+                "  [Enter]Import [Esc]Cancel",
                 Style::default().fg(theme.muted),
             ),
         ])),
@@ -131,6 +293,16 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
             Span::styled("[any]", Style::default().fg(theme.accent)),
             Span::styled("Cancel", Style::default().fg(theme.fg)),
         ])),
+        InputMode::ConfirmQuit => Paragraph::new(Line::from(vec![
+            Span::styled(
+                format!(" Unfinished: {}. ", app.unfinished_work().join(", ")),
+                Style::default().fg(theme.negative),
+            ),
+            Span::styled("[y]", Style::default().fg(theme.accent)),
+            Span::styled("Finish and quit ", Style::default().fg(theme.fg)),
+            Span::styled("[any]", Style::default().fg(theme.accent)),
+            Span::styled("Cancel", Style::default().fg(theme.fg)),
+        ])),
         InputMode::Normal => match app.view_mode {
             ViewMode::Feed | ViewMode::Bookmarks => {
                 let mut spans = vec![
@@ -159,7 +331,34 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
                 ]);
                 Paragraph::new(Line::from(spans))
             }
+            ViewMode::Reader if app.highlight_selection.is_some() => {
+                Paragraph::new(Line::from(vec![
+                    Span::styled(format!("{:>3}% ", progress), Style::default().fg(theme.muted)),
+                    Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                    Span::styled("Extend ", Style::default().fg(theme.fg)),
+                    Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                    Span::styled("Save highlight ", Style::default().fg(theme.fg)),
+                    Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                    Span::styled("Cancel", Style::default().fg(theme.fg)),
+                ]))
+            }
+            ViewMode::Reader if !app.linked_pages.is_empty() => {
+                Paragraph::new(Line::from(vec![
+                    Span::styled(format!("{:>3}% ", progress), Style::default().fg(theme.muted)),
+                    Span::styled("[Esc/Bksp]", Style::default().fg(theme.accent)),
+                    Span::styled("Back ", Style::default().fg(theme.fg)),
+                    Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                    Span::styled("Scroll ", Style::default().fg(theme.fg)),
+                    Span::styled("[d/u]", Style::default().fg(theme.accent)),
+                    Span::styled("Page ", Style::default().fg(theme.fg)),
+                    Span::styled("[l]", Style::default().fg(theme.accent)),
+                    Span::styled("Links ", Style::default().fg(theme.fg)),
+                    Span::styled("[o]", Style::default().fg(theme.accent)),
+                    Span::styled("Browser", Style::default().fg(theme.fg)),
+                ]))
+            }
             ViewMode::Reader => Paragraph::new(Line::from(vec![
+                Span::styled(format!("{:>3}% ", progress), Style::default().fg(theme.muted)),
                 Span::styled("[Esc]", Style::default().fg(theme.accent)),
                 Span::styled("Back ", Style::default().fg(theme.fg)),
                 Span::styled("[j/k]", Style::default().fg(theme.accent)),
@@ -173,7 +372,132 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
                 Span::styled("[b]", Style::default().fg(theme.accent)),
                 Span::styled("Bookmark ", Style::default().fg(theme.fg)),
                 Span::styled("[T]", Style::default().fg(theme.accent)),
-                Span::styled("Ticker", Style::default().fg(theme.fg)),
+                Span::styled("Ticker ", Style::default().fg(theme.fg)),
+                Span::styled("[s]", Style::default().fg(theme.accent)),
+                Span::styled("Summary ", Style::default().fg(theme.fg)),
+                Span::styled("[t]", Style::default().fg(theme.accent)),
+                Span::styled("Translate ", Style::default().fg(theme.fg)),
+                Span::styled("[K]", Style::default().fg(theme.accent)),
+                Span::styled("Send ", Style::default().fg(theme.fg)),
+                Span::styled("[l]", Style::default().fg(theme.accent)),
+                Span::styled("Links ", Style::default().fg(theme.fg)),
+                Span::styled("[/]", Style::default().fg(theme.accent)),
+                Span::styled("Find ", Style::default().fg(theme.fg)),
+                Span::styled("[v]", Style::default().fg(theme.accent)),
+                Span::styled(
+                    if app.speech.is_some() { "Stop " } else { "Listen " },
+                    Style::default().fg(theme.fg),
+                ),
+                Span::styled("[1-9]", Style::default().fg(theme.accent)),
+                Span::styled("Prior", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Highlights => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Read ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open ", Style::default().fg(theme.fg)),
+                Span::styled("[d]", Style::default().fg(theme.accent)),
+                Span::styled("Delete ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Alerts => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Read ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open ", Style::default().fg(theme.fg)),
+                Span::styled("[Space]", Style::default().fg(theme.accent)),
+                Span::styled("Acknowledge ", Style::default().fg(theme.fg)),
+                Span::styled("[A]", Style::default().fg(theme.accent)),
+                Span::styled("All ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Meetings => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Ticker feed ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Timeline => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Ticker feed ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Ratings => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Ticker feed ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Ipo => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Coverage ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open latest ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Earnings => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Ticker feed ", Style::default().fg(theme.fg)),
+                Span::styled("[o]", Style::default().fg(theme.accent)),
+                Span::styled("Open ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
+            ])),
+            ViewMode::Watchlist => Paragraph::new(Line::from(vec![
+                Span::styled("[Esc]", Style::default().fg(theme.accent)),
+                Span::styled("Back ", Style::default().fg(theme.fg)),
+                Span::styled("[j/k]", Style::default().fg(theme.accent)),
+                Span::styled("Navigate ", Style::default().fg(theme.fg)),
+                Span::styled("[Enter]", Style::default().fg(theme.accent)),
+                Span::styled("Open feed ", Style::default().fg(theme.fg)),
+                Span::styled("[D]", Style::default().fg(theme.accent)),
+                Span::styled("Timeline ", Style::default().fg(theme.fg)),
+                Span::styled("[a]", Style::default().fg(theme.accent)),
+                Span::styled("Add ", Style::default().fg(theme.fg)),
+                Span::styled("[x]", Style::default().fg(theme.accent)),
+                Span::styled("Remove ", Style::default().fg(theme.fg)),
+                Span::styled("[i]", Style::default().fg(theme.accent)),
+                Span::styled("Import CSV ", Style::default().fg(theme.fg)),
+                Span::styled("[r]", Style::default().fg(theme.accent)),
+                Span::styled("Refresh", Style::default().fg(theme.fg)),
             ])),
             ViewMode::Sources => Paragraph::new(Line::from(vec![
                 Span::styled("[Esc]", Style::default().fg(theme.accent)),
@@ -185,7 +509,9 @@ fn draw_footer(frame: &mut Frame, area: Rect, app: &App) {
                 Span::styled("[e]", Style::default().fg(theme.accent)),
                 Span::styled("Edit ", Style::default().fg(theme.fg)),
                 Span::styled("[d]", Style::default().fg(theme.accent)),
-                Span::styled("Delete", Style::default().fg(theme.fg)),
+                Span::styled("Delete ", Style::default().fg(theme.fg)),
+                Span::styled("[R]", Style::default().fg(theme.accent)),
+                Span::styled("Re-analyze", Style::default().fg(theme.fg)),
             ])),
         },
     };
@@ -230,7 +556,11 @@ fn draw_feed(frame: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         ));
 
-    let header = Row::new(vec!["", "Source", "Time", "Title", "Tickers"])
+    let mut header_cells = vec!["", "Source", "Time", "Title", "Tickers", "Mood"];
+    if app.reading_time_column {
+        header_cells.insert(3, "Read");
+    }
+    let header = Row::new(header_cells)
         .style(
             Style::default()
                 .fg(theme.header)
@@ -238,9 +568,25 @@ fn draw_feed(frame: &mut Frame, area: Rect, app: &App) {
         )
         .height(1);
 
+    // Build only the rows that fit, so a large archive costs nothing to
+    // draw past the visible window
+    let visible = area.height.saturating_sub(3).max(1) as usize;
+    let selected = app.selected_index.min(display.len() - 1);
+    let mut offset = app
+        .scroll_offset
+        .get()
+        .min(display.len().saturating_sub(visible));
+    if selected < offset {
+        offset = selected;
+    } else if selected >= offset + visible {
+        offset = selected + 1 - visible;
+    }
+
     let rows: Vec<Row> = display
         .iter()
         .enumerate()
+        .skip(offset)
+        .take(visible)
         .map(|(i, row)| {
             let article = &app.articles[row.article_idx];
             let is_selected = i == app.selected_index;
@@ -254,18 +600,54 @@ fn draw_feed(frame: &mut Frame, area: Rect, app: &App) {
                 "+"
             };
 
-            let time_ago = format_time_ago(article.published_at);
-            let tickers_str = if article.tickers.is_empty() {
-                String::new()
-            } else {
-                article.tickers.join(",")
+            let time_ago = format_time_ago(article.published_at, &app.time_display);
+            let tickers_str = match article.tickers.first().and_then(|t| app.quotes.get(t)) {
+                Some(q) if article.tickers.len() > 1 => format!(
+                    "{} {} +{}",
+                    q.ticker,
+                    q.change_label(),
+                    article.tickers.len() - 1
+                ),
+                Some(q) => format!("{} {}", q.ticker, q.change_label()),
+                None => article.tickers.join(","),
             };
 
-            let title_text = if row.dup_count > 0 {
-                format!("{} (+{})", article.title, row.dup_count)
+            // Borrowed unless a badge or marker has to be added
+            let mut title_text: Cow<str> = if row.dup_count > 0 {
+                format!("{} (+{})", article.title, row.dup_count).into()
+            } else if row.cluster.first().is_some_and(|&m| m != row.article_idx) {
+                // Later copies of an expanded cluster
+                format!("  \u{21b3} {}", article.title).into()
             } else {
-                article.title.clone()
+                Cow::Borrowed(&article.title)
             };
+            let alert = classify_alert(&article.title);
+            if let Some(event) = article.event {
+                title_text = format!("[{}] {}", event.badge(), title_text).into();
+            } else if is_market_news(&article.title) {
+                title_text = format!("[MKT] {}", title_text).into();
+            }
+            if is_regulatory(&article.title) {
+                title_text = format!("[REG] {}", title_text).into();
+            }
+            if let Some(lang) = article.language {
+                title_text = format!("[{}] {}", lang.badge(), title_text).into();
+            }
+            if app.is_new(article) {
+                title_text = format!("[NEW] {}", title_text).into();
+            }
+            for topic in &article.topics {
+                title_text.to_mut().push_str(&format!(" #{}", topic));
+            }
+            if app.sent_ids.contains(&article.id) {
+                title_text.to_mut().push_str(" [sent]");
+            }
+            if let Some(price_move) = app.price_move(article) {
+                title_text = format!("[{:+.1}%] {}", price_move.change_pct, title_text).into();
+            }
+            if let Some(alert) = alert {
+                title_text = format!("[!{}] {}", alert.badge(), title_text).into();
+            }
 
             let style = if is_selected {
                 Style::default()
@@ -278,25 +660,60 @@ fn draw_feed(frame: &mut Frame, area: Rect, app: &App) {
                 Style::default().fg(theme.muted)
             };
 
-            Row::new(vec![
-                format!("{}{}", read_marker, sentiment_indicator),
-                article.source.clone(),
-                time_ago,
-                title_text,
-                tickers_str,
-            ])
-            .style(style)
-            .height(1)
+            let mood = article
+                .tickers
+                .first()
+                .and_then(|t| app.primary_meter(t))
+                .map(|m| {
+                    Span::styled(
+                        m.glyph(),
+                        Style::default().fg(if is_selected { theme.fg } else { m.color(theme) }),
+                    )
+                })
+                .unwrap_or_default();
+
+            let holding_marker = if app.is_holding(article) { "$" } else { " " };
+
+            let mut cells = vec![
+                Span::raw(format!("{}{}{}", read_marker, holding_marker, sentiment_indicator)),
+                Span::raw(&*article.source),
+                Span::raw(time_ago),
+                if alert.is_some() && !is_selected {
+                    Span::styled(
+                        title_text,
+                        Style::default()
+                            .fg(theme.negative)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::raw(title_text)
+                },
+                Span::raw(tickers_str),
+                mood,
+            ];
+            if app.reading_time_column {
+                let minutes = article
+                    .word_count
+                    .map(|w| format!("{}m", reading_minutes(w)))
+                    .unwrap_or_default();
+                cells.insert(3, Span::raw(minutes));
+            }
+
+            Row::new(cells).style(style).height(1)
         })
         .collect();
 
-    let widths = [
-        Constraint::Length(3),
+    let mut widths = vec![
+        Constraint::Length(4),
         Constraint::Length(14),
         Constraint::Length(8),
         Constraint::Min(20),
-        Constraint::Length(10),
+        Constraint::Length(16),
+        Constraint::Length(4),
     ];
+    if app.reading_time_column {
+        widths.insert(3, Constraint::Length(5));
+    }
 
     let table = Table::new(rows, widths)
         .header(header)
@@ -307,6 +724,219 @@ fn draw_feed(frame: &mut Frame, area: Rect, app: &App) {
                 .add_modifier(Modifier::BOLD),
         );
 
+    let mut state = ratatui::widgets::TableState::default().with_selected(Some(selected - offset));
+    frame.render_stateful_widget(table, area, &mut state);
+    app.scroll_offset.set(offset);
+}
+
+// ============================================================
+// Watchlist Overview
+// ============================================================
+
+fn draw_watchlist(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            " Watchlist Overview ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let meter_header = app
+        .sentiment_windows
+        .iter()
+        .map(|(w, _)| w.as_str())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let header = Row::new(vec![
+        "Ticker".to_string(),
+        "Price".to_string(),
+        "Unread".to_string(),
+        "24h".to_string(),
+        "Sent.".to_string(),
+        meter_header,
+        "Crowd".to_string(),
+        "Latest".to_string(),
+    ])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .watchlist_overview
+        .iter()
+        .enumerate()
+        .map(|(i, summary)| {
+            let base = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else if summary.unread > 0 {
+                Style::default().fg(theme.fg)
+            } else {
+                Style::default().fg(theme.muted)
+            };
+
+            let price = app
+                .quotes
+                .get(&summary.ticker)
+                .map(|q| format!("{} {}", format_price(q.price), q.change_label()))
+                .unwrap_or_default();
+
+            let net = summary.positive as i64 - summary.negative as i64;
+            let sentiment_color = if net > 0 {
+                theme.positive
+            } else if net < 0 {
+                theme.negative
+            } else {
+                theme.muted
+            };
+
+            // One glyph group per window, padded to line up under the header
+            let meters: Vec<Span> = app
+                .sentiment_windows
+                .iter()
+                .enumerate()
+                .map(|(w, (label, _))| {
+                    let width = label.chars().count() + 1;
+                    match app.sentiment_meters.get(&summary.ticker).and_then(|m| m.get(w)) {
+                        Some(m) => Span::styled(
+                            format!("{:<width$}", m.glyph(), width = width),
+                            Style::default().fg(m.color(theme)),
+                        ),
+                        None => Span::raw(format!("{:<width$}", "\u{00b7}", width = width)),
+                    }
+                })
+                .collect();
+
+            // Community mentions and crowd mood, for contrast with the news
+            let crowd = match app.community.get(&summary.ticker) {
+                Some(m) => Line::from(vec![
+                    Span::raw(format!("{:>5} ", m.count)),
+                    Span::styled(m.glyph(), Style::default().fg(m.color(theme))),
+                ]),
+                None => Line::from(""),
+            };
+
+            Row::new(vec![
+                Line::from(summary.ticker.clone()),
+                Line::from(price),
+                Line::from(summary.unread.to_string()),
+                Line::from(summary.recent.to_string()),
+                Line::from(Span::styled(
+                    format!("+{}/-{}", summary.positive, summary.negative),
+                    Style::default().fg(sentiment_color),
+                )),
+                Line::from(meters),
+                crowd,
+                Line::from(summary.latest_title.clone().unwrap_or_default()),
+            ])
+            .style(base)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Length(15),
+        Constraint::Length(7),
+        Constraint::Length(5),
+        Constraint::Length(8),
+        Constraint::Length(14),
+        Constraint::Length(9),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_widget(table, area);
+}
+
+// ============================================================
+// Earnings Calendar
+// ============================================================
+
+fn draw_earnings(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            " Earnings ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.earnings.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No results articles yet.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["Period", "Ticker", "Time", "Title", "Source"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .earnings
+        .iter()
+        .enumerate()
+        .map(|(i, (period, article))| {
+            // Only label the first row of each period so groups stand out
+            let new_group = i == 0 || app.earnings[i - 1].0 != *period;
+            let period_text = match (new_group, period) {
+                (false, _) => String::new(),
+                (true, Some(p)) => p.label(),
+                (true, None) => "Undated".to_string(),
+            };
+
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else if !article.read {
+                Style::default().fg(theme.fg)
+            } else {
+                Style::default().fg(theme.muted)
+            };
+
+            Row::new(vec![
+                Span::styled(period_text, Style::default().fg(theme.accent)),
+                Span::raw(article.tickers.first().cloned().unwrap_or_default()),
+                Span::raw(format_time_ago(article.published_at, &app.time_display)),
+                Span::raw(&*article.title),
+                Span::raw(&*article.source),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(9),
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Min(20),
+        Constraint::Length(14),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
     frame.render_stateful_widget(
         table,
         area,
@@ -315,13 +945,646 @@ fn draw_feed(frame: &mut Frame, area: Rect, app: &App) {
 }
 
 // ============================================================
-// Reader View
+// IPO Pipeline
 // ============================================================
 
-fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
+fn draw_ipo(frame: &mut Frame, area: Rect, app: &App) {
     let theme = &app.theme;
 
-    let article = match app.selected_article() {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            " IPO Pipeline ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.ipo_listings.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No IPO coverage yet.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["Company", "Stage", "Arts", "Time", "Latest"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .ipo_listings
+        .iter()
+        .enumerate()
+        .map(|(i, listing)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else if listing.stage == crate::ipo::IpoStage::Listed {
+                Style::default().fg(theme.muted)
+            } else {
+                Style::default().fg(theme.fg)
+            };
+
+            Row::new(vec![
+                Span::raw(listing.name()),
+                Span::styled(listing.stage.label(), Style::default().fg(theme.accent)),
+                Span::raw(listing.articles.to_string()),
+                Span::raw(format_time_ago(
+                    listing.latest.published_at,
+                    &app.time_display,
+                )),
+                Span::raw(&*listing.latest.title),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(28),
+        Constraint::Length(12),
+        Constraint::Length(4),
+        Constraint::Length(8),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(Some(app.selected_index)),
+    );
+}
+
+// ============================================================
+// RUPS Calendar
+// ============================================================
+
+fn draw_highlights(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            " Highlights ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.highlights.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No highlights yet. In the reader, [m] marks paragraphs to save.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["Saved", "Passage", "Note", "Article"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .highlights
+        .iter()
+        .enumerate()
+        .map(|(i, h)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else {
+                Style::default().fg(theme.fg)
+            };
+            let date = app.time_display.date(h.created_at);
+            let passage = h.text.split_whitespace().collect::<Vec<_>>().join(" ");
+
+            Row::new(vec![
+                Span::styled(date, Style::default().fg(theme.muted)),
+                Span::styled(passage, Style::default().fg(theme.title)),
+                Span::raw(h.note.clone().unwrap_or_default()),
+                Span::styled(h.title.clone(), Style::default().fg(theme.muted)),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Percentage(50),
+        Constraint::Percentage(20),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(Some(app.selected_index)),
+    );
+}
+
+fn draw_alerts(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let pending = app.alert_history.iter().filter(|a| !a.acknowledged).count();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            format!(" Alerts ({} unacknowledged) ", pending),
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.alert_history.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No alerts have fired yet.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["Fired", "Rule", "Article"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .alert_history
+        .iter()
+        .enumerate()
+        .map(|(i, alert)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else if alert.acknowledged {
+                Style::default().fg(theme.muted)
+            } else {
+                Style::default().fg(theme.fg)
+            };
+            let rule = AlertKind::from_str(&alert.rule)
+                .map(|k| k.badge().to_string())
+                .unwrap_or_else(|| alert.rule.clone());
+            let rule_style = if alert.acknowledged {
+                Style::default().fg(theme.muted)
+            } else {
+                Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD)
+            };
+
+            Row::new(vec![
+                Span::raw(app.time_display.format(alert.created_at, "%m-%d %H:%M")),
+                Span::styled(rule, rule_style),
+                Span::raw(alert.title.clone()),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(11),
+        Constraint::Length(8),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(Some(app.selected_index)),
+    );
+}
+
+fn draw_meetings(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            " Upcoming Shareholder Meetings ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.meetings.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No upcoming RUPS announced for your watchlist.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let today = chrono::Local::now().date_naive();
+    let header = Row::new(vec!["Date", "When", "Ticker", "Kind", "Announcement"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .meetings
+        .iter()
+        .enumerate()
+        .map(|(i, meeting)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else {
+                Style::default().fg(theme.fg)
+            };
+            let when_color = if meeting.days_until(today) <= crate::rups::REMIND_DAYS {
+                theme.negative
+            } else {
+                theme.muted
+            };
+
+            Row::new(vec![
+                Span::raw(meeting.date.format("%Y-%m-%d").to_string()),
+                Span::styled(meeting.when_label(today), Style::default().fg(when_color)),
+                Span::styled(meeting.ticker.clone(), Style::default().fg(theme.accent)),
+                Span::raw(meeting.kind.label().to_string()),
+                Span::raw(meeting.title.clone()),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(7),
+        Constraint::Length(7),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(Some(app.selected_index)),
+    );
+}
+
+// ============================================================
+// Ticker Timeline
+// ============================================================
+
+fn draw_timeline(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            format!(" {} Timeline ", app.timeline_ticker),
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.timeline.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No earnings, dividends, ratings, or corporate actions on record.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["Date", "Kind", "Detail", "Headline"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .timeline
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else {
+                Style::default().fg(theme.fg)
+            };
+            let date = app.time_display.date(entry.published_at);
+            let kind_color = match entry.sentiment {
+                Sentiment::Positive => theme.positive,
+                Sentiment::Negative => theme.negative,
+                Sentiment::Neutral => theme.accent,
+            };
+
+            Row::new(vec![
+                Span::styled(date, Style::default().fg(theme.muted)),
+                Span::styled(entry.badge.clone(), Style::default().fg(kind_color)),
+                Span::raw(entry.detail.clone()),
+                Span::raw(entry.title.clone()),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(10),
+        Constraint::Length(8),
+        Constraint::Length(32),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(Some(app.selected_index)),
+    );
+}
+
+// ============================================================
+// Alert Banner
+// ============================================================
+
+fn draw_alert_banner(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+    let Some(latest) = app.trading_alerts.last() else {
+        return;
+    };
+    let kind = classify_alert(&latest.title)
+        .map(|a| a.badge().to_string())
+        .unwrap_or_default();
+    let more = if app.trading_alerts.len() > 1 {
+        format!(" (+{} more)", app.trading_alerts.len() - 1)
+    } else {
+        String::new()
+    };
+    let alert_style = Style::default()
+        .fg(theme.negative)
+        .add_modifier(Modifier::BOLD | Modifier::REVERSED);
+
+    let line = Line::from(vec![
+        Span::styled(format!(" ! {} ", kind), alert_style),
+        Span::styled(
+            format!(" {} {}{}", latest.tickers.join(","), latest.title, more),
+            Style::default()
+                .fg(theme.negative)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled("  [!] dismiss", Style::default().fg(theme.muted)),
+    ]);
+    frame.render_widget(Paragraph::new(line), area);
+}
+
+// ============================================================
+// Market Strip
+// ============================================================
+
+fn draw_market_strip(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let mut spans = Vec::new();
+    for indicator in &app.quotes_config.indicators {
+        spans.push(Span::styled(
+            format!(" {} ", indicator.label),
+            Style::default().fg(theme.muted),
+        ));
+        match app.market_quotes.get(&indicator.label) {
+            Some(q) => {
+                let color = if q.change_pct > 0.0 {
+                    theme.positive
+                } else if q.change_pct < 0.0 {
+                    theme.negative
+                } else {
+                    theme.fg
+                };
+                spans.push(Span::styled(
+                    format_price(q.price),
+                    Style::default().fg(theme.fg),
+                ));
+                spans.push(Span::styled(
+                    format!(" {} ", q.change_label()),
+                    Style::default().fg(color),
+                ));
+            }
+            None => spans.push(Span::styled("-- ", Style::default().fg(theme.muted))),
+        }
+    }
+
+    frame.render_widget(Paragraph::new(Line::from(spans)), area);
+}
+
+// ============================================================
+// Rating Changes
+// ============================================================
+
+fn draw_ratings(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            " Analyst Ratings ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    if app.ratings.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            "  No rating actions in the last 90 days.",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block);
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let header = Row::new(vec!["Ticker", "Time", "Broker", "Action", "Rating", "Target", "Title"])
+        .style(
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )
+        .height(1);
+
+    let rows: Vec<Row> = app
+        .ratings
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let r = &entry.rating;
+            let new_group = i == 0 || app.ratings[i - 1].rating.ticker != r.ticker;
+            let style = if i == app.selected_index {
+                Style::default()
+                    .fg(theme.fg)
+                    .add_modifier(Modifier::BOLD)
+                    .bg(ratatui::style::Color::Rgb(40, 40, 50))
+            } else {
+                Style::default().fg(theme.fg)
+            };
+            let action_color = match r.action {
+                Some(RatingAction::Upgrade) => theme.positive,
+                Some(RatingAction::Downgrade) => theme.negative,
+                _ => theme.muted,
+            };
+
+            Row::new(vec![
+                Span::styled(
+                    if new_group { r.ticker.clone() } else { String::new() },
+                    Style::default().fg(theme.accent),
+                ),
+                Span::raw(format_time_ago(entry.published_at, &app.time_display)),
+                Span::raw(r.broker.clone().unwrap_or_default()),
+                Span::styled(
+                    r.action.map(|a| a.label().to_string()).unwrap_or_default(),
+                    Style::default().fg(action_color),
+                ),
+                Span::raw(r.rating.clone().unwrap_or_default()),
+                Span::raw(r.target_price.map(format_price).unwrap_or_default()),
+                Span::raw(entry.title.clone()),
+            ])
+            .style(style)
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(7),
+        Constraint::Length(8),
+        Constraint::Length(16),
+        Constraint::Length(10),
+        Constraint::Length(12),
+        Constraint::Length(8),
+        Constraint::Min(20),
+    ];
+
+    let table = Table::new(rows, widths).header(header).block(block);
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(Some(app.selected_index)),
+    );
+}
+
+// ============================================================
+// Quotes Panel
+// ============================================================
+
+fn draw_quotes_panel(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    // Quotes are delayed, so show when the newest one was fetched
+    let title = app
+        .quotes
+        .values()
+        .map(|q| q.fetched_at)
+        .max()
+        .map(|ts| format!(" Quotes {} ", app.time_display.format(ts, "%H:%M")))
+        .unwrap_or_else(|| " Quotes ".to_string());
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border))
+        .title(Span::styled(
+            title,
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let tickers = app.quote_tickers();
+    if tickers.is_empty() {
+        let empty = Paragraph::new(Span::styled(
+            " Add tickers to your watchlist",
+            Style::default().fg(theme.muted),
+        ))
+        .block(block)
+        .wrap(Wrap { trim: false });
+        frame.render_widget(empty, area);
+        return;
+    }
+
+    let rows: Vec<Row> = tickers
+        .iter()
+        .map(|ticker| match app.quotes.get(ticker) {
+            Some(q) => {
+                let color = if q.change_pct > 0.0 {
+                    theme.positive
+                } else if q.change_pct < 0.0 {
+                    theme.negative
+                } else {
+                    theme.muted
+                };
+                Row::new(vec![
+                    Span::styled(ticker.clone(), Style::default().fg(theme.fg)),
+                    Span::styled(format_price(q.price), Style::default().fg(theme.fg)),
+                    Span::styled(q.change_label(), Style::default().fg(color)),
+                ])
+            }
+            None => Row::new(vec![
+                Span::styled(ticker.clone(), Style::default().fg(theme.fg)),
+                Span::styled("...", Style::default().fg(theme.muted)),
+                Span::raw(""),
+            ]),
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(6),
+        Constraint::Length(9),
+        Constraint::Length(9),
+    ];
+    let table = Table::new(rows, widths).block(block);
+    frame.render_widget(table, area);
+}
+
+// ============================================================
+// Reader View
+// ============================================================
+
+fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
+    let theme = &app.theme;
+
+    let article = match app.selected_article() {
         Some(a) => a,
         None => {
             let empty = Paragraph::new("No article selected")
@@ -331,9 +1594,35 @@ fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
         }
     };
 
-    let time_str = chrono::DateTime::from_timestamp(article.published_at, 0)
-        .map(|dt| dt.format("%Y-%m-%d %H:%M UTC").to_string())
-        .unwrap_or_default();
+    let lines = highlight_matches(reader_lines(app, article), app);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border_selected))
+        .title(Span::styled(
+            " Article ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .wrap(Wrap { trim: false })
+        .scroll((app.reader_scroll, 0));
+    frame.render_widget(paragraph, area);
+}
+
+/// Everything the reader shows for `article`, before wrapping
+fn reader_lines<'a>(app: &'a App, article: &'a Article) -> Vec<Line<'a>> {
+    reader_parts(app, article).0
+}
+
+/// Reader lines plus the index of the first line of the article body
+fn reader_parts<'a>(app: &'a App, article: &'a Article) -> (Vec<Line<'a>>, usize) {
+    let theme = &app.theme;
+
+    let time_str = app.time_display.full(article.published_at);
 
     let sentiment_text = match article.sentiment {
         Sentiment::Positive => "Positive",
@@ -357,7 +1646,7 @@ fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
     // Build header lines
     let mut lines = vec![
         Line::from(Span::styled(
-            &article.title,
+            &*article.title,
             Style::default()
                 .fg(theme.fg)
                 .add_modifier(Modifier::BOLD),
@@ -365,40 +1654,132 @@ fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
         Line::from(""),
         Line::from(vec![
             Span::styled("Source: ", Style::default().fg(theme.muted)),
-            Span::styled(&article.source, Style::default().fg(theme.accent)),
+            Span::styled(&*article.source, Style::default().fg(theme.accent)),
             Span::styled("  ", Style::default()),
-            Span::styled(&time_str, Style::default().fg(theme.muted)),
+            Span::styled(time_str, Style::default().fg(theme.muted)),
         ]),
         Line::from(vec![
             Span::styled("Sentiment: ", Style::default().fg(theme.muted)),
             Span::styled(sentiment_text, Style::default().fg(sentiment_color)),
+            Span::styled(
+                format!(" ({:+.2})", article.sentiment_score),
+                Style::default().fg(theme.muted),
+            ),
             Span::styled(bookmark_text, Style::default().fg(theme.accent)),
+            Span::styled(
+                if app.sent_ids.contains(&article.id) {
+                    " [Sent]"
+                } else {
+                    ""
+                },
+                Style::default().fg(theme.accent),
+            ),
+            Span::styled(
+                article
+                    .event
+                    .map(|e| format!(" [{}]", e.label()))
+                    .unwrap_or_default(),
+                Style::default().fg(theme.title),
+            ),
+            Span::styled(
+                classify_alert(&article.title)
+                    .map(|a| format!(" [{}]", a.label()))
+                    .unwrap_or_default(),
+                Style::default()
+                    .fg(theme.negative)
+                    .add_modifier(Modifier::BOLD),
+            ),
         ]),
         Line::from(vec![
             Span::styled("Tickers: ", Style::default().fg(theme.muted)),
             Span::styled(tickers_text, Style::default().fg(theme.title)),
         ]),
+    ];
+    if let Some(words) = app.reader_body().map(|b| word_count(b)).filter(|w| *w > 0) {
+        lines.push(Line::from(vec![
+            Span::styled("Length: ", Style::default().fg(theme.muted)),
+            Span::styled(
+                format!("{} words, ~{} min read", words, reading_minutes(words)),
+                Style::default().fg(theme.fg),
+            ),
+        ]));
+    }
+    if !article.keywords.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Keywords: ", Style::default().fg(theme.muted)),
+            Span::styled(article.keywords.join(", "), Style::default().fg(theme.accent)),
+        ]));
+    }
+    if let Some(ref image) = app.reader_image {
+        lines.push(Line::from(vec![
+            Span::styled("Image: ", Style::default().fg(theme.muted)),
+            Span::styled(image.as_str(), Style::default().fg(theme.fg)),
+        ]));
+    }
+    lines.extend([
         Line::from(""),
         Line::from(Span::styled(
             "\u{2500}".repeat(60),
             Style::default().fg(theme.border),
         )),
         Line::from(""),
-    ];
+    ]);
 
-    // Article content
-    if app.content_loading {
+    // LLM summary above the body
+    if app.summary_loading {
         lines.push(Line::from(Span::styled(
-            format!("  {} Loading article content...", app.spinner_char()),
+            format!("  {} Summarizing...", app.spinner_char()),
             Style::default().fg(theme.muted),
         )));
-    } else if let Some(ref content) = app.reader_content {
-        for line in content.lines() {
+        lines.push(Line::from(""));
+    } else if let Some(ref summary) = app.reader_summary {
+        lines.push(Line::from(Span::styled(
+            "  Summary",
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for line in summary.lines() {
             lines.push(Line::from(Span::styled(
                 format!("  {}", line),
-                Style::default().fg(theme.fg),
+                Style::default().fg(theme.title),
             )));
         }
+        lines.push(Line::from(""));
+    }
+
+    // Article content, or its translation when toggled on
+    let translation = app
+        .reader_translation
+        .as_ref()
+        .filter(|_| app.show_translation);
+    if app.translation_loading {
+        lines.push(Line::from(Span::styled(
+            format!("  {} Translating...", app.spinner_char()),
+            Style::default().fg(theme.muted),
+        )));
+        lines.push(Line::from(""));
+    }
+    let mut body_start = lines.len();
+    if let Some((lang, text)) = translation {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "  Translated to {} ([t] original)",
+                translate::language_name(lang)
+            ),
+            Style::default().fg(theme.accent),
+        )));
+        lines.push(Line::from(""));
+        body_start = lines.len();
+        lines.extend(content_lines(app, text, app.highlight_range()));
+    } else if app.content_loading {
+        lines.push(Line::from(Span::styled(
+            format!("  {} Loading article content...", app.spinner_char()),
+            Style::default().fg(theme.muted),
+        )));
+    } else if let Some(ref content) = app.reader_content {
+        body_start = lines.len();
+        lines.extend(content_lines(app, content, app.highlight_range()));
     } else {
         lines.push(Line::from(Span::styled(
             "  No content loaded. Press [o] to open in browser.",
@@ -415,18 +1796,60 @@ fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
     lines.push(Line::from(vec![
         Span::styled("  URL: ", Style::default().fg(theme.muted)),
         Span::styled(
-            &article.url,
+            &*article.url,
             Style::default()
                 .fg(theme.title)
                 .add_modifier(Modifier::UNDERLINED),
         ),
     ]));
 
+    // Prior coverage, reachable with the number keys
+    if !app.reader_context.is_empty() {
+        let subject = if article.tickers.is_empty() {
+            "this story".to_string()
+        } else {
+            article.tickers.join(", ")
+        };
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  Previously on {}", subject),
+            Style::default()
+                .fg(theme.header)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (i, prior) in app.reader_context.iter().enumerate() {
+            let date = app.time_display.date(prior.published_at);
+            lines.push(Line::from(vec![
+                Span::styled(format!("  [{}] ", i + 1), Style::default().fg(theme.accent)),
+                Span::styled(format!("{} ", date), Style::default().fg(theme.muted)),
+                Span::styled(format!("{}  ", prior.source), Style::default().fg(theme.muted)),
+                Span::styled(&*prior.title, Style::default().fg(theme.fg)),
+            ]));
+        }
+    }
+
+    if !app.reader_links.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {} links in this article, [l] to read one here", app.reader_links.len()),
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    (lines, body_start)
+}
+
+/// A page opened from a link in the reader, on top of the back-stack
+fn draw_linked_page(frame: &mut Frame, area: Rect, app: &App, page: &LinkedPage) {
+    let theme = &app.theme;
+
+    let lines = highlight_matches(linked_page_lines(app, page), app);
+
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border_selected))
         .title(Span::styled(
-            " Article ",
+            format!(" Linked page {} ", app.linked_pages.len()),
             Style::default()
                 .fg(theme.title)
                 .add_modifier(Modifier::BOLD),
@@ -439,18 +1862,440 @@ fn draw_reader(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(paragraph, area);
 }
 
+/// Everything shown for a linked page, before wrapping
+fn linked_page_lines<'a>(app: &'a App, page: &'a LinkedPage) -> Vec<Line<'a>> {
+    let theme = &app.theme;
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            &page.title,
+            Style::default()
+                .fg(theme.fg)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            &page.url,
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::UNDERLINED),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            "\u{2500}".repeat(60),
+            Style::default().fg(theme.border),
+        )),
+        Line::from(""),
+    ];
+
+    match page.content {
+        Some(ref content) => lines.extend(content_lines(app, content, None)),
+        None => lines.push(Line::from(Span::styled(
+            format!("  {} Loading linked page...", app.spinner_char()),
+            Style::default().fg(theme.muted),
+        ))),
+    }
+
+    if !page.links.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  {} links on this page, [l] to read one here", page.links.len()),
+            Style::default().fg(theme.muted),
+        )));
+    }
+
+    lines
+}
+
+/// Styled lines of extracted article text, honoring `[reader]` config:
+/// "## " headings, "> " quotes, **bold** and *italic* runs, and image
+/// placeholders, with blank lines between paragraphs
+fn content_lines(
+    app: &App,
+    text: &str,
+    selected: Option<(usize, usize)>,
+) -> Vec<Line<'static>> {
+    let theme = &app.theme;
+    let cfg = &app.reader_style;
+    let body = Style::default().fg(theme.fg);
+
+    let mut lines = Vec::new();
+    for (i, para) in body_paragraphs(text).enumerate() {
+        if !lines.is_empty() {
+            lines.extend((0..cfg.paragraph_spacing).map(|_| Line::from("")));
+        }
+        // Image placeholders from extraction stand apart from the text
+        if para.starts_with("[Image") {
+            lines.push(Line::from(Span::styled(
+                format!("  {}", para),
+                Style::default()
+                    .fg(theme.muted)
+                    .add_modifier(Modifier::ITALIC),
+            )));
+        } else if let Some(heading) = para.strip_prefix("## ") {
+            let style = match cfg.headings.as_str() {
+                "plain" => body,
+                "bold" => body.add_modifier(Modifier::BOLD),
+                _ => Style::default()
+                    .fg(theme.accent)
+                    .add_modifier(Modifier::BOLD),
+            };
+            lines.push(Line::from(Span::styled(format!("  {}", heading), style)));
+        } else if let Some(quote) = para.strip_prefix("> ") {
+            let style = match cfg.quotes.as_str() {
+                "plain" => body,
+                _ => Style::default().fg(theme.muted),
+            };
+            let mut spans = vec![Span::styled("  \u{2502} ", Style::default().fg(theme.border))];
+            spans.extend(emphasis_spans(quote, style, cfg.emphasis));
+            lines.push(Line::from(spans));
+        } else {
+            let mut spans = vec![Span::styled("  ", body)];
+            spans.extend(emphasis_spans(para, body, cfg.emphasis));
+            lines.push(Line::from(spans));
+        }
+        // Paragraphs being marked for a highlight get a bar and backdrop
+        if selected.is_some_and(|(lo, hi)| (lo..=hi).contains(&i)) {
+            if let Some(line) = lines.last_mut() {
+                let rest = line.spans[0].content.strip_prefix("  ").map(str::to_string);
+                if let Some(rest) = rest {
+                    line.spans[0].content = rest.into();
+                    line.spans
+                        .insert(0, Span::styled("\u{258c} ", Style::default().fg(theme.accent)));
+                }
+                line.style = Style::default().bg(ratatui::style::Color::Rgb(40, 40, 50));
+            }
+        }
+    }
+    lines
+}
+
+/// Non-empty paragraphs of an article body, as `content_lines` renders
+/// them one line each
+pub fn body_paragraphs(text: &str) -> impl Iterator<Item = &str> {
+    text.lines().map(str::trim).filter(|l| !l.is_empty())
+}
+
+/// Rendered row where each body paragraph of the reader article starts,
+/// for a reader `width` columns wide including its borders
+pub fn reader_paragraph_rows(app: &App, width: u16) -> Vec<u16> {
+    let (Some(article), Some(body)) = (app.selected_article(), app.reader_body()) else {
+        return Vec::new();
+    };
+    let (lines, body_start) = reader_parts(app, article);
+    let width = width.saturating_sub(2) as usize;
+    let step = app.reader_style.paragraph_spacing + 1;
+    let count = body_paragraphs(body).count();
+
+    let mut rows = Vec::new();
+    let mut row = 0;
+    for (i, line) in lines.iter().enumerate() {
+        if i >= body_start && (i - body_start) % step == 0 && rows.len() < count {
+            rows.push(row.min(u16::MAX as usize) as u16);
+        }
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        row += wrapped_height(&text, width);
+    }
+    rows
+}
+
+/// Split `text` on balanced `**` and `*` markers, bolding and italicizing
+/// the runs between them when `enabled`. Unbalanced markers are literal.
+fn emphasis_spans(text: &str, base: Style, enabled: bool) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let bold_parts: Vec<&str> = text.split("**").collect();
+    let bold_parts = if bold_parts.len() % 2 == 1 {
+        bold_parts
+    } else {
+        vec![text]
+    };
+    for (i, part) in bold_parts.iter().enumerate() {
+        let style = if enabled && i % 2 == 1 {
+            base.add_modifier(Modifier::BOLD)
+        } else {
+            base
+        };
+        let italic_parts: Vec<&str> = part.split('*').collect();
+        let italic_parts = if italic_parts.len() % 2 == 1 {
+            italic_parts
+        } else {
+            vec![*part]
+        };
+        for (j, piece) in italic_parts.iter().enumerate() {
+            if piece.is_empty() {
+                continue;
+            }
+            let style = if enabled && j % 2 == 1 {
+                style.add_modifier(Modifier::ITALIC)
+            } else {
+                style
+            };
+            spans.push(Span::styled(piece.to_string(), style));
+        }
+    }
+    spans
+}
+
+/// The lines the reader has on screen: the top linked page or the article
+fn current_reader_lines(app: &App) -> Vec<Line<'_>> {
+    match (app.linked_pages.last(), app.selected_article()) {
+        (Some(page), _) => linked_page_lines(app, page),
+        (None, Some(article)) => reader_lines(app, article),
+        (None, None) => Vec::new(),
+    }
+}
+
+/// Columns and rows of reader text, inside its borders, on a terminal
+/// `size` columns wide and rows high
+pub fn reader_text_area(app: &App, size: (u16, u16)) -> (u16, u16) {
+    let strip = u16::from(app.show_market_strip);
+    let banner = u16::from(!app.trading_alerts.is_empty());
+    // Header and footer, then the reader's own borders
+    let height = size.1.saturating_sub(2 + strip + banner + 2);
+    (size.0.saturating_sub(2), height)
+}
+
+/// Furthest the reader can scroll while its last row stays at the bottom
+pub fn reader_max_scroll(app: &App, size: (u16, u16)) -> u16 {
+    let (width, height) = reader_text_area(app, size);
+    let total: usize = current_reader_lines(app)
+        .iter()
+        .map(|line| {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            wrapped_height(&text, width as usize)
+        })
+        .sum();
+    total.saturating_sub(height as usize).min(u16::MAX as usize) as u16
+}
+
+/// Percent of the reader page that has been scrolled into view
+fn reader_progress(app: &App, area: Rect) -> u16 {
+    let width = area.width.saturating_sub(2) as usize;
+    let height = area.height.saturating_sub(2) as usize;
+    let total: usize = current_reader_lines(app)
+        .iter()
+        .map(|line| {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            wrapped_height(&text, width)
+        })
+        .sum();
+    if total <= height {
+        return 100;
+    }
+    let scroll = (app.reader_scroll as usize).min(total - height);
+    ((scroll + height) * 100 / total) as u16
+}
+
+/// Rendered row of each reader line containing the search query, for a
+/// reader `width` columns wide including its borders
+pub fn reader_match_rows(app: &App, width: u16) -> Vec<u16> {
+    let Some(ref query) = app.reader_search else {
+        return Vec::new();
+    };
+    let lines = current_reader_lines(app);
+    let width = width.saturating_sub(2) as usize;
+
+    let mut rows = Vec::new();
+    let mut row = 0;
+    for line in &lines {
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        if text.to_lowercase().contains(query.as_str()) {
+            rows.push(row.min(u16::MAX as usize) as u16);
+        }
+        row += wrapped_height(&text, width);
+    }
+    rows
+}
+
+/// Rows a line takes once word-wrapped to `width` columns. Counts chars
+/// rather than display width, which is close enough to jump to a match.
+fn wrapped_height(text: &str, width: usize) -> usize {
+    if width == 0 {
+        return 1;
+    }
+    let mut rows = 1;
+    let mut col = 0;
+    for word in text.split_inclusive(' ') {
+        if col > 0 && col + word.trim_end().chars().count() > width {
+            rows += 1;
+            col = 0;
+        }
+        col += word.chars().count();
+        while col > width {
+            rows += 1;
+            col -= width;
+        }
+    }
+    rows
+}
+
+/// Highlight reader search matches; the current match stands out
+fn highlight_matches<'a>(lines: Vec<Line<'a>>, app: &App) -> Vec<Line<'a>> {
+    let Some(ref query) = app.reader_search else {
+        return lines;
+    };
+    let theme = &app.theme;
+    let mut matched = 0;
+    lines
+        .into_iter()
+        .map(|line| {
+            let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+            if !text.to_lowercase().contains(query.as_str()) {
+                return line;
+            }
+            let hit = if matched == app.reader_match {
+                Style::default()
+                    .fg(ratatui::style::Color::Black)
+                    .bg(theme.accent)
+            } else {
+                Style::default().add_modifier(Modifier::REVERSED)
+            };
+            matched += 1;
+
+            let mut spans = Vec::new();
+            for span in line.spans {
+                let content = span.content.to_string();
+                let lower = content.to_lowercase();
+                // Case folding that changes byte lengths can't be mapped back
+                if lower.len() != content.len() {
+                    spans.push(span);
+                    continue;
+                }
+                let mut last = 0;
+                for (at, _) in lower.match_indices(query.as_str()) {
+                    if at > last {
+                        spans.push(Span::styled(content[last..at].to_string(), span.style));
+                    }
+                    let end = at + query.len();
+                    spans.push(Span::styled(content[at..end].to_string(), hit));
+                    last = end;
+                }
+                if last < content.len() {
+                    spans.push(Span::styled(content[last..].to_string(), span.style));
+                }
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Popup list of recently used ticker filters, numbered for quick picks
+fn draw_recent_tickers(frame: &mut Frame, app: &App) {
+    let area = centered_rect(30, 40, frame.area());
+    frame.render_widget(Clear, area);
+
+    let theme = &app.theme;
+    let rows: Vec<Row> = app
+        .recent_tickers
+        .iter()
+        .enumerate()
+        .map(|(i, ticker)| {
+            let change = app
+                .quotes
+                .get(ticker)
+                .map(|q| q.change_label())
+                .unwrap_or_default();
+            Row::new(vec![
+                Span::styled(format!("{}", i + 1), Style::default().fg(theme.accent)),
+                Span::styled(ticker.clone(), Style::default().fg(theme.fg)),
+                Span::styled(change, Style::default().fg(theme.muted)),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border_selected))
+        .title(Span::styled(
+            " Recent tickers  [1-9]Pick [Esc]Close ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(2),
+            Constraint::Length(8),
+            Constraint::Min(8),
+        ],
+    )
+    .block(block)
+    .row_highlight_style(
+        Style::default()
+            .fg(theme.accent)
+            .add_modifier(Modifier::BOLD)
+            .bg(ratatui::style::Color::Rgb(40, 40, 50)),
+    );
+
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(app.recent_cursor),
+    );
+}
+
+/// Popup list of the links on the page in the reader
+fn draw_link_picker(frame: &mut Frame, app: &App) {
+    let area = centered_rect(70, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let theme = &app.theme;
+    let rows: Vec<Row> = app
+        .current_links()
+        .iter()
+        .map(|link| {
+            let host = reqwest::Url::parse(&link.url)
+                .ok()
+                .and_then(|u| u.host_str().map(|h| h.trim_start_matches("www.").to_string()))
+                .unwrap_or_default();
+            Row::new(vec![
+                Span::styled(link.text.clone(), Style::default().fg(theme.fg)),
+                Span::styled(host, Style::default().fg(theme.muted)),
+            ])
+        })
+        .collect();
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme.border_selected))
+        .title(Span::styled(
+            " Links  [Enter]Read here [o]Browser [Esc]Close ",
+            Style::default()
+                .fg(theme.title)
+                .add_modifier(Modifier::BOLD),
+        ));
+    let table = Table::new(rows, [Constraint::Min(20), Constraint::Length(24)])
+        .block(block)
+        .row_highlight_style(
+            Style::default()
+                .fg(theme.accent)
+                .add_modifier(Modifier::BOLD)
+                .bg(ratatui::style::Color::Rgb(40, 40, 50)),
+        );
+
+    frame.render_stateful_widget(
+        table,
+        area,
+        &mut ratatui::widgets::TableState::default().with_selected(app.link_cursor),
+    );
+}
+
 // ============================================================
 // Sources View
 // ============================================================
 
 fn draw_sources(frame: &mut Frame, area: Rect, app: &App) {
     let theme = &app.theme;
+    let title = match app.last_refresh_took {
+        Some(took) => format!(" Feed Sources (last refresh {:.1}s) ", took.as_secs_f64()),
+        None => " Feed Sources ".to_string(),
+    };
 
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(theme.border))
         .title(Span::styled(
-            " Feed Sources ",
+            title,
             Style::default()
                 .fg(theme.title)
                 .add_modifier(Modifier::BOLD),
@@ -467,10 +2312,30 @@ fn draw_sources(frame: &mut Frame, area: Rect, app: &App) {
         } else {
             Style::default().fg(theme.fg)
         };
-        lines.push(Line::from(Span::styled(
+        let mut spans = vec![Span::styled(
             format!("  {} {} - {}", check, source.name, source.url),
             style,
-        )));
+        )];
+        let mut notes: Vec<String> = Vec::new();
+        if let Some(ref category) = source.category {
+            notes.push(category.clone());
+        }
+        if let Some(secs) = source.refresh_interval {
+            notes.push(format!("every {}s", secs));
+        }
+        if !source.tags.is_empty() {
+            notes.push(format!("#{}", source.tags.join(" #")));
+        }
+        if source.mute_content {
+            notes.push("content muted".to_string());
+        }
+        if !notes.is_empty() {
+            spans.push(Span::styled(
+                format!("  ({})", notes.join(", ")),
+                Style::default().fg(theme.muted),
+            ));
+        }
+        lines.push(Line::from(spans));
     }
 
     // Source input/delete UI
@@ -571,9 +2436,35 @@ fn draw_help_overlay(frame: &mut Frame, app: &App) {
         Line::from(" o              Open in browser"),
         Line::from(" b              Toggle bookmark"),
         Line::from(" r              Refresh feeds"),
+        Line::from(" P              Pause/resume auto-refresh"),
+        Line::from(" p              Switch config preset"),
+        Line::from(" h              Recent ticker filters"),
+        Line::from(" a              Alert history"),
+        Line::from(" e / x / u      Expand duplicates / dismiss story / undo"),
+        Line::from(" m              Show the next source's copy of a story"),
+        Line::from(" Z              Clear article cache and retry failed loads"),
         Line::from(" /              Search (title+tickers+body)"),
+        Line::from(" A              Add article by URL"),
         Line::from(" T              Filter by ticker"),
         Line::from(" c              Clear ticker filter"),
+        Line::from(" E              Cycle corporate action filter"),
+        Line::from(" N              Cycle language filter (Indonesian/English)"),
+        Line::from(" C              Cycle commodity/FX topic filter"),
+        Line::from(" M              Cycle market news: mixed/only/hidden"),
+        Line::from(" L              Toggle regulatory news (OJK, BEI, BI, Kemenkeu)"),
+        Line::from(" X              Only articles around large price moves"),
+        Line::from(" H              Toggle market strip (IHSG, USD/IDR)"),
+        Line::from(" O              Toggle time/portfolio sort"),
+        Line::from(" Q              Toggle quotes panel"),
+        Line::from(" W              Watchlist overview (a: add, x: remove, i: import CSV)"),
+        Line::from(" w              Watch/unwatch the article's first ticker"),
+        Line::from(" R              Earnings calendar"),
+        Line::from(" I              IPO pipeline"),
+        Line::from(" U              Analyst rating changes"),
+        Line::from(" D              Event timeline for the ticker"),
+        Line::from(" V              Upcoming RUPS calendar"),
+        Line::from(" Y              Saved highlights"),
+        Line::from(" !              Dismiss alert banner (suspension, UMA, FCA, margin)"),
         Line::from(""),
         Line::from(Span::styled(
             " Reader",
@@ -582,8 +2473,20 @@ fn draw_help_overlay(frame: &mut Frame, app: &App) {
                 .fg(theme.accent),
         )),
         Line::from(" j/k            Scroll up/down"),
-        Line::from(" d/u            Page down/up"),
+        Line::from(" d/u            Scroll 10 lines down/up"),
+        Line::from(" Space/PgDn     Screen down (Shift-Space/PgUp: up)"),
+        Line::from(" Home/End       Top/bottom"),
         Line::from(" n/p            Next/prev article"),
+        Line::from(" r              Reload article"),
+        Line::from(" s              Summarize with the configured LLM"),
+        Line::from(" t              Translate Indonesian <-> English (reader)"),
+        Line::from(" v              Read aloud with tts_command / stop"),
+        Line::from(" m              Mark paragraphs as a highlight (j/k, Enter)"),
+        Line::from(" y              Copy article text (clipboard_command or OSC 52)"),
+        Line::from(" |              Pipe article text to pipe_command"),
+        Line::from(" l              Pick a link to read in place (Bksp: back)"),
+        Line::from(" /              Find in article (n/N next/prev match)"),
+        Line::from(" 1-9            Open prior coverage (reader)"),
         Line::from(" g/G            Top/bottom"),
         Line::from(""),
         Line::from(Span::styled(
@@ -592,8 +2495,10 @@ fn draw_help_overlay(frame: &mut Frame, app: &App) {
                 .add_modifier(Modifier::BOLD)
                 .fg(theme.accent),
         )),
-        Line::from(" f              Cycle filter mode"),
+        Line::from(" f              Cycle filter mode (all/watchlist/unread/source/new)"),
         Line::from(" B              View bookmarks"),
+        Line::from(" F              Download bookmarks for offline reading"),
+        Line::from(" K              Send to read-later (Pocket, Instapaper, Wallabag, Readwise)"),
         Line::from(" S              View feed sources"),
         Line::from(" t              Cycle theme"),
         Line::from(""),
@@ -607,6 +2512,7 @@ fn draw_help_overlay(frame: &mut Frame, app: &App) {
         Line::from(" e              Edit source"),
         Line::from(" d              Delete source"),
         Line::from(" Space          Toggle enable/disable"),
+        Line::from(" R              Re-analyze stored tickers and sentiment"),
         Line::from(""),
         Line::from(Span::styled(
             " General",
@@ -616,6 +2522,7 @@ fn draw_help_overlay(frame: &mut Frame, app: &App) {
         )),
         Line::from(" ?              Toggle help"),
         Line::from(" q / Ctrl+C     Quit"),
+        Line::from(" Ctrl+Z         Suspend to shell"),
         Line::from(""),
         Line::from(Span::styled(
             " Press ? to close ",
@@ -639,7 +2546,8 @@ fn draw_help_overlay(frame: &mut Frame, app: &App) {
 // Utilities
 // ============================================================
 
-fn format_time_ago(timestamp: i64) -> String {
+/// Relative age for the last week, then the date in the display zone
+fn format_time_ago(timestamp: i64, time_display: &TimeDisplay) -> String {
     let now = chrono::Utc::now().timestamp();
     let diff = now - timestamp;
 
@@ -649,8 +2557,19 @@ fn format_time_ago(timestamp: i64) -> String {
         format!("{}m ago", diff / 60)
     } else if diff < 86400 {
         format!("{}h ago", diff / 3600)
-    } else {
+    } else if diff < 7 * 86400 {
         format!("{}d ago", diff / 86400)
+    } else {
+        time_display.date(timestamp)
+    }
+}
+
+/// IDX prices are whole rupiah; only show decimals for sub-100 quotes
+fn format_price(price: f64) -> String {
+    if price >= 100.0 {
+        format!("{:.0}", price)
+    } else {
+        format!("{:.2}", price)
     }
 }
 
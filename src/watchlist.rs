@@ -0,0 +1,100 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// A plausible IDX code once any ".JK" suffix is dropped: 4 letters,
+/// optionally with a board/warrant suffix like "-W"
+static CODE_RE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^[A-Z]{4}(?:-[A-Z]{1,2})?$").unwrap());
+
+/// Header names brokers and Stockbit use for the ticker column
+const CODE_HEADERS: &[&str] = &[
+    "symbol",
+    "ticker",
+    "code",
+    "stock",
+    "stock code",
+    "saham",
+    "kode",
+    "kode saham",
+    "kode efek",
+    "emiten",
+    "instrument",
+];
+
+/// Codes are printed in capitals, which also keeps header words like
+/// "Name" or "Last" out of the watchlist
+fn normalize(field: &str) -> Option<String> {
+    let field = field.trim().trim_matches('"').trim();
+    let code = field.strip_suffix(".JK").unwrap_or(field);
+    CODE_RE.is_match(code).then(|| code.to_string())
+}
+
+/// Tickers from a broker portfolio export or Stockbit watchlist CSV, in
+/// file order without duplicates. The delimiter (comma, semicolon, or tab)
+/// is sniffed from the first line. When a header names the code column it
+/// is used; otherwise the first column holding codes wins.
+pub fn parse_csv(text: &str) -> Vec<String> {
+    let first = text.lines().next().unwrap_or_default();
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| first.matches(*d).count())
+        .unwrap_or(',');
+    let rows: Vec<Vec<&str>> = text
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.split(delimiter).collect())
+        .collect();
+
+    let header_column = rows.first().and_then(|header| {
+        header.iter().position(|h| {
+            let h = h.trim().trim_matches('"').trim().to_lowercase();
+            CODE_HEADERS.contains(&h.as_str())
+        })
+    });
+    let column = header_column.or_else(|| {
+        let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        (0..width).find(|&c| {
+            rows.iter()
+                .any(|r| r.get(c).and_then(|f| normalize(f)).is_some())
+        })
+    });
+    let Some(column) = column else {
+        return Vec::new();
+    };
+
+    let skip = usize::from(header_column.is_some());
+    let mut tickers: Vec<String> = Vec::new();
+    for row in rows.iter().skip(skip) {
+        if let Some(code) = row.get(column).and_then(|f| normalize(f)) {
+            if !tickers.contains(&code) {
+                tickers.push(code);
+            }
+        }
+    }
+    tickers
+}
+
+/// Codes typed by hand, separated by commas or spaces, in any case
+pub fn parse_codes(input: &str) -> Vec<String> {
+    let mut tickers: Vec<String> = Vec::new();
+    for field in input.split(|c: char| c == ',' || c.is_whitespace()) {
+        if let Some(code) = normalize(&field.to_uppercase()) {
+            if !tickers.contains(&code) {
+                tickers.push(code);
+            }
+        }
+    }
+    tickers
+}
+
+/// Append imported tickers not already on the watchlist; returns how many
+/// were added
+pub fn merge(watchlist: &mut Vec<String>, imported: &[String]) -> usize {
+    let before = watchlist.len();
+    for ticker in imported {
+        if !watchlist.contains(ticker) {
+            watchlist.push(ticker.clone());
+        }
+    }
+    watchlist.len() - before
+}
@@ -0,0 +1,120 @@
+use crate::config::WebhookConfig;
+use crate::model::{AlertKind, Article};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use std::time::Duration;
+
+/// Carries `sha256=<hex>`, the HMAC of the body, when a secret is set
+const SIGNATURE_HEADER: &str = "X-StockNews-Signature";
+
+/// Wait before the first retry; doubles after each failed attempt
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// A configured endpoint, with its signing secret read from the environment
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    url: String,
+    secret: Option<String>,
+    retries: u32,
+}
+
+impl Webhook {
+    pub fn from_config(cfg: &[WebhookConfig]) -> Vec<Webhook> {
+        cfg.iter()
+            .map(|hook| Webhook {
+                url: hook.url.clone(),
+                secret: hook
+                    .secret_env
+                    .as_deref()
+                    .and_then(|var| std::env::var(var).ok()),
+                retries: hook.retries,
+            })
+            .collect()
+    }
+}
+
+/// JSON body announcing a new article; `alert` names the exchange notice
+/// kind when the article is one
+pub fn payload(article: &Article, alert: Option<AlertKind>) -> Vec<u8> {
+    serde_json::to_vec(&serde_json::json!({
+        "event": "new_article",
+        "alert": alert.map(|a| a.as_str()),
+        "article": article,
+    }))
+    .unwrap_or_default()
+}
+
+fn signature(secret: &str, body: &[u8]) -> Option<String> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).ok()?;
+    mac.update(body);
+    let hex: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    Some(format!("sha256={}", hex))
+}
+
+/// POST one body, retrying network errors, 429s, and 5xx responses
+async fn deliver(client: &reqwest::Client, hook: &Webhook, body: Vec<u8>) -> Result<(), String> {
+    let signature = hook.secret.as_deref().and_then(|s| signature(s, &body));
+    let mut delay = RETRY_DELAY;
+    let mut attempt = 0;
+    loop {
+        let mut req = client
+            .post(&hook.url)
+            .header(reqwest::header::CONTENT_TYPE, "application/json")
+            .body(body.clone());
+        if let Some(ref signature) = signature {
+            req = req.header(SIGNATURE_HEADER, signature);
+        }
+        let error = match req.send().await {
+            Ok(resp) if resp.status().is_success() => return Ok(()),
+            Ok(resp) => {
+                let status = resp.status();
+                if !status.is_server_error() && status != reqwest::StatusCode::TOO_MANY_REQUESTS {
+                    return Err(format!("HTTP {}", status));
+                }
+                format!("HTTP {}", status)
+            }
+            Err(e) => format!("Network error: {}", e),
+        };
+        if attempt >= hook.retries {
+            return Err(error);
+        }
+        attempt += 1;
+        tokio::time::sleep(delay).await;
+        delay *= 2;
+    }
+}
+
+/// Send every payload to every webhook at once. Returns the deliveries
+/// that failed for good, as "url: error".
+pub async fn deliver_all(
+    client: &reqwest::Client,
+    hooks: &[Webhook],
+    payloads: Vec<Vec<u8>>,
+) -> Vec<String> {
+    let mut handles = Vec::new();
+    for hook in hooks {
+        for body in &payloads {
+            let client = client.clone();
+            let hook = hook.clone();
+            let body = body.clone();
+            handles.push(tokio::spawn(async move {
+                deliver(&client, &hook, body)
+                    .await
+                    .map_err(|e| format!("{}: {}", hook.url, e))
+            }));
+        }
+    }
+
+    let mut failures = Vec::new();
+    for handle in handles {
+        if let Ok(Err(e)) = handle.await {
+            failures.push(e);
+        }
+    }
+    failures
+}